@@ -11,6 +11,22 @@ mod m20240601_100718_create_session;
 mod m20240607_103644_create_default_role_id_cell;
 mod m20240607_103716_create_init_state_cell;
 mod m20240623_171621_create_access_log;
+mod m20240712_094512_create_ban;
+mod m20240719_151203_add_user_attributes;
+mod m20240724_103318_add_user_avatar;
+mod m20240729_114205_create_password_history;
+mod m20240801_092614_add_password_expiry;
+mod m20240805_140932_add_user_phone;
+mod m20240809_101544_add_user_locale;
+mod m20240814_093021_add_user_canonical;
+mod m20240816_112407_add_role_parent;
+mod m20240819_104236_create_user_permission;
+mod m20240821_143518_create_role_user_scope;
+mod m20240823_091547_add_role_user_period;
+mod m20240826_101732_create_organization;
+mod m20240828_154210_create_group;
+mod m20240830_112406_add_ban_previous_state;
+mod m20240902_101215_add_user_canonical_unique;
 
 pub struct Migrator;
 
@@ -29,6 +45,22 @@ impl MigratorTrait for Migrator {
             Box::new(m20240607_103644_create_default_role_id_cell::Migration),
             Box::new(m20240607_103716_create_init_state_cell::Migration),
             Box::new(m20240623_171621_create_access_log::Migration),
+            Box::new(m20240712_094512_create_ban::Migration),
+            Box::new(m20240719_151203_add_user_attributes::Migration),
+            Box::new(m20240724_103318_add_user_avatar::Migration),
+            Box::new(m20240729_114205_create_password_history::Migration),
+            Box::new(m20240801_092614_add_password_expiry::Migration),
+            Box::new(m20240805_140932_add_user_phone::Migration),
+            Box::new(m20240809_101544_add_user_locale::Migration),
+            Box::new(m20240814_093021_add_user_canonical::Migration),
+            Box::new(m20240816_112407_add_role_parent::Migration),
+            Box::new(m20240819_104236_create_user_permission::Migration),
+            Box::new(m20240821_143518_create_role_user_scope::Migration),
+            Box::new(m20240823_091547_add_role_user_period::Migration),
+            Box::new(m20240826_101732_create_organization::Migration),
+            Box::new(m20240828_154210_create_group::Migration),
+            Box::new(m20240830_112406_add_ban_previous_state::Migration),
+            Box::new(m20240902_101215_add_user_canonical_unique::Migration),
         ]
     }
 }
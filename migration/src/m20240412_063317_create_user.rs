@@ -64,6 +64,16 @@ pub enum Users {
     HashedPassword,
     CreatedAt,
     UpdatedAt,
+    Attributes,
+    AvatarId,
+    PasswordChangedAt,
+    MustChangePassword,
+    Phone,
+    PhoneVerified,
+    Locale,
+    Timezone,
+    UsernameCanonical,
+    EmailCanonical,
 }
 
 
@@ -51,4 +51,5 @@ pub enum Roles {
     Description,
     CreatedAt,
     UpdatedAt,
+    ParentId,
 }
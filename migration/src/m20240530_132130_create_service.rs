@@ -58,4 +58,5 @@ pub enum Services {
     Description,
     CreatedAt,
     UpdatedAt,
+    AttributeSchema,
 }
@@ -55,8 +55,10 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum RoleUser {
+pub enum RoleUser {
     Table,
     UserId,
-    RoleId
+    RoleId,
+    StartsAt,
+    ExpiresAt,
 }
@@ -77,7 +77,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum Sessions {
+pub enum Sessions {
     Table,
     Id,
     TokenHash,
@@ -88,4 +88,5 @@ enum Sessions {
     Device,
     CreatedAt,
     UpdatedAt,
+    Restricted,
 }
@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Bans::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Bans::Id)
+                            .uuid()
+                            .not_null()
+                            .extra("DEFAULT gen_random_uuid()")
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Bans::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Bans::Table, Bans::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .col(
+                        ColumnDef::new(Bans::BannedBy)
+                            .uuid()
+                            .null()
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(Bans::Table, Bans::BannedBy)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                    )
+                    .col(
+                        ColumnDef::new(Bans::Reason)
+                            .string_len(512)
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(Bans::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(Bans::RevokedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .col(
+                        ColumnDef::new(Bans::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_bans_user_id")
+                    .table(Bans::Table)
+                    .col(Bans::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Bans::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Bans {
+    Table,
+    Id,
+    UserId,
+    BannedBy,
+    Reason,
+    ExpiresAt,
+    RevokedAt,
+    CreatedAt,
+    PreviousState,
+}
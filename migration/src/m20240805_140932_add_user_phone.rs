@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::Phone)
+                            .string_len(16)
+                            .null()
+                            .unique_key()
+                    )
+                    .add_column(
+                        ColumnDef::new(Users::PhoneVerified)
+                            .boolean()
+                            .default(false)
+                            .not_null()
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Phone)
+                    .drop_column(Users::PhoneVerified)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,75 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(
+                        ColumnDef::new(Users::UsernameCanonical)
+                            .string_len(255)
+                            .default("")
+                            .not_null()
+                    )
+                    .add_column(
+                        ColumnDef::new(Users::EmailCanonical)
+                            .string_len(255)
+                            .default("")
+                            .not_null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Предварительное заполнение без правил почтовых провайдеров,
+        // полный пересчет выполняет команда normalize-users
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Users::Table)
+                    .value(Users::UsernameCanonical, Expr::cust("lower(normalize(username, NFKC))"))
+                    .value(Users::EmailCanonical, Expr::cust("lower(normalize(email, NFKC))"))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_username_canonical")
+                    .table(Users::Table)
+                    .col(Users::UsernameCanonical)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_email_canonical")
+                    .table(Users::Table)
+                    .col(Users::EmailCanonical)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::UsernameCanonical)
+                    .drop_column(Users::EmailCanonical)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240530_130156_create_role::Roles;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Roles::Table)
+                    .add_column(
+                        ColumnDef::new(Roles::ParentId)
+                            .uuid()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_roles_parent_id")
+                    .from(Roles::Table, Roles::ParentId)
+                    .to(Roles::Table, Roles::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Roles::Table)
+                    .drop_column(Roles::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
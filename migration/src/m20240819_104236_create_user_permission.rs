@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+use crate::m20240530_133643_create_permission::Permissions;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserPermissions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserPermissions::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(UserPermissions::PermissionId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(UserPermissions::Denied)
+                            .boolean()
+                            .default(false)
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(UserPermissions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .primary_key(
+                        Index::create()
+                            .table(UserPermissions::Table)
+                            .col(UserPermissions::UserId)
+                            .col(UserPermissions::PermissionId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserPermissions::Table, UserPermissions::PermissionId)
+                            .to(Permissions::Table, Permissions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserPermissions::Table, UserPermissions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserPermissions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserPermissions {
+    Table,
+    UserId,
+    PermissionId,
+    Denied,
+    CreatedAt,
+}
@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240530_141709_create_m2m_role_user::RoleUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoleUserScopes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RoleUserScopes::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                    )
+                    .col(
+                        ColumnDef::new(RoleUserScopes::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(RoleUserScopes::RoleId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(RoleUserScopes::ResourceType)
+                            .string_len(64)
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(RoleUserScopes::ResourceId)
+                            .string_len(255)
+                            .not_null()
+                    )
+                    // Области удаляются вместе с назначением роли
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(RoleUserScopes::Table)
+                            .from_col(RoleUserScopes::UserId)
+                            .from_col(RoleUserScopes::RoleId)
+                            .to_tbl(RoleUser::Table)
+                            .to_col(RoleUser::UserId)
+                            .to_col(RoleUser::RoleId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_role_user_scopes_user_id_role_id")
+                    .table(RoleUserScopes::Table)
+                    .col(RoleUserScopes::UserId)
+                    .col(RoleUserScopes::RoleId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RoleUserScopes::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RoleUserScopes {
+    Table,
+    Id,
+    UserId,
+    RoleId,
+    ResourceType,
+    ResourceId,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240530_141709_create_m2m_role_user::RoleUser;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RoleUser::Table)
+                    .add_column(
+                        ColumnDef::new(RoleUser::StartsAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .add_column(
+                        ColumnDef::new(RoleUser::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_role_user_expires_at")
+                    .table(RoleUser::Table)
+                    .col(RoleUser::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RoleUser::Table)
+                    .drop_column(RoleUser::StartsAt)
+                    .drop_column(RoleUser::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,169 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+use crate::m20240530_130156_create_role::Roles;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Organizations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Organizations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                    )
+                    .col(ColumnDef::new(Organizations::Title).string_len(64).unique_key().not_null())
+                    .col(ColumnDef::new(Organizations::Description).string_len(255).null())
+                    .col(
+                        ColumnDef::new(Organizations::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(Organizations::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationUsers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrganizationUsers::OrganizationId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(OrganizationUsers::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(OrganizationUsers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .primary_key(
+                        Index::create()
+                            .table(OrganizationUsers::Table)
+                            .col(OrganizationUsers::OrganizationId)
+                            .col(OrganizationUsers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrganizationUsers::Table, OrganizationUsers::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrganizationUsers::Table, OrganizationUsers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationRoleUser::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrganizationRoleUser::OrganizationId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(OrganizationRoleUser::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(OrganizationRoleUser::RoleId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .primary_key(
+                        Index::create()
+                            .table(OrganizationRoleUser::Table)
+                            .col(OrganizationRoleUser::OrganizationId)
+                            .col(OrganizationRoleUser::UserId)
+                            .col(OrganizationRoleUser::RoleId),
+                    )
+                    // Роли в организации удаляются вместе с членством
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(OrganizationRoleUser::Table)
+                            .from_col(OrganizationRoleUser::OrganizationId)
+                            .from_col(OrganizationRoleUser::UserId)
+                            .to_tbl(OrganizationUsers::Table)
+                            .to_col(OrganizationUsers::OrganizationId)
+                            .to_col(OrganizationUsers::UserId)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(OrganizationRoleUser::Table, OrganizationRoleUser::RoleId)
+                            .to(Roles::Table, Roles::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrganizationRoleUser::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(OrganizationUsers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Organizations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Organizations {
+    Table,
+    Id,
+    Title,
+    Description,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationUsers {
+    Table,
+    OrganizationId,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationRoleUser {
+    Table,
+    OrganizationId,
+    UserId,
+    RoleId,
+}
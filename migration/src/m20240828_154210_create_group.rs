@@ -0,0 +1,168 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::Users;
+use crate::m20240530_130156_create_role::Roles;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Groups::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Groups::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key()
+                    )
+                    .col(ColumnDef::new(Groups::Title).string_len(64).unique_key().not_null())
+                    .col(ColumnDef::new(Groups::Description).string_len(255).null())
+                    .col(
+                        ColumnDef::new(Groups::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(Groups::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupUsers::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupUsers::GroupId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(GroupUsers::UserId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(GroupUsers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .primary_key(
+                        Index::create()
+                            .table(GroupUsers::Table)
+                            .col(GroupUsers::GroupId)
+                            .col(GroupUsers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GroupUsers::Table, GroupUsers::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GroupUsers::Table, GroupUsers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Разрешения вычисляются от пользователя, поэтому нужен индекс по user_id
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_group_users_user_id")
+                    .table(GroupUsers::Table)
+                    .col(GroupUsers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRoles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRoles::GroupId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .col(
+                        ColumnDef::new(GroupRoles::RoleId)
+                            .uuid()
+                            .not_null()
+                    )
+                    .primary_key(
+                        Index::create()
+                            .table(GroupRoles::Table)
+                            .col(GroupRoles::GroupId)
+                            .col(GroupRoles::RoleId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GroupRoles::Table, GroupRoles::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(GroupRoles::Table, GroupRoles::RoleId)
+                            .to(Roles::Table, Roles::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupRoles::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(GroupUsers::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Groups::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    Title,
+    Description,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupUsers {
+    Table,
+    GroupId,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupRoles {
+    Table,
+    GroupId,
+    RoleId,
+}
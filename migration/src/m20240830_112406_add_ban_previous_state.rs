@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240412_063317_create_user::UserState;
+use crate::m20240712_094512_create_ban::Bans;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Состояние пользователя до бана, в него он возвращается после снятия бана
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bans::Table)
+                    .add_column(
+                        ColumnDef::new(Bans::PreviousState)
+                            .custom(UserState::Enum)
+                            .null()
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Bans::Table)
+                    .drop_column(Bans::PreviousState)
+                    .to_owned(),
+            )
+            .await
+    }
+}
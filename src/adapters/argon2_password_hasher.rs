@@ -1,40 +1,68 @@
-use argon2::{Argon2, Params, password_hash::{
+use std::sync::Arc;
+
+use argon2::{Argon2, KeyId, Params, ParamsBuilder, password_hash::{
     PasswordHash,
     PasswordHasher, PasswordVerifier, rand_core::OsRng, SaltString
 }};
 use async_trait::async_trait;
 
+use crate::adapters::legacy_password_hash::LegacyScheme;
 use crate::application::common::hasher::Hasher;
+use crate::domain::models::user::UNUSABLE_PASSWORD_HASH;
+
+/// Метка в PHC строке (`keyid`), по которой отличаются хеши с перцем
+const PEPPER_KEY_ID: &[u8] = b"pepper";
 
 pub struct Argon2PasswordHasher {
-    hasher: Argon2<'static>
+    params: Params,
+    pepper: Option<Arc<Vec<u8>>>,
 }
 
 impl Argon2PasswordHasher {
-    pub fn new() -> Self {
+    pub fn new(memory_cost: u32, time_cost: u32, parallelism: u32, pepper: Option<&str>) -> Self {
+        let mut params = ParamsBuilder::new();
+        params
+            .m_cost(memory_cost)
+            .t_cost(time_cost)
+            .p_cost(parallelism)
+            .output_len(64);
+        if pepper.is_some() {
+            params.keyid(KeyId::new(PEPPER_KEY_ID).unwrap());
+        }
+        
         Self {
-            hasher: Argon2::new(
-                argon2::Algorithm::Argon2id,
-                argon2::Version::V0x13,
-                Params::new(
-                    2048,
-                    64,
-                    4,
-                    Some(64)
-                ).unwrap()
-            )
+            params: params.build().unwrap(),
+            pepper: pepper.map(|pepper| Arc::new(pepper.as_bytes().to_vec())),
         }
     }
 }
 
+/// Хешер с перцем используется только для хешей, помеченных `keyid`
+fn make_hasher<'a>(params: Params, pepper: Option<&'a [u8]>) -> Argon2<'a> {
+    match pepper {
+        Some(pepper) => Argon2::new_with_secret(
+            pepper,
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params
+        ).unwrap(),
+        None => Argon2::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            params
+        )
+    }
+}
+
 #[async_trait]
 impl Hasher for Argon2PasswordHasher {
     async fn hash(&self, value: &str) -> String {
-        let hasher = self.hasher.clone();
+        let params = self.params.clone();
+        let pepper = self.pepper.clone();
         let value = value.to_owned();
         let hash = tokio::task::spawn_blocking(move || {
             let salt = SaltString::generate(&mut OsRng);
-            let hash = hasher.hash_password(
+            let hash = make_hasher(params, pepper.as_deref().map(Vec::as_slice)).hash_password(
                 value.as_bytes(),
                 &salt
             ).unwrap();
@@ -44,18 +72,66 @@ impl Hasher for Argon2PasswordHasher {
     }
 
     async fn verify(&self, value: &str, hash: &str) -> bool {
-        let hasher = self.hasher.clone();
+        let params = self.params.clone();
+        let pepper = self.pepper.clone();
         let value = value.to_owned();
         let hash = hash.to_owned();
         let result = tokio::task::spawn_blocking(move || {
-            let parsed_hash = PasswordHash::new(&hash).unwrap();
-            hasher.verify_password(
+            if hash == UNUSABLE_PASSWORD_HASH {
+                return false
+            }
+            
+            // Хеши из прежних систем проверяются своей схемой и пересчитываются при входе
+            if let Some(scheme) = LegacyScheme::detect(&hash) {
+                return scheme.verify(&value, &hash)
+            }
+            
+            // Нераспознанный хеш не совпадает ни с одним паролем
+            let parsed_hash = match PasswordHash::new(&hash) {
+                Ok(parsed_hash) => parsed_hash,
+                Err(_) => return false
+            };
+            let is_peppered = Params::try_from(&parsed_hash)
+                .map(|params| params.keyid() == PEPPER_KEY_ID)
+                .unwrap_or(false);
+            
+            // Перец из конфигурации не задан - хеш с перцем проверить невозможно
+            if is_peppered && pepper.is_none() {
+                return false
+            }
+            
+            make_hasher(params, pepper.as_deref().map(Vec::as_slice).filter(|_| is_peppered)).verify_password(
                 value.as_bytes(),
                 &parsed_hash
             ).is_ok()
         }).await.unwrap();
         result
     }
+
+    /// **needs_rehash** - метод проверки актуальности хеша.
+    ///
+    /// Хеш устарел, если он построен другим алгоритмом (в том числе из прежних систем), с другими параметрами
+    /// или без перца, который теперь задан (и наоборот).
+    fn needs_rehash(&self, hash: &str) -> bool {
+        let parsed_hash = match PasswordHash::new(hash) {
+            Ok(parsed_hash) => parsed_hash,
+            Err(_) => return true
+        };
+        
+        if parsed_hash.algorithm != argon2::ARGON2ID_IDENT || parsed_hash.version != Some(0x13) {
+            return true
+        }
+        
+        match Params::try_from(&parsed_hash) {
+            Ok(params) => {
+                params.m_cost() != self.params.m_cost() ||
+                params.t_cost() != self.params.t_cost() ||
+                params.p_cost() != self.params.p_cost() ||
+                params.keyid() != self.params.keyid()
+            },
+            Err(_) => true
+        }
+    }
 }
 
 #[cfg(test)]
@@ -64,7 +140,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_hash() {
-        let hasher = Argon2PasswordHasher::new();
+        let hasher = Argon2PasswordHasher::new(2048, 4, 4, None);
         let value = "test";
         let hash = hasher.hash(value).await;
         assert_ne!(hash, value);
@@ -72,10 +148,35 @@ mod tests {
 
     #[tokio::test]
     async fn test_verify() {
-        let hasher = Argon2PasswordHasher::new();
+        let hasher = Argon2PasswordHasher::new(2048, 4, 4, None);
         let value = "test";
         let hash = hasher.hash(value).await;
         let result = hasher.verify(value, &hash).await;
         assert_eq!(result, true);
     }
+
+    #[tokio::test]
+    async fn test_needs_rehash() {
+        let old_hasher = Argon2PasswordHasher::new(2048, 4, 4, None);
+        let new_hasher = Argon2PasswordHasher::new(4096, 4, 4, Some("secret"));
+        let hash = old_hasher.hash("test").await;
+
+        assert!(!old_hasher.needs_rehash(&hash));
+        assert!(new_hasher.needs_rehash(&hash));
+        assert!(new_hasher.verify("test", &hash).await);
+
+        let peppered_hash = new_hasher.hash("test").await;
+        assert!(!new_hasher.needs_rehash(&peppered_hash));
+        assert!(new_hasher.verify("test", &peppered_hash).await);
+        assert!(!old_hasher.verify("test", &peppered_hash).await);
+        assert!(!Argon2PasswordHasher::new(4096, 4, 4, Some("other")).verify("test", &peppered_hash).await);
+    }
+
+    #[tokio::test]
+    async fn test_verify_unusable_and_malformed_hash() {
+        let hasher = Argon2PasswordHasher::new(2048, 4, 4, None);
+        assert!(!hasher.verify("test", UNUSABLE_PASSWORD_HASH).await);
+        assert!(!hasher.verify("test", "not a hash").await);
+        assert!(!hasher.verify("test", "").await);
+    }
 }
@@ -23,6 +23,7 @@ pub struct IdHeaderProvider {
     session_id: Option<SessionId>,
     user_state: Option<UserState>,
     permissions: Vec<PermissionTextId>,
+    service_permissions: HashMap<ServiceTextId, Vec<PermissionTextId>>,
     client: String,
     os: String,
     device: String,
@@ -87,6 +88,7 @@ impl IdHeaderProvider {
                         vec![]
                     }
                 },
+                service_permissions: payload.permissions,
                 client,
                 os,
                 device,
@@ -102,8 +104,11 @@ impl IdHeaderProvider {
                     "CreateSession".parse().unwrap(),
                     "ConfirmUser".parse().unwrap(),
                     "ResetUserPassword".parse().unwrap(),
+                    "RevertEmailChange".parse().unwrap(),
+                    "AcceptInvitation".parse().unwrap(),
                     "SendConfirmCode".parse().unwrap(),
                 ],
+                service_permissions: HashMap::new(),
                 client,
                 os,
                 device,
@@ -140,6 +145,10 @@ impl IdProvider for IdHeaderProvider {
         &self.permissions
     }
 
+    fn service_permissions(&self, service_text_id: &ServiceTextId) -> Option<&Vec<PermissionTextId>> {
+        self.service_permissions.get(service_text_id)
+    }
+
     fn client(&self) -> &str {
         &self.client
     }
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
-use sea_orm::{DbConn, EntityTrait, QueryFilter, QuerySelect};
+use sea_orm::{DbConn, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use sea_orm::ActiveValue::Set;
-use sea_orm::sea_query::Expr;
+use sea_orm::sea_query::{Expr, Order};
 
 use crate::adapters::database::models::access_logs;
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::access_log_gateway::{
     AccessLogGateway as AccessLogGatewayTrait,
     AccessLogReader,
@@ -27,17 +29,36 @@ impl AccessLogGateway {
 #[async_trait]
 impl AccessLogReader for AccessLogGateway {
 
-    async fn get_user_records(&self, user_id: &UserId, limit: &u64, offset: &u64) -> Vec<AccessLogDomain> {
-        let records = access_logs::Entity::find()
-            .filter(Expr::col(access_logs::Column::UserId).eq(user_id.clone()))
+    async fn get_user_records(&self, user_id: &UserId, after: Option<&Cursor>, limit: &u64) -> Vec<AccessLogDomain> {
+        // Сначала новые записи
+        let mut query = access_logs::Entity::find()
+            .filter(Expr::col(access_logs::Column::UserId).eq(*user_id));
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((access_logs::Entity, access_logs::Column::CreatedAt)).into(),
+                Expr::col((access_logs::Entity, access_logs::Column::Id)).into(),
+                Order::Desc,
+                cursor
+            ));
+        }
+        let records = query
+            .order_by_desc(access_logs::Column::CreatedAt)
+            .order_by_asc(access_logs::Column::Id)
             .limit(*limit)
-            .offset(*offset)
             .all(self.db.as_ref())
             .await
             .unwrap();
 
         records.iter().map(|record| map_rec_model_to_domain(record.clone())).collect()
     }
+
+    async fn count_user_records(&self, user_id: &UserId) -> u64 {
+        access_logs::Entity::find()
+            .filter(Expr::col(access_logs::Column::UserId).eq(*user_id))
+            .count(self.db.as_ref())
+            .await
+            .unwrap()
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{DbBackend, DbConn, EntityTrait, QueryFilter, QueryOrder, Statement};
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
+
+use crate::adapters::database::models::bans;
+use crate::adapters::database::models::sea_orm_active_enums::UserState;
+use crate::application::common::ban_gateway::{
+    BanGateway as BanGatewayTrait,
+    BanReader,
+    BanWriter
+};
+use crate::domain::models::ban::Ban as BanDomain;
+use crate::domain::models::user::{UserId, UserState as UserStateDomain};
+
+pub struct BanGateway{
+    pub db: Box<DbConn>,
+}
+
+impl BanGateway {
+    pub fn new(db: Box<DbConn>) -> Self {
+        BanGateway {
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl BanReader for BanGateway {
+    async fn get_user_active_ban(&self, user_id: &UserId) -> Option<BanDomain> {
+        let ban = bans::Entity::find()
+            .filter(Expr::col(bans::Column::UserId).eq(*user_id))
+            .filter(Expr::col(bans::Column::RevokedAt).is_null())
+            .order_by_desc(bans::Column::CreatedAt)
+            .one(self.db.as_ref())
+            .await
+            .unwrap();
+
+        ban.map(map_ban_model_to_domain)
+    }
+
+    async fn get_user_bans(&self, user_id: &UserId) -> Vec<BanDomain> {
+        let bans = bans::Entity::find()
+            .filter(Expr::col(bans::Column::UserId).eq(*user_id))
+            .order_by_desc(bans::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await
+            .unwrap();
+
+        bans.into_iter().map(map_ban_model_to_domain).collect()
+    }
+
+    async fn get_expired_bans(&self, until: &DateTime<Utc>) -> Vec<BanDomain> {
+        let raw_sql = r#"
+            SELECT
+                *
+            FROM (
+                SELECT DISTINCT ON (bans.user_id)
+                    bans.*
+                FROM
+                    bans
+                JOIN
+                    users ON users.id = bans.user_id
+                WHERE
+                    bans.revoked_at IS NULL
+                    AND users.state = 'banned'
+                ORDER BY
+                    bans.user_id, bans.created_at DESC
+            ) AS last_bans
+            WHERE
+                last_bans.expires_at <= $1;
+        "#;
+
+        bans::Entity::find().from_raw_sql(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![(*until).into()]
+            )
+        )
+            .all(self.db.as_ref())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(map_ban_model_to_domain)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl BanWriter for BanGateway {
+    async fn save_ban(&self, data: &BanDomain) {
+        let model = map_ban_domain_to_model(data.clone());
+
+        match bans::Entity::find_by_id(data.id).one(self.db.as_ref()).await.unwrap() {
+            Some(_) => {
+                bans::Entity::update(model).exec(self.db.as_ref()).await.unwrap();
+            }
+            None => {
+                bans::Entity::insert(model).exec(self.db.as_ref()).await.unwrap();
+            }
+        }
+    }
+}
+
+fn map_ban_model_to_domain(ban: bans::Model) -> BanDomain {
+    BanDomain {
+        id: ban.id,
+        user_id: ban.user_id,
+        banned_by: ban.banned_by,
+        reason: ban.reason,
+        expires_at: ban.expires_at,
+        revoked_at: ban.revoked_at,
+        created_at: ban.created_at,
+        previous_state: ban.previous_state.map(|state| match state {
+            UserState::Active => UserStateDomain::Active,
+            UserState::Inactive => UserStateDomain::Inactive,
+            UserState::Banned => UserStateDomain::Banned,
+            UserState::Deleted => UserStateDomain::Deleted
+        }),
+    }
+}
+
+fn map_ban_domain_to_model(ban: BanDomain) -> bans::ActiveModel {
+    bans::ActiveModel {
+        id: Set(ban.id),
+        user_id: Set(ban.user_id),
+        banned_by: Set(ban.banned_by),
+        reason: Set(ban.reason),
+        expires_at: Set(ban.expires_at),
+        revoked_at: Set(ban.revoked_at),
+        created_at: Set(ban.created_at),
+        previous_state: Set(ban.previous_state.map(|state| match state {
+            UserStateDomain::Active => UserState::Active,
+            UserStateDomain::Inactive => UserState::Inactive,
+            UserStateDomain::Banned => UserState::Banned,
+            UserStateDomain::Deleted => UserState::Deleted
+        })),
+    }
+}
+
+impl BanGatewayTrait for BanGateway {}
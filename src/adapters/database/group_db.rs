@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::{Expr, Order};
+use sea_orm::sea_query::extension::postgres::PgExpr;
+
+use crate::adapters::database::models::{group_roles, group_users, groups};
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::group_gateway::{
+    GroupGateway as GroupGatewayTrait,
+    GroupLinker,
+    GroupReader,
+    GroupRemover,
+    GroupWriter
+};
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::group::{Group, GroupId};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::UserId;
+
+pub struct GroupGateway {
+    pub db: Box<DbConn>,
+}
+
+impl GroupGateway {
+    pub fn new(db: Box<DbConn>) -> Self {
+        GroupGateway {
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl GroupReader for GroupGateway {
+    async fn get_group(&self, group_id: &GroupId) -> Option<Group> {
+        groups::Entity::find_by_id(*group_id)
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .map(map_group_model_to_domain)
+    }
+
+    async fn get_group_by_title_not_sensitive(&self, title: &str) -> Option<Group> {
+        groups::Entity::find()
+            .filter(Expr::col(groups::Column::Title).ilike(title))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .map(map_group_model_to_domain)
+    }
+
+    async fn get_groups_range(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Group> {
+        let mut query = groups::Entity::find();
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((groups::Entity, groups::Column::CreatedAt)).into(),
+                Expr::col((groups::Entity, groups::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        query
+            .order_by_asc(groups::Column::CreatedAt)
+            .order_by_asc(groups::Column::Id)
+            .limit(*limit)
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(map_group_model_to_domain)
+            .collect()
+    }
+
+    async fn count_groups(&self) -> u64 {
+        groups::Entity::find().count(&*self.db).await.unwrap()
+    }
+
+    async fn is_user_in_group(&self, group_id: &GroupId, user_id: &UserId) -> bool {
+        group_users::Entity::find_by_id((*group_id, *user_id))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    async fn get_group_user_ids(&self, group_id: &GroupId) -> Vec<UserId> {
+        group_users::Entity::find()
+            .filter(group_users::Column::GroupId.eq(*group_id))
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| model.user_id)
+            .collect()
+    }
+
+    async fn is_role_linked_to_group(&self, group_id: &GroupId, role_id: &RoleId) -> bool {
+        group_roles::Entity::find_by_id((*group_id, *role_id))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    async fn get_group_role_ids(&self, group_id: &GroupId) -> Vec<RoleId> {
+        group_roles::Entity::find()
+            .filter(group_roles::Column::GroupId.eq(*group_id))
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|model| model.role_id)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl GroupWriter for GroupGateway {
+    async fn save_group(&self, data: &Group) {
+        let model = groups::ActiveModel {
+            id: Set(data.id),
+            title: Set(data.title.clone()),
+            description: Set(data.description.clone()),
+            created_at: Set(data.created_at),
+            updated_at: Set(data.updated_at),
+        };
+
+        match groups::Entity::find_by_id(data.id).one(&*self.db).await.unwrap() {
+            Some(_) => {
+                groups::Entity::update(model).exec(&*self.db).await.unwrap();
+            }
+            None => {
+                groups::Entity::insert(model).exec(&*self.db).await.unwrap();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl GroupLinker for GroupGateway {
+    async fn link_user_to_group(&self, group_id: &GroupId, user_id: &UserId) {
+        group_users::Entity::insert(group_users::ActiveModel {
+            group_id: Set(*group_id),
+            user_id: Set(*user_id),
+            created_at: Set(chrono::Utc::now()),
+        }).exec(&*self.db).await.unwrap();
+    }
+
+    async fn unlink_user_from_group(&self, group_id: &GroupId, user_id: &UserId) {
+        group_users::Entity::delete_by_id((*group_id, *user_id))
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
+
+    async fn link_role_to_group(&self, group_id: &GroupId, role_id: &RoleId) {
+        group_roles::Entity::insert(group_roles::ActiveModel {
+            group_id: Set(*group_id),
+            role_id: Set(*role_id),
+        }).exec(&*self.db).await.unwrap();
+    }
+
+    async fn unlink_role_from_group(&self, group_id: &GroupId, role_id: &RoleId) {
+        group_roles::Entity::delete_by_id((*group_id, *role_id))
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
+}
+
+#[async_trait]
+impl GroupRemover for GroupGateway {
+    async fn remove_group(&self, group_id: &GroupId) {
+        groups::Entity::delete_by_id(*group_id)
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
+}
+
+fn map_group_model_to_domain(model: groups::Model) -> Group {
+    Group {
+        id: model.id,
+        title: model.title,
+        description: model.description,
+        created_at: model.created_at,
+        updated_at: model.updated_at,
+    }
+}
+
+impl GroupGatewayTrait for GroupGateway {}
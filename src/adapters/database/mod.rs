@@ -1,4 +1,5 @@
 pub mod models;
+pub mod pagination;
 pub mod user_db;
 pub mod session_db;
 pub mod role_db;
@@ -6,3 +7,8 @@ pub mod service_db;
 pub mod permission_db;
 pub mod init_state_db;
 pub mod access_log_db;
+pub mod ban_db;
+pub mod organization_db;
+pub mod group_db;
+
+pub mod password_history_db;
\ No newline at end of file
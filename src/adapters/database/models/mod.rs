@@ -12,3 +12,13 @@ pub mod users;
 pub mod default_role;
 pub mod init_state;
 pub mod access_logs;
+pub mod bans;
+pub mod password_history;
+pub mod user_permissions;
+pub mod role_user_scopes;
+pub mod organizations;
+pub mod organization_users;
+pub mod organization_role_user;
+pub mod groups;
+pub mod group_users;
+pub mod group_roles;
\ No newline at end of file
@@ -20,6 +20,18 @@ pub struct Model {
     pub hashed_password: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub attributes: Json,
+    pub avatar_id: Option<Uuid>,
+    pub password_changed_at: DateTime<Utc>,
+    pub must_change_password: bool,
+    #[sea_orm(unique)]
+    pub phone: Option<String>,
+    pub phone_verified: bool,
+    pub locale: String,
+    pub timezone: String,
+    pub username_canonical: String,
+    pub email_canonical: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
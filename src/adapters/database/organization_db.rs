@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sea_orm::{DbBackend, DbConn, EntityTrait, FromQueryResult, JsonValue, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement};
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::{Expr, Order};
+use sea_orm::sea_query::extension::postgres::PgExpr;
+
+use crate::adapters::database::models::{organization_role_user, organization_users, organizations};
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::organization_gateway::{
+    OrganizationGateway as OrganizationGatewayTrait,
+    OrganizationLinker,
+    OrganizationReader,
+    OrganizationWriter
+};
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::organization::{Organization, OrganizationId};
+use crate::domain::models::permission::{PermissionId, PermissionTextId};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+
+pub struct OrganizationGateway {
+    pub db: Box<DbConn>,
+}
+
+impl OrganizationGateway {
+    pub fn new(db: Box<DbConn>) -> Self {
+        OrganizationGateway {
+            db,
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationReader for OrganizationGateway {
+    async fn get_organization(&self, organization_id: &OrganizationId) -> Option<Organization> {
+        organizations::Entity::find_by_id(*organization_id)
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .map(map_organization_model_to_domain)
+    }
+
+    async fn get_organization_by_title_not_sensitive(&self, title: &str) -> Option<Organization> {
+        organizations::Entity::find()
+            .filter(Expr::col(organizations::Column::Title).ilike(title))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .map(map_organization_model_to_domain)
+    }
+
+    async fn get_organizations_range(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Organization> {
+        let mut query = organizations::Entity::find();
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((organizations::Entity, organizations::Column::CreatedAt)).into(),
+                Expr::col((organizations::Entity, organizations::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        query
+            .order_by_asc(organizations::Column::CreatedAt)
+            .order_by_asc(organizations::Column::Id)
+            .limit(*limit)
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(map_organization_model_to_domain)
+            .collect()
+    }
+
+    async fn count_organizations(&self) -> u64 {
+        organizations::Entity::find().count(&*self.db).await.unwrap()
+    }
+
+    async fn is_user_in_organization(&self, organization_id: &OrganizationId, user_id: &UserId) -> bool {
+        organization_users::Entity::find_by_id((*organization_id, *user_id))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    async fn get_user_organization_permissions(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId
+    ) -> HashMap<ServiceTextId, Vec<PermissionTextId>> {
+        let raw_sql = r#"
+            WITH RECURSIVE organization_roles AS (
+                SELECT organization_role_user.role_id FROM organization_role_user
+                WHERE organization_role_user.organization_id = $1
+                AND organization_role_user.user_id = $2
+                UNION
+                SELECT roles.parent_id FROM roles
+                JOIN organization_roles ON roles.id = organization_roles.role_id
+                WHERE roles.parent_id IS NOT NULL
+            )
+            SELECT DISTINCT
+                services.text_id AS service_text_id,
+                permissions.text_id AS permission_text_id
+            FROM
+                organization_roles
+            JOIN
+                role_permissions ON organization_roles.role_id = role_permissions.role_id
+            JOIN
+                permissions ON role_permissions.permission_id = permissions.id
+            JOIN
+                services ON permissions.service_id = services.id
+            WHERE
+                permissions.id NOT IN (
+                    SELECT user_permissions.permission_id FROM user_permissions
+                    WHERE user_permissions.user_id = $2 AND user_permissions.denied
+                );
+        "#;
+
+        let raw_values: Vec<JsonValue> = JsonValue::find_by_statement(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![(*organization_id).into(), (*user_id).into()],
+            )
+        )
+            .all(&*self.db)
+            .await.unwrap();
+
+        let mut data: HashMap<ServiceTextId, Vec<PermissionTextId>> = HashMap::new();
+        raw_values.iter().for_each(
+            |value| {
+                let field = |name: &str| value.get(name).unwrap().as_str().unwrap().to_string();
+                
+                data.entry(field("service_text_id"))
+                    .or_default()
+                    .push(field("permission_text_id"));
+            }
+        );
+        
+        data
+    }
+
+    async fn get_user_organization_permission_ids(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId
+    ) -> Vec<PermissionId> {
+        let raw_sql = r#"
+            WITH RECURSIVE organization_roles AS (
+                SELECT organization_role_user.role_id FROM organization_role_user
+                WHERE organization_role_user.organization_id = $1
+                AND organization_role_user.user_id = $2
+                UNION
+                SELECT roles.parent_id FROM roles
+                JOIN organization_roles ON roles.id = organization_roles.role_id
+                WHERE roles.parent_id IS NOT NULL
+            )
+            SELECT DISTINCT
+                role_permissions.permission_id::text AS permission_id
+            FROM
+                organization_roles
+            JOIN
+                role_permissions ON organization_roles.role_id = role_permissions.role_id
+            WHERE
+                role_permissions.permission_id NOT IN (
+                    SELECT user_permissions.permission_id FROM user_permissions
+                    WHERE user_permissions.user_id = $2 AND user_permissions.denied
+                );
+        "#;
+
+        let raw_values: Vec<JsonValue> = JsonValue::find_by_statement(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![(*organization_id).into(), (*user_id).into()],
+            )
+        )
+            .all(&*self.db)
+            .await.unwrap();
+
+        raw_values.iter().map(
+            |value| PermissionId::parse_str(
+                value.get("permission_id").unwrap().as_str().unwrap()
+            ).unwrap()
+        ).collect()
+    }
+}
+
+#[async_trait]
+impl OrganizationWriter for OrganizationGateway {
+    async fn save_organization(&self, data: &Organization) {
+        let model = organizations::ActiveModel {
+            id: Set(data.id),
+            title: Set(data.title.clone()),
+            description: Set(data.description.clone()),
+            created_at: Set(data.created_at),
+            updated_at: Set(data.updated_at),
+        };
+
+        match organizations::Entity::find_by_id(data.id).one(&*self.db).await.unwrap() {
+            Some(_) => {
+                organizations::Entity::update(model).exec(&*self.db).await.unwrap();
+            }
+            None => {
+                organizations::Entity::insert(model).exec(&*self.db).await.unwrap();
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationLinker for OrganizationGateway {
+    async fn link_user_to_organization(&self, organization_id: &OrganizationId, user_id: &UserId) {
+        organization_users::Entity::insert(organization_users::ActiveModel {
+            organization_id: Set(*organization_id),
+            user_id: Set(*user_id),
+            created_at: Set(chrono::Utc::now()),
+        }).exec(&*self.db).await.unwrap();
+    }
+
+    async fn unlink_user_from_organization(&self, organization_id: &OrganizationId, user_id: &UserId) {
+        organization_users::Entity::delete_by_id((*organization_id, *user_id))
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
+
+    async fn is_role_linked_to_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    ) -> bool {
+        organization_role_user::Entity::find_by_id((*organization_id, *user_id, *role_id))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    async fn link_role_to_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    ) {
+        organization_role_user::Entity::insert(organization_role_user::ActiveModel {
+            organization_id: Set(*organization_id),
+            user_id: Set(*user_id),
+            role_id: Set(*role_id),
+        }).exec(&*self.db).await.unwrap();
+    }
+
+    async fn unlink_role_from_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    ) {
+        organization_role_user::Entity::delete_by_id((*organization_id, *user_id, *role_id))
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
+}
+
+fn map_organization_model_to_domain(model: organizations::Model) -> Organization {
+    Organization {
+        id: model.id,
+        title: model.title,
+        description: model.description,
+        created_at: model.created_at,
+        updated_at: model.updated_at,
+    }
+}
+
+impl OrganizationGatewayTrait for OrganizationGateway {}
@@ -0,0 +1,82 @@
+use sea_orm::sea_query::{Condition, Expr, Order, SimpleExpr};
+use sea_orm::Value;
+
+use crate::application::common::pagination::{Cursor, CursorValue};
+
+/// Условие выборки строк, идущих после курсора.
+///
+/// Предполагается сортировка `column <order> NULLS LAST, id ASC`.
+pub fn after_cursor(
+    column: SimpleExpr,
+    id_column: SimpleExpr,
+    order: Order,
+    cursor: &Cursor,
+) -> Condition {
+    let value: Value = match &cursor.value {
+        CursorValue::Text(text) => text.clone().into(),
+        CursorValue::Time(time) => (*time).into(),
+        CursorValue::Null => {
+            return Condition::all()
+                .add(Expr::expr(column).is_null())
+                .add(Expr::expr(id_column).gt(cursor.id))
+        }
+    };
+
+    Condition::any()
+        .add(match order {
+            Order::Desc => Expr::expr(column.clone()).lt(value.clone()),
+            _ => Expr::expr(column.clone()).gt(value.clone()),
+        })
+        .add(
+            Condition::all()
+                .add(Expr::expr(column.clone()).eq(value))
+                .add(Expr::expr(id_column).gt(cursor.id))
+        )
+        .add(Expr::expr(column).is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::{Alias, Asterisk, PostgresQueryBuilder, Query};
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn to_sql(condition: Condition) -> String {
+        Query::select()
+            .column(Asterisk)
+            .from(Alias::new("t"))
+            .cond_where(condition)
+            .to_string(PostgresQueryBuilder)
+    }
+
+    #[test]
+    fn test_after_text_cursor() {
+        let cursor = Cursor::new(CursorValue::Text("bob".to_string()), Uuid::nil());
+        let sql = to_sql(after_cursor(
+            Expr::col(Alias::new("name")).into(),
+            Expr::col(Alias::new("id")).into(),
+            Order::Desc,
+            &Cursor::decode(&cursor.encode()).unwrap()
+        ));
+        assert_eq!(
+            sql,
+            r#"SELECT * FROM "t" WHERE "name" < 'bob' OR ("name" = 'bob' AND "id" > '00000000-0000-0000-0000-000000000000') OR "name" IS NULL"#
+        );
+    }
+
+    #[test]
+    fn test_after_null_cursor() {
+        let cursor = Cursor::new(CursorValue::Null, Uuid::nil());
+        let sql = to_sql(after_cursor(
+            Expr::col(Alias::new("last_login")).into(),
+            Expr::col(Alias::new("id")).into(),
+            Order::Asc,
+            &cursor
+        ));
+        assert_eq!(
+            sql,
+            r#"SELECT * FROM "t" WHERE "last_login" IS NULL AND "id" > '00000000-0000-0000-0000-000000000000'"#
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use sea_orm::{DbConn, EntityTrait, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
+use uuid::Uuid;
+
+use crate::adapters::database::models::password_history;
+use crate::application::common::password_history_gateway::{
+    PasswordHistoryGateway as PasswordHistoryGatewayTrait,
+    PasswordHistoryReader,
+    PasswordHistoryRemover,
+    PasswordHistoryWriter
+};
+use crate::domain::models::user::UserId;
+
+pub struct PasswordHistoryGateway {
+    pub db: Box<DbConn>,
+    history_size: usize,
+}
+
+impl PasswordHistoryGateway {
+    pub fn new(db: Box<DbConn>, history_size: usize) -> Self {
+        PasswordHistoryGateway {
+            db,
+            history_size,
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryReader for PasswordHistoryGateway {
+    async fn get_password_history(&self, user_id: &UserId) -> Vec<String> {
+        if self.history_size == 0 {
+            return vec![]
+        }
+        
+        password_history::Entity::find()
+            .filter(Expr::col(password_history::Column::UserId).eq(*user_id))
+            .order_by_desc(password_history::Column::CreatedAt)
+            .limit(self.history_size as u64)
+            .all(self.db.as_ref())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|record| record.hashed_password)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryWriter for PasswordHistoryGateway {
+    async fn save_password_history(&self, user_id: &UserId, hashed_password: &str) {
+        if self.history_size == 0 {
+            return
+        }
+        
+        password_history::Entity::insert(password_history::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(*user_id),
+            hashed_password: Set(hashed_password.to_string()),
+            created_at: Set(chrono::Utc::now()),
+        }).exec(self.db.as_ref()).await.unwrap();
+
+        let outdated: Vec<Uuid> = password_history::Entity::find()
+            .select_only()
+            .column(password_history::Column::Id)
+            .filter(Expr::col(password_history::Column::UserId).eq(*user_id))
+            .order_by_desc(password_history::Column::CreatedAt)
+            .offset(self.history_size as u64)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await
+            .unwrap();
+
+        if !outdated.is_empty() {
+            password_history::Entity::delete_many()
+                .filter(Expr::col(password_history::Column::Id).is_in(outdated))
+                .exec(self.db.as_ref())
+                .await
+                .unwrap();
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordHistoryRemover for PasswordHistoryGateway {
+    async fn remove_password_history(&self, user_id: &UserId) {
+        password_history::Entity::delete_many()
+            .filter(Expr::col(password_history::Column::UserId).eq(*user_id))
+            .exec(self.db.as_ref())
+            .await
+            .unwrap();
+    }
+}
+
+impl PasswordHistoryGatewayTrait for PasswordHistoryGateway {}
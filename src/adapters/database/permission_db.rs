@@ -1,11 +1,14 @@
 use core::option::Option;
 
 use async_trait::async_trait;
-use sea_orm::{Condition, DbBackend, DbConn, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait, Statement};
+use chrono::Utc;
+use sea_orm::{Condition, DbBackend, DbConn, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Statement};
 use sea_orm::ActiveValue::Set;
-use sea_orm::sea_query::{Expr, IntoCondition};
+use sea_orm::sea_query::{Expr, IntoCondition, Order, Value};
 
-use crate::adapters::database::models::{permissions, role_permissions};
+use crate::adapters::database::models::{permissions, role_permissions, user_permissions};
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::permission_gateway::{
     PermissionGateway as PermissionGatewayTrait,
     PermissionLinker,
@@ -13,12 +16,78 @@ use crate::application::common::permission_gateway::{
     PermissionRemover,
     PermissionWriter
 };
-use crate::domain::models::permission::{Permission as PermissionDomain, PermissionId, PermissionTextId};
+use crate::domain::models::permission::{Permission as PermissionDomain, PermissionEffect, PermissionId, PermissionTextId};
 use crate::domain::models::permission::Permission;
 use crate::domain::models::role::RoleId;
 use crate::domain::models::service::ServiceId;
 use crate::domain::models::user::UserId;
 
+/// Общие CTE для расчета действующих разрешений пользователя.
+/// Запрос должен заранее объявить CTE `user_ref` с колонкой `user_id`,
+/// результат — CTE `effective_permissions` с колонкой `permission_id`
+/// и CTE `scoped_permissions` с разрешениями, ограниченными областями ресурсов.
+/// Учитываются только назначения ролей, действующие в текущий момент,
+/// и роли групп, в которых состоит пользователь
+pub const EFFECTIVE_PERMISSIONS_CTE: &str = r#"
+    active_role_user AS (
+        SELECT role_user.* FROM role_user
+        JOIN user_ref ON role_user.user_id = user_ref.user_id
+        WHERE (role_user.starts_at IS NULL OR role_user.starts_at <= now())
+        AND (role_user.expires_at IS NULL OR role_user.expires_at > now())
+    ),
+    user_roles AS (
+        SELECT role_user.role_id FROM active_role_user AS role_user
+        WHERE NOT EXISTS (
+            SELECT 1 FROM role_user_scopes
+            WHERE role_user_scopes.user_id = role_user.user_id
+            AND role_user_scopes.role_id = role_user.role_id
+        )
+        UNION
+        SELECT group_roles.role_id FROM group_roles
+        JOIN group_users ON group_roles.group_id = group_users.group_id
+        JOIN user_ref ON group_users.user_id = user_ref.user_id
+        UNION
+        SELECT roles.parent_id FROM roles
+        JOIN user_roles ON roles.id = user_roles.role_id
+        WHERE roles.parent_id IS NOT NULL
+    ),
+    effective_permissions AS (
+        SELECT role_permissions.permission_id FROM role_permissions
+        JOIN user_roles ON role_permissions.role_id = user_roles.role_id
+        UNION
+        SELECT user_permissions.permission_id FROM user_permissions
+        JOIN user_ref ON user_permissions.user_id = user_ref.user_id
+        WHERE NOT user_permissions.denied
+        EXCEPT
+        SELECT user_permissions.permission_id FROM user_permissions
+        JOIN user_ref ON user_permissions.user_id = user_ref.user_id
+        WHERE user_permissions.denied
+    ),
+    scoped_roles AS (
+        SELECT role_user_scopes.role_id, role_user_scopes.resource_type, role_user_scopes.resource_id
+        FROM role_user_scopes
+        JOIN active_role_user ON role_user_scopes.user_id = active_role_user.user_id
+        AND role_user_scopes.role_id = active_role_user.role_id
+        UNION
+        SELECT roles.parent_id, scoped_roles.resource_type, scoped_roles.resource_id FROM roles
+        JOIN scoped_roles ON roles.id = scoped_roles.role_id
+        WHERE roles.parent_id IS NOT NULL
+    ),
+    scoped_permissions AS (
+        SELECT DISTINCT role_permissions.permission_id, scoped_roles.resource_type, scoped_roles.resource_id
+        FROM role_permissions
+        JOIN scoped_roles ON role_permissions.role_id = scoped_roles.role_id
+        WHERE role_permissions.permission_id NOT IN (
+            SELECT effective_permissions.permission_id FROM effective_permissions
+        )
+        AND role_permissions.permission_id NOT IN (
+            SELECT user_permissions.permission_id FROM user_permissions
+            JOIN user_ref ON user_permissions.user_id = user_ref.user_id
+            WHERE user_permissions.denied
+        )
+    )
+"#;
+
 pub struct PermissionGateway{
     pub db: Box<DbConn>,
 }
@@ -29,6 +98,20 @@ impl PermissionGateway {
             db,
         }
     }
+
+    async fn get_permissions_by_raw_sql(&self, raw_sql: &str, value: Value) -> Vec<PermissionDomain> {
+        let permissions: Vec<permissions::Model> = permissions::Entity::find().from_raw_sql(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![value]
+            )
+        )
+            .all(&*self.db)
+            .await.unwrap();
+
+        permissions.into_iter().map(map_permission_model_to_domain).collect()
+    }
 }
 
 #[async_trait]
@@ -93,15 +176,29 @@ impl PermissionReader for PermissionGateway {
         Some(permissions.into_iter().map(map_permission_model_to_domain).collect())
     }
 
-    async fn get_permissions_list(&self, limit: &u64, offset: &u64) -> Vec<Permission> {
-        let permissions = permissions::Entity::find()
+    async fn get_permissions_list(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Permission> {
+        let mut query = permissions::Entity::find();
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((permissions::Entity, permissions::Column::CreatedAt)).into(),
+                Expr::col((permissions::Entity, permissions::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        let permissions = query
+            .order_by_asc(permissions::Column::CreatedAt)
+            .order_by_asc(permissions::Column::Id)
             .limit(*limit)
-            .offset(*offset)
             .all(&*self.db)
             .await.unwrap();
         permissions.into_iter().map(map_permission_model_to_domain).collect()
     }
 
+    async fn count_permissions(&self) -> u64 {
+        permissions::Entity::find().count(&*self.db).await.unwrap()
+    }
+
     async fn get_role_permissions(&self, role_id: &RoleId) -> Vec<Permission> {
         let role_id = role_id.clone();
         let permissions = permissions::Entity::find()
@@ -120,31 +217,99 @@ impl PermissionReader for PermissionGateway {
         permissions.into_iter().map(map_permission_model_to_domain).collect()
     }
 
-    async fn get_user_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain> {
+    async fn get_role_inherited_permissions(&self, role_id: &RoleId) -> Vec<PermissionDomain> {
         let raw_sql = r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT roles.parent_id AS role_id FROM roles
+                WHERE roles.id = $1 AND roles.parent_id IS NOT NULL
+                UNION
+                SELECT roles.parent_id FROM roles
+                JOIN ancestors ON roles.id = ancestors.role_id
+                WHERE roles.parent_id IS NOT NULL
+            )
+            SELECT DISTINCT
+                permissions.*
+            FROM
+                permissions
+            JOIN
+                role_permissions ON permissions.id = role_permissions.permission_id
+            JOIN
+                ancestors ON role_permissions.role_id = ancestors.role_id;
+        "#;
+
+        self.get_permissions_by_raw_sql(raw_sql, (*role_id).into()).await
+    }
+
+    async fn get_user_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain> {
+        let raw_sql = format!(r#"
+            WITH RECURSIVE user_ref AS (
+                SELECT $1::uuid AS user_id
+            ),
+            {EFFECTIVE_PERMISSIONS_CTE}
             SELECT
                 permissions.*
             FROM
                 permissions
             JOIN
-                role_permissions ON permissions.permission_id = role_permissions.permission_id
+                effective_permissions ON permissions.id = effective_permissions.permission_id;
+        "#);
+
+        self.get_permissions_by_raw_sql(&raw_sql, (*user_id).into()).await
+    }
+
+    async fn get_user_direct_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain> {
+        let raw_sql = r#"
+            WITH direct_permissions AS (
+                SELECT role_permissions.permission_id FROM role_permissions
+                JOIN role_user ON role_permissions.role_id = role_user.role_id
+                WHERE role_user.user_id = $1
+                AND (role_user.starts_at IS NULL OR role_user.starts_at <= now())
+                AND (role_user.expires_at IS NULL OR role_user.expires_at > now())
+                UNION
+                SELECT user_permissions.permission_id FROM user_permissions
+                WHERE user_permissions.user_id = $1 AND NOT user_permissions.denied
+                EXCEPT
+                SELECT user_permissions.permission_id FROM user_permissions
+                WHERE user_permissions.user_id = $1 AND user_permissions.denied
+            )
+            SELECT
+                permissions.*
+            FROM
+                permissions
             JOIN
-                role_user ON role_permissions.role_id = role_user.role_id
-            WHERE
-                role_user.user_id = $1;
+                direct_permissions ON permissions.id = direct_permissions.permission_id;
         "#;
 
-        let permissions: Vec<permissions::Model> = permissions::Entity::find().from_raw_sql(
-            Statement::from_sql_and_values(
-                DbBackend::Postgres,
-                raw_sql,
-                vec![user_id.clone().into()]
+        self.get_permissions_by_raw_sql(raw_sql, (*user_id).into()).await
+    }
+
+    async fn get_user_group_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain> {
+        let raw_sql = r#"
+            WITH RECURSIVE group_user_roles AS (
+                SELECT group_roles.role_id FROM group_roles
+                JOIN group_users ON group_roles.group_id = group_users.group_id
+                WHERE group_users.user_id = $1
+                UNION
+                SELECT roles.parent_id FROM roles
+                JOIN group_user_roles ON roles.id = group_user_roles.role_id
+                WHERE roles.parent_id IS NOT NULL
+            ),
+            group_permissions AS (
+                SELECT role_permissions.permission_id FROM role_permissions
+                JOIN group_user_roles ON role_permissions.role_id = group_user_roles.role_id
+                EXCEPT
+                SELECT user_permissions.permission_id FROM user_permissions
+                WHERE user_permissions.user_id = $1 AND user_permissions.denied
             )
-        )
-            .all(&*self.db)
-            .await.unwrap();
+            SELECT
+                permissions.*
+            FROM
+                permissions
+            JOIN
+                group_permissions ON permissions.id = group_permissions.permission_id;
+        "#;
 
-        permissions.into_iter().map(map_permission_model_to_domain).collect()
+        self.get_permissions_by_raw_sql(raw_sql, (*user_id).into()).await
     }
 }
 
@@ -256,6 +421,42 @@ impl PermissionLinker for PermissionGateway {
             .unwrap()
             .is_some()
     }
+
+    async fn get_user_permission_effect(&self, user_id: &UserId, permission_id: &PermissionId) -> Option<PermissionEffect> {
+        user_permissions::Entity::find_by_id((*user_id, *permission_id))
+            .one(&*self.db)
+            .await
+            .unwrap()
+            .map(|link| match link.denied {
+                true => PermissionEffect::Deny,
+                false => PermissionEffect::Allow
+            })
+    }
+
+    async fn link_permission_to_user(&self, user_id: &UserId, permission_id: &PermissionId, effect: &PermissionEffect) {
+        let model = user_permissions::ActiveModel {
+            user_id: Set(*user_id),
+            permission_id: Set(*permission_id),
+            denied: Set(*effect == PermissionEffect::Deny),
+            created_at: Set(Utc::now()),
+        };
+
+        match user_permissions::Entity::find_by_id((*user_id, *permission_id)).one(&*self.db).await.unwrap() {
+            Some(_) => {
+                user_permissions::Entity::update(model).exec(&*self.db).await.unwrap();
+            }
+            None => {
+                user_permissions::Entity::insert(model).exec(&*self.db).await.unwrap();
+            }
+        }
+    }
+
+    async fn unlink_permission_from_user(&self, user_id: &UserId, permission_id: &PermissionId) {
+        user_permissions::Entity::delete_by_id((*user_id, *permission_id))
+            .exec(&*self.db)
+            .await
+            .unwrap();
+    }
 }
 
 fn map_permission_model_to_domain(permission: permissions::Model) -> PermissionDomain {
@@ -2,15 +2,19 @@ use core::option::Option;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use cached::{Cached, TimedCache};
-use sea_orm::{DbBackend, DbConn, EntityTrait, QueryFilter, QuerySelect, Statement};
+use sea_orm::{DbBackend, DbConn, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement};
 use sea_orm::ActiveValue::Set;
-use sea_orm::sea_query::{Condition, Expr};
+use sea_orm::sea_query::{Condition, Expr, Order};
 use sea_orm::sea_query::extension::postgres::PgExpr;
+use uuid::Uuid;
 
-use crate::adapters::database::models::{default_role, role_user, roles};
+use crate::adapters::database::models::{default_role, role_user, role_user_scopes, roles};
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::role_gateway::{RoleGateway as RoleGatewayTrait, RoleLinker, RoleReader, RoleRemover, RoleWriter};
-use crate::domain::models::role::{Role as RoleDomain, RoleId};
+use crate::domain::models::role::{Role as RoleDomain, RoleId, RoleUserLink};
 use crate::domain::models::user::UserId;
 
 pub struct RoleGateway{
@@ -74,9 +78,19 @@ impl RoleReader for RoleGateway {
         )
     }
 
-    async fn get_roles_range(&self, limit: &u64, offset: &u64) -> Vec<RoleDomain> {
-        let roles: Vec<roles::Model> = roles::Entity::find()
-            .offset(*offset)
+    async fn get_roles_range(&self, after: Option<&Cursor>, limit: &u64) -> Vec<RoleDomain> {
+        let mut query = roles::Entity::find();
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((roles::Entity, roles::Column::CreatedAt)).into(),
+                Expr::col((roles::Entity, roles::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        let roles: Vec<roles::Model> = query
+            .order_by_asc(roles::Column::CreatedAt)
+            .order_by_asc(roles::Column::Id)
             .limit(*limit)
             .all(&*self.db)
             .await
@@ -84,6 +98,10 @@ impl RoleReader for RoleGateway {
         roles.iter().map(|role| map_role_model_to_domain(role.clone())).collect()
     }
 
+    async fn count_roles(&self) -> u64 {
+        roles::Entity::find().count(&*self.db).await.unwrap()
+    }
+
     async fn get_user_roles(&self, user_id: &UserId) -> Vec<RoleDomain> {
         let raw_sql = r#"
             SELECT
@@ -108,6 +126,38 @@ impl RoleReader for RoleGateway {
 
         roles.into_iter().map(map_role_model_to_domain).collect()
     }
+
+    async fn get_role_ancestors(&self, role_id: &RoleId) -> Vec<RoleDomain> {
+        // UNION отбрасывает повторы, поэтому запрос завершится даже при цикле в данных
+        let raw_sql = r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT roles.parent_id AS role_id FROM roles
+                WHERE roles.id = $1 AND roles.parent_id IS NOT NULL
+                UNION
+                SELECT roles.parent_id FROM roles
+                JOIN ancestors ON roles.id = ancestors.role_id
+                WHERE roles.parent_id IS NOT NULL
+            )
+            SELECT
+                roles.*
+            FROM
+                roles
+            JOIN
+                ancestors ON roles.id = ancestors.role_id;
+        "#;
+
+        let roles: Vec<roles::Model> = roles::Entity::find().from_raw_sql(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![(*role_id).into()]
+            )
+        )
+            .all(&*self.db)
+            .await.unwrap();
+
+        roles.into_iter().map(map_role_model_to_domain).collect()
+    }
     
     async fn get_role_by_title_not_sensitive(&self, title: &String) -> Option<RoleDomain> {
         let role: Option<roles::Model> = roles::Entity::find().filter(
@@ -129,6 +179,20 @@ impl RoleReader for RoleGateway {
                 |(_, role)| map_role_model_to_domain(role.unwrap())
             )
     }
+
+    async fn get_users_with_started_role_links(&self, since: &DateTime<Utc>, until: &DateTime<Utc>) -> Vec<UserId> {
+        role_user::Entity::find()
+            .filter(
+                Expr::col(role_user::Column::StartsAt).gt(*since)
+                    .and(Expr::col(role_user::Column::StartsAt).lte(*until))
+            )
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|link| link.user_id)
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -139,7 +203,8 @@ impl RoleWriter for RoleGateway {
             title: Set(data.title.clone()),
             description: Set(data.description.clone()),
             created_at: Set(data.created_at),
-            updated_at: Set(data.updated_at.clone())
+            updated_at: Set(data.updated_at.clone()),
+            parent_id: Set(data.parent_id)
         };
 
         match roles::Entity::find_by_id(data.id).one(&*self.db).await.unwrap() {
@@ -165,10 +230,35 @@ impl RoleLinker for RoleGateway {
     async fn link_role_to_user(&self, role_id: &RoleId, user_id: &UserId) {
         role_user::Entity::insert(role_user::ActiveModel {
             role_id: Set(role_id.clone()),
-            user_id: Set(user_id.clone())
+            user_id: Set(user_id.clone()),
+            starts_at: Set(None),
+            expires_at: Set(None),
         }).exec(&*self.db).await.unwrap();
     }
 
+    async fn save_role_user_link(&self, link: &RoleUserLink) {
+        role_user::Entity::insert(role_user::ActiveModel {
+            role_id: Set(link.role_id),
+            user_id: Set(link.user_id),
+            starts_at: Set(link.starts_at),
+            expires_at: Set(link.expires_at),
+        }).exec(&*self.db).await.unwrap();
+
+        if link.scopes.is_empty() {
+            return
+        }
+
+        role_user_scopes::Entity::insert_many(
+            link.scopes.iter().map(|scope| role_user_scopes::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                user_id: Set(link.user_id),
+                role_id: Set(link.role_id),
+                resource_type: Set(scope.resource_type.clone()),
+                resource_id: Set(scope.resource_id.clone()),
+            })
+        ).exec(&*self.db).await.unwrap();
+    }
+
     async fn unlink_role_from_user(&self, role_id: &RoleId, user_id: &UserId) {
         role_user::Entity::delete_many()
             .filter(
@@ -191,6 +281,31 @@ impl RoleLinker for RoleGateway {
             .unwrap()
             .is_some()
     }
+
+    async fn remove_expired_role_links(&self, until: &DateTime<Utc>) -> Vec<UserId> {
+        let raw_sql = r#"
+            DELETE FROM
+                role_user
+            WHERE
+                role_user.expires_at <= $1
+            RETURNING
+                role_user.*;
+        "#;
+
+        role_user::Entity::find().from_raw_sql(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                raw_sql,
+                vec![(*until).into()]
+            )
+        )
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|link| link.user_id)
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -210,7 +325,8 @@ fn map_role_model_to_domain(role: roles::Model) -> RoleDomain {
         title: role.title,
         description: role.description,
         created_at: role.created_at,
-        updated_at: role.updated_at
+        updated_at: role.updated_at,
+        parent_id: role.parent_id
     }
 }
 
@@ -1,8 +1,11 @@
 use async_trait::async_trait;
-use sea_orm::{ColumnTrait, DbConn, EntityTrait, QueryFilter, QuerySelect};
+use sea_orm::{ColumnTrait, DbConn, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect};
 use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::{Expr, Order};
 
 use crate::adapters::database::models::services;
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::service_gateway::{
     ServiceGateway as ServiceGatewayTrait,
     ServiceReader,
@@ -44,10 +47,20 @@ impl ServiceReader for ServiceGateway {
             .map(map_service_model_to_domain)
     }
     
-    async fn get_services(&self, limit: &u64, offset: &u64) -> Vec<Service> {
-        services::Entity::find()
+    async fn get_services(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Service> {
+        let mut query = services::Entity::find();
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((services::Entity, services::Column::CreatedAt)).into(),
+                Expr::col((services::Entity, services::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        query
+            .order_by_asc(services::Column::CreatedAt)
+            .order_by_asc(services::Column::Id)
             .limit(*limit)
-            .offset(*offset)
             .all(&*self.db)
             .await
             .unwrap()
@@ -55,6 +68,10 @@ impl ServiceReader for ServiceGateway {
             .map(map_service_model_to_domain)
             .collect()
     }
+
+    async fn count_services(&self) -> u64 {
+        services::Entity::find().count(&*self.db).await.unwrap()
+    }
 }
 
 #[async_trait]
@@ -65,6 +82,7 @@ impl ServiceWriter for ServiceGateway {
             text_id: Set(data.text_id.clone()),
             title: Set(data.title.clone()),
             description: Set(data.description.clone()),
+            attribute_schema: Set(data.attribute_schema.clone()),
             created_at: Set(data.created_at),
             updated_at: Set(data.updated_at),
         };
@@ -98,6 +116,7 @@ fn map_service_model_to_domain(model: services::Model) -> Service {
         text_id: model.text_id,
         title: model.title,
         description: model.description,
+        attribute_schema: model.attribute_schema,
         created_at: model.created_at,
         updated_at: model.updated_at,
     }
@@ -4,11 +4,15 @@ use std::str::FromStr;
 use async_trait::async_trait;
 use deadpool_redis::Pool;
 use redis::cmd;
-use sea_orm::{DbBackend, DbConn, EntityTrait, FromQueryResult, JsonValue, QueryFilter, Statement};
+use sea_orm::{DbBackend, DbConn, EntityTrait, FromQueryResult, JsonValue, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Statement};
 use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::Expr;
+use sea_orm::sea_query::Order;
 
 use crate::adapters::database::models::sessions;
+use crate::adapters::database::pagination::after_cursor;
+use crate::adapters::database::permission_db::EFFECTIVE_PERMISSIONS_CTE;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::session_gateway::{
     SessionGateway as SessionGatewayTrait,
     SessionReader,
@@ -16,6 +20,7 @@ use crate::application::common::session_gateway::{
     SessionWriter
 };
 use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::resource_scope::{PermissionScopes, ResourceScope};
 use crate::domain::models::service::ServiceTextId;
 use crate::domain::models::session::{
     Session,
@@ -42,6 +47,56 @@ impl SessionGateway {
             db,
         }
     }
+
+    /// Разрешения владельца сессии, ограниченные областями ресурсов
+    async fn get_session_permission_scopes(&self, token_hash: &SessionTokenHash) -> PermissionScopes {
+        let raw_sql = format!(r#"
+            WITH RECURSIVE user_ref AS (
+                SELECT sessions.user_id FROM sessions
+                WHERE sessions.token_hash = $1
+            ),
+            {EFFECTIVE_PERMISSIONS_CTE}
+            SELECT
+                services.text_id AS service_text_id,
+                permissions.text_id AS permission_text_id,
+                scoped_permissions.resource_type,
+                scoped_permissions.resource_id
+            FROM
+                scoped_permissions
+            JOIN
+                permissions ON scoped_permissions.permission_id = permissions.id
+            JOIN
+                services ON permissions.service_id = services.id;
+        "#);
+
+        let raw_values: Vec<JsonValue> = JsonValue::find_by_statement(
+            Statement::from_sql_and_values(
+                DbBackend::Postgres,
+                &raw_sql,
+                vec![token_hash.as_str().into()],
+            )
+        )
+            .all(&*self.db)
+            .await.unwrap();
+
+        let mut scopes: PermissionScopes = HashMap::new();
+        raw_values.into_iter().for_each(
+            |value| {
+                let field = |name: &str| value.get(name).unwrap().as_str().unwrap().to_string();
+                
+                scopes.entry(field("service_text_id"))
+                    .or_default()
+                    .entry(field("permission_text_id"))
+                    .or_default()
+                    .push(ResourceScope {
+                        resource_type: field("resource_type"),
+                        resource_id: field("resource_id"),
+                    });
+            }
+        );
+
+        scopes
+    }
 }
 
 
@@ -59,8 +114,14 @@ impl SessionReader for SessionGateway {
     async fn get_session_by_token_hash(
         &self,
         token_hash: &SessionTokenHash
-    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>)> {
-        let raw_sql = r#"
+    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>, PermissionScopes)> {
+        // Разрешения ролей с учетом родительских ролей и личные выдачи за вычетом запретов
+        let raw_sql = format!(r#"
+            WITH RECURSIVE user_ref AS (
+                SELECT sessions.user_id FROM sessions
+                WHERE sessions.token_hash = $1
+            ),
+            {EFFECTIVE_PERMISSIONS_CTE}
             SELECT
                 sessions.*,
                 users.state::text AS user_state,
@@ -70,22 +131,21 @@ impl SessionReader for SessionGateway {
                 sessions
             JOIN
                 users ON sessions.user_id = users.id
-            JOIN
-                role_user ON users.id = role_user.user_id
-            JOIN
-                role_permissions ON role_user.role_id = role_permissions.role_id
-            JOIN
-                permissions ON role_permissions.permission_id = permissions.id
-            JOIN
-                services ON permissions.service_id = services.id
+            LEFT JOIN (
+                effective_permissions
+                JOIN
+                    permissions ON effective_permissions.permission_id = permissions.id
+                JOIN
+                    services ON permissions.service_id = services.id
+            ) ON TRUE
             WHERE
                 sessions.token_hash = $1;
-        "#;
+        "#);
         
         let raw_values: Vec<JsonValue> = JsonValue::find_by_statement(
             Statement::from_sql_and_values(
                 DbBackend::Postgres,
-                raw_sql,
+                &raw_sql,
                 vec![token_hash.as_str().into()],
             )
         )
@@ -101,27 +161,32 @@ impl SessionReader for SessionGateway {
             raw_values[0].get("user_state").unwrap().as_str().unwrap()
         ).unwrap();
 
+        // Сессия пользователя без глобальных разрешений возвращается одной строкой без разрешения
         let mut data: HashMap<ServiceTextId, Vec<PermissionTextId>> = HashMap::new();
         raw_values.iter().for_each(
             |value| {
-                let service_text_id: ServiceTextId = ServiceTextId::from_str(
-                    value.get("service_text_id").unwrap().as_str().unwrap()
-                ).unwrap();
-                let permission_text_id: PermissionTextId = PermissionTextId::from_str(
-                    value.get("permission_text_id").unwrap().as_str().unwrap()
-                ).unwrap();
+                let (Some(service_text_id), Some(permission_text_id)) = (
+                    value.get("service_text_id").and_then(|v| v.as_str()),
+                    value.get("permission_text_id").and_then(|v| v.as_str())
+                ) else {
+                    return
+                };
                 
-                data.entry(service_text_id).or_insert(Vec::new()).push(permission_text_id);
+                data.entry(service_text_id.to_string())
+                    .or_insert(Vec::new())
+                    .push(permission_text_id.to_string());
             }
         );
         
-        Some((session, user_state, data))
+        let scopes = self.get_session_permission_scopes(token_hash).await;
+        
+        Some((session, user_state, data, scopes))
     }
 
     async fn get_session_by_token_hash_from_cache(
         &self,
         token_hash: &SessionTokenHash
-    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>)> {
+    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>, PermissionScopes)> {
         let mut conn = self.cache_redis_pool.get().await.unwrap();
         match cmd("GET")
             .arg(token_hash.as_str())
@@ -132,8 +197,9 @@ impl SessionReader for SessionGateway {
                     serde_json::from_str::<(
                         Session,
                         UserState,
-                        HashMap<ServiceTextId, Vec<PermissionTextId>>
-                    )>(value.as_str()).unwrap()
+                        HashMap<ServiceTextId, Vec<PermissionTextId>>,
+                        PermissionScopes
+                    )>(value.as_str()).ok()?
                 )
             },
             Err(_) => {
@@ -154,6 +220,44 @@ impl SessionReader for SessionGateway {
             |model| map_session_model_to_domain(model.clone())
         ).collect()
     }
+
+    async fn get_user_sessions_range(
+        &self,
+        user_id: &UserId,
+        after: Option<&Cursor>,
+        limit: &u64
+    ) -> Vec<Session> {
+        let mut query = sessions::Entity::find().filter(
+            Expr::col(sessions::Column::UserId).eq(*user_id)
+        );
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                Expr::col((sessions::Entity, sessions::Column::CreatedAt)).into(),
+                Expr::col((sessions::Entity, sessions::Column::Id)).into(),
+                Order::Asc,
+                cursor
+            ));
+        }
+        query
+            .order_by_asc(sessions::Column::CreatedAt)
+            .order_by_asc(sessions::Column::Id)
+            .limit(*limit)
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(map_session_model_to_domain)
+            .collect()
+    }
+
+    async fn count_user_sessions(&self, user_id: &UserId) -> u64 {
+        sessions::Entity::find().filter(
+            Expr::col(sessions::Column::UserId).eq(*user_id)
+        )
+            .count(&*self.db)
+            .await
+            .unwrap()
+    }
 }
 
 #[async_trait]
@@ -168,7 +272,8 @@ impl SessionWriter for SessionGateway {
             os: Set(data.os.clone()),
             device: Set(data.device.clone()),
             created_at: Set(data.created_at),
-            updated_at: Set(data.updated_at.clone())
+            updated_at: Set(data.updated_at.clone()),
+            restricted: Set(data.restricted)
         };
 
         match sessions::Entity::find_by_id(data.id).one(&*self.db).await.unwrap() {
@@ -185,14 +290,16 @@ impl SessionWriter for SessionGateway {
         &self,
         data: &Session,
         user_state: &UserState,
-        permissions: &HashMap<ServiceTextId, Vec<PermissionTextId>>
+        permissions: &HashMap<ServiceTextId, Vec<PermissionTextId>>,
+        scopes: &PermissionScopes
     ) {
         let mut conn = self.cache_redis_pool.get().await.unwrap();
         
         let serde_json = serde_json::to_string(&(
             data.clone(),
             user_state.clone(),
-            permissions.clone()
+            permissions.clone(),
+            scopes.clone()
         )).unwrap();
         
         cmd("SET")
@@ -241,6 +348,22 @@ impl SessionRemover for SessionGateway {
             .await
             .ok();
     }
+
+    async fn remove_user_sessions_from_cache(&self, user_id: &UserId) {
+        let token_hashes = self.get_user_sessions(user_id).await.into_iter()
+            .map(|session| session.token_hash)
+            .collect::<Vec<SessionTokenHash>>();
+        
+        if token_hashes.is_empty() {
+            return
+        }
+        
+        cmd("DEL")
+            .arg(token_hashes)
+            .query_async::<_, ()>(&mut self.cache_redis_pool.get().await.unwrap())
+            .await
+            .ok();
+    }
 }
 
 impl SessionGatewayTrait for SessionGateway {}
@@ -256,5 +379,6 @@ fn map_session_model_to_domain(model: sessions::Model) -> Session {
         device: model.device,
         created_at: model.created_at,
         updated_at: model.updated_at,
+        restricted: model.restricted,
     }
 }
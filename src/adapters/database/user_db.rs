@@ -1,36 +1,121 @@
 use core::option::Option;
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use cached::{Cached, TimedCache};
-use sea_orm::{DbConn, EntityTrait, QueryFilter, QuerySelect};
+use chrono::{DateTime, Utc};
+use sea_orm::{ColumnTrait, ConnectionTrait, DbConn, DbErr, EntityTrait, FromQueryResult, PaginatorTrait, QueryFilter, QueryOrder, QueryResult, QuerySelect, QueryTrait, Select, SqlErr, TransactionTrait};
 use sea_orm::ActiveValue::Set;
-use sea_orm::sea_query::{Condition, Expr};
+use sea_orm::sea_query::{Condition, Expr, NullOrdering, OnConflict, Order, Query, SimpleExpr};
 use sea_orm::sea_query::extension::postgres::PgExpr;
 
 use crate::adapters::database::models::sea_orm_active_enums::UserState;
-use crate::adapters::database::models::users;
+use crate::adapters::database::models::{role_user, users};
+use crate::adapters::database::pagination::after_cursor;
+use crate::application::common::pagination::Cursor;
 use crate::application::common::user_gateway::{
+    SortOrder,
+    UserFilter,
     UserGateway as UserGatewayTrait,
     UserReader,
+    UserSortField,
     UserWriter
 };
+use crate::domain::models::role::RoleId;
 use crate::domain::models::user::{User as UserDomain, UserId};
 use crate::domain::models::user::UserState as UserStateDomain;
+use crate::domain::services::normalizer::NormalizerService;
+
+/// Размер пачки при пересчете канонических форм
+const CANONICAL_BATCH_SIZE: u64 = 1000;
 
 pub struct UserGateway{
     pub db: Box<DbConn>,
+    normalizer: NormalizerService,
     cache_user_by_id: Arc<Mutex<TimedCache<UserId, users::Model>>>,
 }
 
+/// Итог пересчета канонических форм
+pub struct CanonicalRefresh {
+    pub updated: u64,
+    /// Канонические имена пользователей, которые уже заняты другой учетной записью
+    pub duplicate_usernames: Vec<String>,
+    /// Канонические email, которые уже заняты другой учетной записью
+    pub duplicate_emails: Vec<String>,
+}
+
 impl UserGateway {
-    pub fn new(db: Box<DbConn>) -> Self {
+    pub fn new(db: Box<DbConn>, normalizer: NormalizerService) -> Self {
         UserGateway {
             db,
+            normalizer,
             cache_user_by_id: Arc::new(Mutex::new(TimedCache::with_lifespan(3))),
 
         }
     }
+
+    /// **refresh_canonical** - пересчет канонических форм всех пользователей
+    ///
+    /// Нужен после миграции и после изменения правил нормализации в конфигурации.
+    /// Канонические формы уникальны, поэтому совпадающая форма не сохраняется,
+    /// а только возвращается, такие учетные записи нужно объединить вручную.
+    pub async fn refresh_canonical(&self) -> CanonicalRefresh {
+        let mut updated = 0;
+        let mut duplicate_usernames: Vec<String> = vec![];
+        let mut duplicate_emails: Vec<String> = vec![];
+        let mut paginator = users::Entity::find()
+            .order_by_asc(users::Column::Id)
+            .paginate(&*self.db, CANONICAL_BATCH_SIZE);
+        while let Some(batch) = paginator.fetch_and_next().await.unwrap() {
+            for user in batch {
+                let username_canonical = self.normalizer.canonical_username(&user.username);
+                let email_canonical = self.normalizer.canonical_email(&user.email);
+                let mut is_updated = false;
+                if user.username_canonical != username_canonical {
+                    match self.update_canonical(&user.id, users::Column::UsernameCanonical, &username_canonical).await {
+                        true => is_updated = true,
+                        false => duplicate_usernames.push(username_canonical)
+                    }
+                }
+                if user.email_canonical != email_canonical {
+                    match self.update_canonical(&user.id, users::Column::EmailCanonical, &email_canonical).await {
+                        true => is_updated = true,
+                        false => duplicate_emails.push(email_canonical)
+                    }
+                }
+                if is_updated {
+                    updated += 1;
+                }
+            }
+        }
+
+        duplicate_usernames.sort();
+        duplicate_usernames.dedup();
+        duplicate_emails.sort();
+        duplicate_emails.dedup();
+        CanonicalRefresh {
+            updated,
+            duplicate_usernames,
+            duplicate_emails,
+        }
+    }
+
+    /// Возвращает false, если каноническая форма уже занята другой учетной записью
+    async fn update_canonical(&self, user_id: &UserId, column: users::Column, value: &str) -> bool {
+        let result = users::Entity::update_many()
+            .col_expr(column, Expr::value(value))
+            .filter(users::Column::Id.eq(*user_id))
+            .exec(&*self.db)
+            .await;
+        match result {
+            Ok(_) => true,
+            Err(error) => match error.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => false,
+                _ => panic!("Failed to update canonical form: {}", error)
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -79,19 +164,61 @@ impl UserReader for UserGateway {
         )
     }
 
-    async fn get_users_list(&self, limit: &u64, offset: &u64) -> Vec<UserDomain> {
-        let users: Vec<users::Model> = users::Entity::find()
-            .limit(limit.clone())
-            .offset(offset.clone())
+    async fn get_users_by_filter(
+        &self,
+        filter: &UserFilter,
+        sort: &UserSortField,
+        order: &SortOrder,
+        after: Option<&Cursor>,
+        limit: &u64
+    ) -> Vec<(UserDomain, Option<DateTime<Utc>>)> {
+        let order = match order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc,
+        };
+        let sort_expr: SimpleExpr = match sort {
+            UserSortField::Username => Expr::col((users::Entity, users::Column::Username)).into(),
+            UserSortField::Email => Expr::col((users::Entity, users::Column::Email)).into(),
+            UserSortField::CreatedAt => Expr::col((users::Entity, users::Column::CreatedAt)).into(),
+            UserSortField::LastLogin => last_login_expr(),
+        };
+
+        let mut query = filtered_users_query(filter)
+            .column_as(last_login_expr(), "last_login");
+        if let Some(cursor) = after {
+            query = query.filter(after_cursor(
+                sort_expr.clone(),
+                Expr::col((users::Entity, users::Column::Id)).into(),
+                order.clone(),
+                cursor
+            ));
+        }
+        // Пользователи без входов всегда в конце списка
+        QueryTrait::query(&mut query)
+            .order_by_expr_with_nulls(sort_expr, order, NullOrdering::Last)
+            .order_by((users::Entity, users::Column::Id), Order::Asc);
+
+        let users: Vec<UserWithLastLogin> = query
+            .limit(*limit)
+            .into_model::<UserWithLastLogin>()
             .all(&*self.db)
             .await
             .unwrap();
-        users.iter().map(|user| map_user_model_to_domain(user.clone())).collect()
+        users.into_iter()
+            .map(|UserWithLastLogin(user, last_login)| (map_user_model_to_domain(user), last_login))
+            .collect()
+    }
+
+    async fn count_users_by_filter(&self, filter: &UserFilter) -> u64 {
+        filtered_users_query(filter)
+            .count(&*self.db)
+            .await
+            .unwrap()
     }
 
     async fn get_user_by_username_not_sensitive(&self, username: &String) -> Option<UserDomain> {
         let user: Option<users::Model> = users::Entity::find().filter(
-                Expr::col(users::Column::Username).ilike(username)
+                users::Column::UsernameCanonical.eq(self.normalizer.canonical_username(username))
             )
             .one(&*self.db)
             .await
@@ -105,7 +232,7 @@ impl UserReader for UserGateway {
 
     async fn get_user_by_email_not_sensitive(&self, email: &String) -> Option<UserDomain> {
         let user: Option<users::Model> = users::Entity::find().filter(
-                Expr::col(users::Column::Email).ilike(email)
+                users::Column::EmailCanonical.eq(self.normalizer.canonical_email(email))
             )
             .one(&*self.db)
             .await
@@ -116,27 +243,50 @@ impl UserReader for UserGateway {
             None => None
         }
     }
+
+    async fn get_user_by_phone(&self, phone: &str) -> Option<UserDomain> {
+        let user: Option<users::Model> = users::Entity::find().filter(
+                Expr::col(users::Column::Phone).eq(phone)
+            )
+            .one(&*self.db)
+            .await
+            .unwrap();
+
+        user.map(map_user_model_to_domain)
+    }
+
+    async fn get_users_by_usernames_or_emails_not_sensitive(
+        &self,
+        usernames: &[String],
+        emails: &[String]
+    ) -> Vec<UserDomain> {
+        if usernames.is_empty() && emails.is_empty() {
+            return vec![]
+        }
+        
+        users::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(users::Column::UsernameCanonical.is_in(
+                        usernames.iter().map(|username| self.normalizer.canonical_username(username))
+                    ))
+                    .add(users::Column::EmailCanonical.is_in(
+                        emails.iter().map(|email| self.normalizer.canonical_email(email))
+                    ))
+            )
+            .all(&*self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(map_user_model_to_domain)
+            .collect()
+    }
 }
 
 #[async_trait]
 impl UserWriter for UserGateway {
     async fn save_user(&self, data: &UserDomain) {
-        let user_model = users::ActiveModel {
-            id: Set(data.id),
-            username: Set(data.username.clone()),
-            email: Set(data.email.clone()),
-            first_name: Set(data.first_name.clone()),
-            last_name: Set(data.last_name.clone()),
-            state: Set(match data.state {
-                UserStateDomain::Active => UserState::Active,
-                UserStateDomain::Inactive => UserState::Inactive,
-                UserStateDomain::Banned => UserState::Banned,
-                UserStateDomain::Deleted => UserState::Deleted
-            }),
-            hashed_password: Set(data.hashed_password.clone()),
-            created_at: Set(data.created_at),
-            updated_at: Set(data.updated_at.clone())
-        };
+        let user_model = map_user_domain_to_model(data, &self.normalizer);
 
         match users::Entity::find_by_id(data.id).one(&*self.db).await.unwrap() {
             Some(_) => {
@@ -147,9 +297,144 @@ impl UserWriter for UserGateway {
             }
         }
     }
+
+    async fn import_users(&self, data: &[UserDomain], role_links: &[(RoleId, UserId)]) -> Vec<UserId> {
+        if data.is_empty() {
+            return vec![]
+        }
+        
+        let txn = self.db.begin().await.unwrap();
+        
+        let mut query = users::Entity::insert_many(
+            data.iter().map(|user| map_user_domain_to_model(user, &self.normalizer))
+        ).on_conflict(OnConflict::new().do_nothing().to_owned()).into_query();
+        query.returning_col(users::Column::Id);
+        
+        let inserted: HashSet<UserId> = txn.query_all(txn.get_database_backend().build(&query))
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.try_get("", "id").unwrap())
+            .collect();
+        
+        let role_links = role_links.iter()
+            .filter(|(_, user_id)| inserted.contains(user_id))
+            .map(|(role_id, user_id)| role_user::ActiveModel {
+                role_id: Set(*role_id),
+                user_id: Set(*user_id),
+                starts_at: Set(None),
+                expires_at: Set(None),
+            })
+            .collect::<Vec<role_user::ActiveModel>>();
+        if !role_links.is_empty() {
+            role_user::Entity::insert_many(role_links).exec(&txn).await.unwrap();
+        }
+        
+        txn.commit().await.unwrap();
+        
+        data.iter().map(|user| user.id).filter(|user_id| !inserted.contains(user_id)).collect()
+    }
+}
+
+
+
+struct UserWithLastLogin(users::Model, Option<DateTime<Utc>>);
+
+impl FromQueryResult for UserWithLastLogin {
+    fn from_query_result(res: &QueryResult, pre: &str) -> Result<Self, DbErr> {
+        Ok(Self(
+            users::Model::from_query_result(res, pre)?,
+            res.try_get(pre, "last_login")?
+        ))
+    }
 }
 
+/// Время последнего успешного входа пользователя
+fn last_login_expr() -> SimpleExpr {
+    Expr::cust(
+        "(SELECT MAX(access_logs.created_at) FROM access_logs \
+        WHERE access_logs.user_id = users.id AND access_logs.is_success)"
+    )
+}
 
+/// Экранирование спецсимволов LIKE для поиска по префиксу
+fn like_prefix(value: &str) -> String {
+    format!(
+        "{}%",
+        value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    )
+}
+
+fn filtered_users_query(filter: &UserFilter) -> Select<users::Entity> {
+    let mut condition = Condition::all();
+
+    if let Some(username) = &filter.username {
+        condition = condition.add(Expr::col(users::Column::Username).ilike(like_prefix(username)));
+    }
+    if let Some(email) = &filter.email {
+        condition = condition.add(Expr::col(users::Column::Email).ilike(like_prefix(email)));
+    }
+    if let Some(state) = &filter.state {
+        condition = condition.add(users::Column::State.eq(match state {
+            UserStateDomain::Active => UserState::Active,
+            UserStateDomain::Inactive => UserState::Inactive,
+            UserStateDomain::Banned => UserState::Banned,
+            UserStateDomain::Deleted => UserState::Deleted
+        }));
+    }
+    if let Some(role_id) = &filter.role_id {
+        condition = condition.add(users::Column::Id.in_subquery(
+            Query::select()
+                .column(role_user::Column::UserId)
+                .from(role_user::Entity)
+                .and_where(Expr::col(role_user::Column::RoleId).eq(*role_id))
+                .to_owned()
+        ));
+    }
+    if let Some(created_from) = &filter.created_from {
+        condition = condition.add(users::Column::CreatedAt.gte(*created_from));
+    }
+    if let Some(created_to) = &filter.created_to {
+        condition = condition.add(users::Column::CreatedAt.lte(*created_to));
+    }
+    if let Some(last_login_from) = &filter.last_login_from {
+        condition = condition.add(Expr::expr(last_login_expr()).gte(*last_login_from));
+    }
+    if let Some(last_login_to) = &filter.last_login_to {
+        condition = condition.add(Expr::expr(last_login_expr()).lte(*last_login_to));
+    }
+
+    users::Entity::find().filter(condition)
+}
+
+fn map_user_domain_to_model(data: &UserDomain, normalizer: &NormalizerService) -> users::ActiveModel {
+    users::ActiveModel {
+        id: Set(data.id),
+        username: Set(data.username.clone()),
+        email: Set(data.email.clone()),
+        first_name: Set(data.first_name.clone()),
+        last_name: Set(data.last_name.clone()),
+        state: Set(match data.state {
+            UserStateDomain::Active => UserState::Active,
+            UserStateDomain::Inactive => UserState::Inactive,
+            UserStateDomain::Banned => UserState::Banned,
+            UserStateDomain::Deleted => UserState::Deleted
+        }),
+        hashed_password: Set(data.hashed_password.clone()),
+        created_at: Set(data.created_at),
+        updated_at: Set(data.updated_at.clone()),
+        attributes: Set(data.attributes.clone()),
+        avatar_id: Set(data.avatar_id),
+        password_changed_at: Set(data.password_changed_at),
+        must_change_password: Set(data.must_change_password),
+        phone: Set(data.phone.clone()),
+        phone_verified: Set(data.phone_verified),
+        locale: Set(data.locale.clone()),
+        timezone: Set(data.timezone.clone()),
+        username_canonical: Set(normalizer.canonical_username(&data.username)),
+        email_canonical: Set(normalizer.canonical_email(&data.email))
+    }
+}
 
 fn map_user_model_to_domain(user: users::Model) -> UserDomain {
     UserDomain {
@@ -166,7 +451,15 @@ fn map_user_model_to_domain(user: users::Model) -> UserDomain {
         },
         hashed_password: user.hashed_password,
         created_at: user.created_at,
-        updated_at: user.updated_at
+        updated_at: user.updated_at,
+        attributes: user.attributes,
+        avatar_id: user.avatar_id,
+        password_changed_at: user.password_changed_at,
+        must_change_password: user.must_change_password,
+        phone: user.phone,
+        phone_verified: user.phone_verified,
+        locale: user.locale,
+        timezone: user.timezone
     }
 }
 
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::common::breached_password_checker::BreachedPasswordChecker;
+
+/// Список загружается в память один раз и разделяется между воркерами
+#[derive(Clone, Default)]
+pub struct FileBreachedPasswordChecker {
+    passwords: Arc<HashSet<String>>,
+}
+
+impl FileBreachedPasswordChecker {
+    
+    /// **from_file** - загрузка списка из файла, по одному паролю в строке
+    pub fn from_file(path: &str) -> std::io::Result<Self> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> std::io::Result<Self> {
+        let mut passwords = HashSet::new();
+        for line in reader.lines() {
+            let line = line?;
+            let password = line.trim_end_matches('\r');
+            if !password.is_empty() {
+                passwords.insert(password.to_lowercase());
+            }
+        }
+        Ok(Self {
+            passwords: Arc::new(passwords),
+        })
+    }
+}
+
+#[async_trait]
+impl BreachedPasswordChecker for FileBreachedPasswordChecker {
+
+    /// **is_breached** - метод проверки пароля.
+    ///
+    /// Сравнение выполняется без учета регистра, чтобы варианты
+    /// вроде `Password1` не проходили мимо `password1` из списка.
+    async fn is_breached(&self, password: &str) -> bool {
+        self.passwords.contains(&password.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_is_breached() {
+        let checker = FileBreachedPasswordChecker::from_reader(
+            Cursor::new("123456\r\nqwerty123\n\npassword1\n")
+        ).unwrap();
+
+        assert!(checker.is_breached("qwerty123").await);
+        assert!(checker.is_breached("Password1").await);
+        assert!(!checker.is_breached("").await);
+        assert!(!checker.is_breached("c0rrect-h0rse").await);
+    }
+}
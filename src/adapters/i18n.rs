@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::domain::exceptions::Message;
+pub use crate::domain::models::user::DEFAULT_LOCALE;
+use crate::domain::models::user::SUPPORTED_LOCALES;
+
+/// Каталоги переводов встраиваются в бинарный файл при сборке,
+/// для каждого языка из `SUPPORTED_LOCALES`
+const CATALOG_SOURCES: [(&str, &str); SUPPORTED_LOCALES.len()] = [
+    ("ru", include_str!("../../locales/ru.yaml")),
+    ("en", include_str!("../../locales/en.yaml")),
+];
+
+type Catalog = HashMap<String, String>;
+
+fn catalogs() -> &'static HashMap<&'static str, Catalog> {
+    static CATALOGS: OnceLock<HashMap<&'static str, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        CATALOG_SOURCES.iter().map(|(locale, source)| {
+            let catalog = serde_yaml::from_str(source).unwrap_or_else(|error| {
+                panic!("Invalid catalog {}: {}", locale, error)
+            });
+            (*locale, catalog)
+        }).collect()
+    })
+}
+
+/// **negotiate_locale** - выбор языка по заголовку Accept-Language
+///
+/// Языки перебираются по убыванию веса `q`, регион не учитывается (`en-US` -> `en`).
+pub fn negotiate_locale(accept_language: Option<&str>) -> &'static str {
+    let accept_language = match accept_language {
+        Some(accept_language) => accept_language,
+        None => return DEFAULT_LOCALE
+    };
+
+    let mut ranges = accept_language.split(',').filter_map(|range| {
+        let mut parts = range.split(';').map(str::trim);
+        let tag = parts.next().filter(|tag| !tag.is_empty())?;
+        let weight = parts
+            .find_map(|param| param.strip_prefix("q="))
+            .map(|weight| weight.parse::<f32>().unwrap_or(0.0))
+            .unwrap_or(1.0);
+        Some((tag, weight))
+    }).filter(|(_, weight)| *weight > 0.0).collect::<Vec<(&str, f32)>>();
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    ranges.iter().find_map(|(tag, _)| {
+        let language = tag.split('-').next().unwrap_or_default().to_lowercase();
+        SUPPORTED_LOCALES.iter().copied().find(|locale| *locale == language)
+    }).unwrap_or(DEFAULT_LOCALE)
+}
+
+/// **translate** - текст сообщения на выбранном языке
+///
+/// Если в каталоге языка нет перевода, берется язык по умолчанию, затем сам код.
+/// Вложенные сообщения дописываются через `; `.
+pub fn translate(message: &Message, locale: &str) -> String {
+    let catalogs = catalogs();
+    let template = catalogs.get(locale)
+        .and_then(|catalog| catalog.get(message.code))
+        .or_else(|| catalogs[DEFAULT_LOCALE].get(message.code));
+
+    let text = match template {
+        Some(template) => message.args.iter().fold(template.clone(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        }),
+        None => message.code.to_string()
+    };
+
+    if message.details.is_empty() {
+        return text
+    }
+    format!(
+        "{}: {}",
+        text,
+        message.details.iter()
+            .map(|detail| translate(detail, locale))
+            .collect::<Vec<String>>()
+            .join("; ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_locale() {
+        assert_eq!(negotiate_locale(None), "ru");
+        assert_eq!(negotiate_locale(Some("en-US,en;q=0.9")), "en");
+        assert_eq!(negotiate_locale(Some("de, ru;q=0.5, en;q=0.8")), "en");
+        assert_eq!(negotiate_locale(Some("en;q=0, fr")), "ru");
+        assert_eq!(negotiate_locale(Some("*")), "ru");
+    }
+
+    #[test]
+    fn test_translate() {
+        let message = Message::new("username_length").arg("min", 3).arg("max", 32);
+        assert_eq!(translate(&message, "en"), "Username must be between 3 and 32 characters long");
+        assert_eq!(translate(&Message::new("unknown_code"), "en"), "unknown_code");
+
+        let message = Message::new("password_policy").details(vec![
+            Message::new("password_digit"),
+            Message::new("password_weak"),
+        ]);
+        assert_eq!(
+            translate(&message, "en"),
+            "Password does not meet the password policy: \
+            Password must contain at least one digit; Password is too weak"
+        );
+    }
+
+    #[test]
+    fn test_catalogs_cover_supported_locales() {
+        let locales = CATALOG_SOURCES.iter().map(|(locale, _)| *locale).collect::<Vec<_>>();
+        assert_eq!(locales, SUPPORTED_LOCALES);
+    }
+
+    #[test]
+    fn test_catalogs_have_same_codes() {
+        let catalogs = catalogs();
+        let mut default_codes = catalogs[DEFAULT_LOCALE].keys().collect::<Vec<_>>();
+        default_codes.sort();
+        for (locale, catalog) in catalogs.iter() {
+            let mut codes = catalog.keys().collect::<Vec<_>>();
+            codes.sort();
+            assert_eq!(codes, default_codes, "catalog {}", locale);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use sha2::Sha256;
+
+/// **LegacyScheme** - схемы хешей паролей, перенесенных из прежних систем
+#[derive(Debug, PartialEq)]
+pub enum LegacyScheme {
+    /// `$2a$`, `$2b$`, `$2y$` - bcrypt в формате modular crypt (PHP `password_hash`)
+    Bcrypt,
+    /// `pbkdf2_sha256$<итерации>$<соль>$<хеш>` - формат Django
+    DjangoPbkdf2Sha256,
+}
+
+impl LegacyScheme {
+    pub fn detect(hash: &str) -> Option<Self> {
+        if ["$2a$", "$2b$", "$2y$"].iter().any(|prefix| hash.starts_with(prefix)) {
+            return Some(Self::Bcrypt)
+        }
+        if hash.starts_with("pbkdf2_sha256$") {
+            return Some(Self::DjangoPbkdf2Sha256)
+        }
+        None
+    }
+
+    pub fn verify(&self, value: &str, hash: &str) -> bool {
+        match self {
+            Self::Bcrypt => bcrypt::verify(value, hash).unwrap_or(false),
+            Self::DjangoPbkdf2Sha256 => verify_django_pbkdf2_sha256(value, hash)
+        }
+    }
+}
+
+fn verify_django_pbkdf2_sha256(value: &str, hash: &str) -> bool {
+    let parts: Vec<&str> = hash.split('$').collect();
+    let (iterations, salt, expected) = match parts.as_slice() {
+        [_, iterations, salt, expected] => match (iterations.parse::<u32>(), STANDARD.decode(expected)) {
+            (Ok(iterations), Ok(expected)) if iterations > 0 && !expected.is_empty() => {
+                (iterations, *salt, expected)
+            },
+            _ => return false
+        },
+        _ => return false
+    };
+
+    let mut derived = vec![0u8; expected.len()];
+    pbkdf2::pbkdf2_hmac::<Sha256>(value.as_bytes(), salt.as_bytes(), iterations, &mut derived);
+
+    // Сравнение за постоянное время
+    derived.iter().zip(expected.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bcrypt() {
+        let hash = bcrypt::hash("Secret123", 4).unwrap().replacen("$2b$", "$2y$", 1);
+        let scheme = LegacyScheme::detect(&hash).unwrap();
+
+        assert_eq!(scheme, LegacyScheme::Bcrypt);
+        assert!(scheme.verify("Secret123", &hash));
+        assert!(!scheme.verify("secret123", &hash));
+    }
+
+    #[test]
+    fn test_django_pbkdf2_sha256() {
+        let hash = "pbkdf2_sha256$1000$qwertysalt$bKPC5LQnsfXVYFThXegOwMyU2rqFwr3VwMfQoOOXijs=";
+        let scheme = LegacyScheme::detect(hash).unwrap();
+
+        assert_eq!(scheme, LegacyScheme::DjangoPbkdf2Sha256);
+        assert!(scheme.verify("Secret123", hash));
+        assert!(!scheme.verify("Secret1234", hash));
+        assert!(!scheme.verify("Secret123", "pbkdf2_sha256$0$qwertysalt$"));
+    }
+}
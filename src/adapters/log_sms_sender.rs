@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde_json::json;
+use tokio::io::AsyncWriteExt;
+
+use crate::adapters::i18n::translate;
+use crate::application::common::sms_sender::SmsSender;
+use crate::domain::exceptions::Message;
+
+/// **LogSmsSender** - отправитель SMS для разработки и тестов
+///
+/// Сообщения не отправляются, а пишутся в лог и, если задан файл,
+/// дописываются в него построчно в формате JSON.
+pub struct LogSmsSender {
+    path: Option<PathBuf>,
+}
+
+impl LogSmsSender {
+    pub fn new(path: Option<&str>) -> Self {
+        Self {
+            path: path.map(PathBuf::from),
+        }
+    }
+}
+
+#[async_trait]
+impl SmsSender for LogSmsSender {
+    async fn send(&self, to: &str, content: &Message, locale: &str, ttl: u32) {
+        let content = translate(content, locale);
+        log::info!("SMS to {}: {}", to, content);
+        
+        if let Some(path) = &self.path {
+            let line = json!({
+                "to": to,
+                "content": content,
+                "ttl": ttl,
+                "sent_at": chrono::Utc::now(),
+            }).to_string();
+            
+            let file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await;
+            
+            match file {
+                Ok(mut file) => {
+                    // tokio::fs::File пишет в фоне, без flush строка может потеряться при drop
+                    let result = match file.write_all(format!("{}\n", line).as_bytes()).await {
+                        Ok(_) => file.flush().await,
+                        Err(error) => Err(error)
+                    };
+                    if let Err(error) = result {
+                        log::error!("Failed to write SMS to {}: {}", path.display(), error);
+                    }
+                },
+                Err(error) => log::error!("Failed to open SMS file {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_send_to_file() {
+        let path = std::env::temp_dir().join(format!("sms-{}.log", uuid::Uuid::new_v4()));
+        let sender = LogSmsSender::new(path.to_str());
+        
+        let message = |code: u32| Message::new("sms_phone_confirm_code")
+            .arg("company", "ACME")
+            .arg("code", code);
+        sender.send("+79991234567", &message(123456), "ru", 300).await;
+        sender.send("+79991234568", &message(654321), "en", 300).await;
+        
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        
+        let lines: Vec<Value> = content.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["to"], "+79991234567");
+        assert_eq!(lines[0]["content"], "ACME: код подтверждения номера 123456");
+        assert_eq!(lines[1]["content"], "ACME: phone confirmation code 654321");
+    }
+}
@@ -1,6 +1,14 @@
 pub mod database;
 pub mod argon2_password_hasher;
+pub mod legacy_password_hash;
 pub mod sha256_session_hasher;
 pub mod auth;
 pub mod redis_confirm_code;
 pub mod rmq_email_sender;
+pub mod redis_rate_limiter;
+pub mod redis_one_time_token;
+pub mod s3_file_storage;
+pub mod webp_image_processor;
+pub mod file_breached_password_checker;
+pub mod log_sms_sender;
+pub mod i18n;
\ No newline at end of file
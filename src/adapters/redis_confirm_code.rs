@@ -4,6 +4,7 @@ use rand::Rng;
 use redis::AsyncCommands;
 
 use crate::application::common::confirm_code::ConfirmCode;
+use crate::domain::exceptions::Message;
 
 pub struct RedisConfirmCode {
     redis: Box<Pool>,
@@ -30,17 +31,17 @@ impl ConfirmCode for RedisConfirmCode {
     /// Пользователю дается 3 попытки на то, чтобы ввести правильный код подтверждения.
     /// Если пользователь превысил лимит попыток, то пользователь дожидается ttl и
     /// запрашивает новый код подтверждения.
-    async fn confirm(&self, key: &str, code: u32) -> Result<(), String> {
+    async fn confirm(&self, key: &str, code: u32) -> Result<(), Message> {
         let mut redis = self.redis.get().await.unwrap();
 
         let stored_data: String = match redis.get(key).await.unwrap() {
             Some(data) => data,
-            None => return Err("Сначала запросите код подтверждения".to_string())
+            None => return Err(Message::new("confirm_code_not_requested"))
         };
         let (stored_code, attempts) = stored_data.split_once(':').unwrap();
 
         if attempts.parse::<u32>().unwrap() >= 3 {
-            return Err("Превышено количество попыток".to_string());
+            return Err(Message::new("confirm_code_attempts_exceeded"));
         }
 
         if stored_code.parse::<u32>().unwrap() == code {
@@ -56,7 +57,7 @@ impl ConfirmCode for RedisConfirmCode {
                 let _: i32 = redis.expire(key, ttl).await.unwrap();
             }
 
-            Err("Неверный код".to_string())
+            Err(Message::new("confirm_code_invalid"))
         }
     }
 
@@ -64,12 +65,12 @@ impl ConfirmCode for RedisConfirmCode {
     ///
     /// Генерируется шестизначный код и в редис записывается код и 
     /// количество попыток равное нулю.
-    async fn generate(&self, key: &str) -> Result<u32, String> {
+    async fn generate(&self, key: &str) -> Result<u32, Message> {
         let mut redis = self.redis.get().await.unwrap();
 
         let data: Option<String> = redis.get(key).await.unwrap();
         if data.is_some() {
-            return Err("Код уже отправлен".to_string());
+            return Err(Message::new("confirm_code_already_sent"));
         }
 
         let code: u32 = rand::thread_rng().gen_range(100000..=999999);
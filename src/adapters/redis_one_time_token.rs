@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use deadpool_redis::Pool;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use redis::AsyncCommands;
+
+use crate::application::common::one_time_token::OneTimeToken;
+
+pub struct RedisOneTimeToken {
+    redis: Box<Pool>,
+}
+
+impl RedisOneTimeToken {
+    pub fn new(redis: Box<Pool>) -> Self {
+        Self {
+            redis,
+        }
+    }
+}
+
+#[async_trait]
+impl OneTimeToken for RedisOneTimeToken {
+
+    /// **issue** - метод выпуска токена.
+    ///
+    /// Генерируется случайная строка из 48 символов, под которой
+    /// в редис записываются данные со временем жизни `ttl`.
+    async fn issue(&self, payload: &str, ttl: u32) -> String {
+        let mut redis = self.redis.get().await.unwrap();
+
+        let token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(48)
+            .map(char::from)
+            .collect();
+
+        let _: String = redis.set_ex(format!("token:{}", token), payload, ttl as u64).await.unwrap();
+
+        token
+    }
+
+    /// **redeem** - метод погашения токена.
+    ///
+    /// Данные читаются и удаляются одной командой (GETDEL),
+    /// поэтому токен невозможно погасить дважды.
+    async fn redeem(&self, token: &str) -> Option<String> {
+        let mut redis = self.redis.get().await.unwrap();
+
+        redis::cmd("GETDEL")
+            .arg(format!("token:{}", token))
+            .query_async::<_, Option<String>>(&mut redis)
+            .await
+            .unwrap()
+    }
+
+    /// **peek** - метод чтения данных токена без его удаления.
+    async fn peek(&self, token: &str) -> Option<String> {
+        let mut redis = self.redis.get().await.unwrap();
+
+        redis.get(format!("token:{}", token)).await.unwrap()
+    }
+}
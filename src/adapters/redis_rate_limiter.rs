@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use deadpool_redis::Pool;
+use redis::AsyncCommands;
+
+use crate::application::common::rate_limiter::RateLimiter;
+
+pub struct RedisRateLimiter {
+    redis: Box<Pool>,
+}
+
+impl RedisRateLimiter {
+    pub fn new(redis: Box<Pool>) -> Self {
+        Self {
+            redis,
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for RedisRateLimiter {
+
+    /// **acquire** - метод захвата слота.
+    ///
+    /// Ключ записывается в редис только если его там нет (SET NX),
+    /// и живет `period` секунд. Пока ключ существует, операция запрещена.
+    async fn acquire(&self, key: &str, period: u32) -> Result<(), u64> {
+        let mut redis = self.redis.get().await.unwrap();
+
+        let is_set: bool = redis::cmd("SET")
+            .arg(key)
+            .arg(1)
+            .arg("NX")
+            .arg("EX")
+            .arg(period)
+            .query_async::<_, Option<String>>(&mut redis)
+            .await
+            .unwrap()
+            .is_some();
+
+        if is_set {
+            return Ok(());
+        }
+
+        let ttl: i64 = redis.ttl(key).await.unwrap();
+        Err(ttl.max(0) as u64)
+    }
+
+    async fn release(&self, key: &str) {
+        let mut redis = self.redis.get().await.unwrap();
+        redis.del::<_, ()>(key).await.unwrap();
+    }
+}
@@ -6,7 +6,9 @@ use lapin::types::{AMQPValue, FieldTable, LongString, ShortString};
 use serde_json::Value;
 use tera::{Context, Tera};
 
-use crate::application::common::email_sender::EmailSender;
+use crate::adapters::i18n::translate;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+use crate::domain::exceptions::Message;
 
 pub struct RMQEmailSender {
     rmq_connection: Box<lapin::Connection>,
@@ -87,8 +89,8 @@ impl EmailSender for RMQEmailSender {
 
     async fn send_template(
         &self, 
-        to: &str, 
-        subject: &str, 
+        to: &EmailRecipient<'_>, 
+        subject: &Message, 
         template: &str, 
         data: Option<BTreeMap<String, Value>>, 
         priority: u8, 
@@ -106,7 +108,14 @@ impl EmailSender for RMQEmailSender {
             None => Context::new()
         };
         
+        // Варианты шаблонов для других языков лежат в templates/<locale>/
+        let localized_template = format!("{}/{}", to.locale, template);
+        let template = match self.tera.get_template_names().any(|name| name == localized_template) {
+            true => localized_template.as_str(),
+            false => template
+        };
+        
         let content = self.tera.render(template, &context).unwrap();
-        self.send(to, subject, &content, "text/html", priority, ttl).await;
+        self.send(to.email, &translate(subject, to.locale), &content, "text/html", priority, ttl).await;
     }
 }
\ No newline at end of file
@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::config::{BehaviorVersion, Builder, Credentials, Region};
+use aws_sdk_s3::primitives::ByteStream;
+
+use crate::application::common::file_storage::FileStorage;
+
+pub struct S3FileStorage {
+    client: Client,
+    bucket: String,
+    public_url: String,
+}
+
+impl S3FileStorage {
+    pub fn new(
+        endpoint_url: &str,
+        region: &str,
+        access_key: &str,
+        secret_key: &str,
+        bucket: &str,
+        public_url: Option<&str>,
+    ) -> Self {
+        let config = Builder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .endpoint_url(endpoint_url)
+            .region(Region::new(region.to_string()))
+            .credentials_provider(Credentials::new(
+                access_key,
+                secret_key,
+                None,
+                None,
+                "config"
+            ))
+            .force_path_style(true)
+            .build();
+        
+        let public_url = match public_url {
+            Some(public_url) => public_url.trim_end_matches('/').to_string(),
+            None => format!("{}/{}", endpoint_url.trim_end_matches('/'), bucket)
+        };
+        
+        Self {
+            client: Client::from_conf(config),
+            bucket: bucket.to_string(),
+            public_url,
+        }
+    }
+}
+
+#[async_trait]
+impl FileStorage for S3FileStorage {
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str) {
+        self.client.put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(data))
+            .send()
+            .await
+            .unwrap();
+    }
+
+    async fn remove(&self, key: &str) {
+        self.client.delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_url, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Требуется локальное S3-совместимое хранилище (например, MinIO)
+    /// с созданным бакетом, параметры задаются переменными окружения S3_TEST_*
+    fn storage() -> S3FileStorage {
+        let env = |name: &str, default: &str| {
+            std::env::var(name).unwrap_or(default.to_string())
+        };
+        S3FileStorage::new(
+            &env("S3_TEST_ENDPOINT", "http://localhost:9000"),
+            &env("S3_TEST_REGION", "us-east-1"),
+            &env("S3_TEST_ACCESS_KEY", "minioadmin"),
+            &env("S3_TEST_SECRET_KEY", "minioadmin"),
+            &env("S3_TEST_BUCKET", "ums-test"),
+            None
+        )
+    }
+
+    #[test]
+    fn test_url() {
+        let storage = S3FileStorage::new(
+            "http://localhost:9000/",
+            "us-east-1",
+            "key",
+            "secret",
+            "bucket",
+            None
+        );
+        assert_eq!(storage.url("a/b.webp"), "http://localhost:9000/bucket/a/b.webp");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn test_put_remove() {
+        let storage = storage();
+        let key = format!("test/{}.txt", uuid::Uuid::new_v4());
+        
+        storage.put(&key, b"test".to_vec(), "text/plain").await;
+        let object = storage.client.get_object()
+            .bucket(&storage.bucket)
+            .key(&key)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(object.content_type(), Some("text/plain"));
+        
+        storage.remove(&key).await;
+        let result = storage.client.get_object()
+            .bucket(&storage.bucket)
+            .key(&key)
+            .send()
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -29,6 +29,10 @@ impl Hasher for Sha256SessionHasher {
         }).await.unwrap();
         result
     }
+
+    fn needs_rehash(&self, _hash: &str) -> bool {
+        false
+    }
 }
 
 
@@ -0,0 +1,97 @@
+use std::io::Cursor;
+
+use async_trait::async_trait;
+use image::{DynamicImage, ImageFormat, ImageReader, Limits};
+use image::imageops::FilterType;
+
+use crate::application::common::image_processor::ImageProcessor;
+use crate::domain::exceptions::Message;
+
+pub struct WebpImageProcessor {
+    max_dimension: u32,
+}
+
+impl WebpImageProcessor {
+    pub fn new() -> Self {
+        Self {
+            max_dimension: 8192,
+        }
+    }
+}
+
+#[async_trait]
+impl ImageProcessor for WebpImageProcessor {
+    
+    /// **square_variants** - метод построения вариантов изображения.
+    ///
+    /// Формат определяется по содержимому, а не по заявленному типу.
+    /// Декодирование ограничено по размеру стороны, чтобы не допустить
+    /// распаковки чрезмерно больших изображений.
+    async fn square_variants(&self, data: Vec<u8>, sizes: &[u32]) -> Result<Vec<Vec<u8>>, Message> {
+        let sizes = sizes.to_vec();
+        let max_dimension = self.max_dimension;
+        
+        tokio::task::spawn_blocking(move || {
+            let mut reader = ImageReader::new(Cursor::new(data))
+                .with_guessed_format()
+                .map_err(|_| Message::new("image_unreadable"))?;
+            
+            match reader.format() {
+                Some(ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP) => (),
+                _ => return Err(Message::new("image_format_unsupported"))
+            }
+            
+            let mut limits = Limits::default();
+            limits.max_image_width = Some(max_dimension);
+            limits.max_image_height = Some(max_dimension);
+            reader.limits(limits);
+            
+            let image = reader.decode()
+                .map_err(|_| Message::new("image_undecodable"))?;
+            
+            sizes.iter().map(|size| {
+                let variant = DynamicImage::ImageRgba8(
+                    image.resize_to_fill(*size, *size, FilterType::Lanczos3).to_rgba8()
+                );
+                let mut buffer = Cursor::new(Vec::new());
+                variant.write_to(&mut buffer, ImageFormat::WebP)
+                    .map_err(|_| Message::new("image_processing_failed"))?;
+                Ok(buffer.into_inner())
+            }).collect()
+        }).await.unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbImage;
+
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut buffer = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut buffer, ImageFormat::Png)
+            .unwrap();
+        buffer.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_square_variants() {
+        let processor = WebpImageProcessor::new();
+        let variants = processor.square_variants(png(300, 200), &[64, 128]).await.unwrap();
+        
+        assert_eq!(variants.len(), 2);
+        for (variant, size) in variants.iter().zip([64, 128]) {
+            let image = image::load_from_memory_with_format(variant, ImageFormat::WebP).unwrap();
+            assert_eq!((image.width(), image.height()), (size, size));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_not_image() {
+        let processor = WebpImageProcessor::new();
+        let result = processor.square_variants(b"not an image".to_vec(), &[64]).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::ban_gateway::BanGateway;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::ban::BanId;
+use crate::domain::models::user::{UserId, UserState};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::ban::BanService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct BanUserDTO {
+    pub user_id: UserId,
+    pub reason: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BanUserResultDTO {
+    id: BanId,
+    user_id: UserId,
+    banned_by: Option<UserId>,
+    reason: String,
+    expires_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+pub struct BanUser<'a> {
+    pub ban_gateway: &'a dyn BanGateway,
+    pub user_gateway: &'a dyn UserGateway,
+    pub state_effects: UserStateEffects<'a>,
+    pub ban_service: &'a BanService,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<BanUserDTO, BanUserResultDTO> for BanUser<'_> {
+    async fn execute(&self, data: BanUserDTO) -> Result<BanUserResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_ban_user(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        self.validator.validate_ban_reason(&data.reason).unwrap_or_else(|e| {
+            validator_err_map.insert("reason".to_string(), e);
+        });
+
+        if let Some(expires_at) = &data.expires_at {
+            if expires_at <= &Utc::now() {
+                validator_err_map.insert(
+                    "expires_at".to_string(),
+                    Message::new("ban_expiry_in_past")
+                );
+            }
+        }
+
+        if &data.user_id == self.id_provider.user_id().unwrap() {
+            validator_err_map.insert(
+                "user_id".to_string(),
+                Message::new("ban_self")
+            );
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = self.user_gateway.get_user_by_id(&data.user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let active_ban = self.ban_gateway.get_user_active_ban(&user.id).await;
+        // При повторном бане сохраняется состояние до первого бана
+        let previous_state = match user.state {
+            UserState::Banned => self.ban_service.restored_state(active_ban.as_ref()),
+            ref state => state.clone()
+        };
+
+        let (new_user, effects) = self.user_service.change_state(user, UserState::Banned).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error.into())
+            )
+        )?;
+
+        // Новый бан заменяет действующий
+        if let Some(active_ban) = active_ban {
+            self.ban_gateway.save_ban(&self.ban_service.revoke_ban(active_ban)).await;
+        }
+
+        let ban = self.ban_service.create_ban(
+            new_user.id,
+            *self.id_provider.user_id().unwrap(),
+            data.reason,
+            data.expires_at,
+            previous_state,
+        );
+
+        self.ban_gateway.save_ban(&ban).await;
+
+        self.user_gateway.save_user(&new_user).await;
+        self.state_effects.apply(&new_user, &effects).await;
+
+        Ok(BanUserResultDTO {
+            id: ban.id,
+            user_id: ban.user_id,
+            banned_by: ban.banned_by,
+            reason: ban.reason,
+            expires_at: ban.expires_at,
+            created_at: ban.created_at,
+        })
+    }
+}
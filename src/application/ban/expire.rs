@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::common::ban_gateway::BanReader;
+use crate::application::common::exceptions::ApplicationError;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::models::user::UserState;
+use crate::domain::services::ban::BanService;
+use crate::domain::services::user::UserService;
+
+/// Момент, на который проверяются сроки банов
+pub struct ExpireBansDTO {
+    pub until: DateTime<Utc>,
+}
+
+pub struct ExpireBans<'a> {
+    pub ban_reader: &'a dyn BanReader,
+    pub user_gateway: &'a dyn UserGateway,
+    pub ban_service: &'a BanService,
+    pub user_service: &'a UserService,
+    pub state_effects: UserStateEffects<'a>,
+}
+
+impl Interactor<ExpireBansDTO, ()> for ExpireBans<'_> {
+    async fn execute(&self, data: ExpireBansDTO) -> Result<(), ApplicationError> {
+        let mut restored = 0;
+        
+        for ban in self.ban_reader.get_expired_bans(&data.until).await {
+            let user = match self.user_gateway.get_user_by_id(&ban.user_id).await {
+                Some(user) if user.state == UserState::Banned => user,
+                _ => continue
+            };
+            
+            // Возвращаем пользователя в состояние до бана, как и при входе
+            let (user, effects) = match self.user_service.change_state(
+                user,
+                self.ban_service.restored_state(Some(&ban))
+            ) {
+                Ok(result) => result,
+                Err(error) => {
+                    log::error!("Не удалось снять истекший бан {}: {}", ban.id, error);
+                    continue
+                }
+            };
+            self.user_gateway.save_user(&user).await;
+            self.state_effects.apply(&user, &effects).await;
+            restored += 1;
+        }
+        
+        if restored > 0 {
+            log::info!("Сняты истекшие баны у пользователей: {}", restored);
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::common::ban_gateway::BanReader;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::domain::exceptions::DomainError;
+use crate::domain::models::ban::BanId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Serialize)]
+pub struct BanItemResult {
+    id: BanId,
+    banned_by: Option<UserId>,
+    reason: String,
+    expires_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+pub type UserBansResultDTO = Vec<BanItemResult>;
+
+pub struct GetUserBans<'a> {
+    pub ban_reader: &'a dyn BanReader,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<UserId, UserBansResultDTO> for GetUserBans<'_> {
+    async fn execute(&self, data: UserId) -> Result<UserBansResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_get_user_bans(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let bans = self.ban_reader.get_user_bans(&data).await;
+
+        Ok(
+            bans.into_iter().map(|ban| BanItemResult {
+                id: ban.id,
+                banned_by: ban.banned_by,
+                reason: ban.reason,
+                expires_at: ban.expires_at,
+                revoked_at: ban.revoked_at,
+                created_at: ban.created_at,
+            }).collect()
+        )
+    }
+}
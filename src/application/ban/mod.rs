@@ -0,0 +1,4 @@
+pub mod create;
+pub mod revoke;
+pub mod get_by_user;
+pub mod expire;
\ No newline at end of file
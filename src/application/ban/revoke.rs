@@ -0,0 +1,84 @@
+use serde::Deserialize;
+
+use crate::application::common::ban_gateway::BanGateway;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::user::{UserId, UserState};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::ban::BanService;
+use crate::domain::services::user::UserService;
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeBanDTO {
+    pub user_id: UserId,
+}
+
+pub struct RevokeBan<'a> {
+    pub ban_gateway: &'a dyn BanGateway,
+    pub user_gateway: &'a dyn UserGateway,
+    pub ban_service: &'a BanService,
+    pub user_service: &'a UserService,
+    pub state_effects: UserStateEffects<'a>,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<RevokeBanDTO, ()> for RevokeBan<'_> {
+    async fn execute(&self, data: RevokeBanDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_ban_user(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let user = self.user_gateway.get_user_by_id(&data.user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        if user.state != UserState::Banned {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("user_not_banned"))
+                )
+            )
+        }
+
+        let active_ban = self.ban_gateway.get_user_active_ban(&user.id).await;
+        let restored_state = self.ban_service.restored_state(active_ban.as_ref());
+        if let Some(active_ban) = active_ban {
+            self.ban_gateway.save_ban(&self.ban_service.revoke_ban(active_ban)).await;
+        }
+
+        let (new_user, effects) = self.user_service.change_state(user, restored_state).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error.into())
+            )
+        )?;
+
+        self.user_gateway.save_user(&new_user).await;
+        self.state_effects.apply(&new_user, &effects).await;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 
+use crate::application::common::pagination::Cursor;
 use crate::domain::models::access_log::AccessLog;
 use crate::domain::models::user::UserId;
 
 #[async_trait]
 pub trait AccessLogReader {
-    async fn get_user_records(&self, user_id: &UserId, limit: &u64, offset: &u64) -> Vec<AccessLog>;
+    async fn get_user_records(&self, user_id: &UserId, after: Option<&Cursor>, limit: &u64) -> Vec<AccessLog>;
+    async fn count_user_records(&self, user_id: &UserId) -> u64;
 
 }
 
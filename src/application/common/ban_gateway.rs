@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::ban::Ban;
+use crate::domain::models::user::UserId;
+
+#[async_trait]
+pub trait BanReader {
+    /// Последний не снятый вручную бан пользователя (может быть уже истекшим)
+    async fn get_user_active_ban(&self, user_id: &UserId) -> Option<Ban>;
+    async fn get_user_bans(&self, user_id: &UserId) -> Vec<Ban>;
+    /// Истекшие к моменту `until` последние баны пользователей, которые всё ещё забанены
+    async fn get_expired_bans(&self, until: &DateTime<Utc>) -> Vec<Ban>;
+}
+
+#[async_trait]
+pub trait BanWriter {
+    async fn save_ban(&self, data: &Ban);
+}
+
+pub trait BanGateway: BanReader + BanWriter { }
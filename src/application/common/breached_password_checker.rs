@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+/// **BreachedPasswordChecker** - интерфейс проверки пароля 
+/// по списку скомпрометированных паролей
+#[async_trait]
+pub trait BreachedPasswordChecker: Send + Sync {
+    /// **Is breached** - встречается ли пароль в списке утечек
+    async fn is_breached(&self, password: &str) -> bool;
+}
@@ -1,5 +1,7 @@
 use async_trait::async_trait;
 
+use crate::domain::exceptions::Message;
+
 /// **ConfirmCode** - интерфейс для работы с кодами подтверждения
 /// 
 /// Позволяет генерировать и подтверждать коды, идентифицируя по уникальному
@@ -8,11 +10,11 @@ use async_trait::async_trait;
 /// Сохраняет состояния.
 #[async_trait]
 pub trait ConfirmCode {
-    async fn confirm(&self, key: &str, code: u32) -> Result<(), String>;
+    async fn confirm(&self, key: &str, code: u32) -> Result<(), Message>;
     
     /// **Generate** - генерация кода подтверждения
     /// 
     /// Генерирует код подтверждения по уникальному ключу.
     /// Результат возвращается в виде шестизначного числа.
-    async fn generate(&self, key: &str) -> Result<u32, String>;
+    async fn generate(&self, key: &str) -> Result<u32, Message>;
 }
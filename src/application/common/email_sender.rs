@@ -3,6 +3,14 @@ use std::collections::BTreeMap;
 use async_trait::async_trait;
 use serde_json::Value;
 
+use crate::domain::exceptions::Message;
+
+/// Получатель письма по шаблону и язык, на котором оно отправляется
+pub struct EmailRecipient<'a> {
+    pub email: &'a str,
+    pub locale: &'a str,
+}
+
 #[async_trait]
 pub trait EmailSender {
     async fn send(
@@ -15,10 +23,14 @@ pub trait EmailSender {
         ttl: u32,
     );
 
+    /// **Send template** - отправка письма по шаблону
+    ///
+    /// Тема переводится из каталога, а шаблон выбирается
+    /// в варианте для языка получателя, если такой вариант есть.
     async fn send_template(
         &self,
-        to: &str,
-        subject: &str,
+        to: &EmailRecipient<'_>,
+        subject: &Message,
         template: &str,
         data: Option<BTreeMap<String, Value>>,
         priority: u8,
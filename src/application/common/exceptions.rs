@@ -2,10 +2,12 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 
+use crate::domain::exceptions::Message;
+
 #[derive(Debug, Serialize, Clone)]
 pub enum ErrorContent {
-    Message(String),
-    Map(HashMap<String, String>),
+    Message(Message),
+    Map(HashMap<String, Message>),
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -15,4 +17,7 @@ pub enum ApplicationError {
     Conflict(ErrorContent),
     Unauthorized(ErrorContent),
     Forbidden(ErrorContent),
+    TooManyRequests(ErrorContent),
+    /// Сервис не настроен для выполнения запроса, вины клиента в этом нет
+    ServiceUnavailable(ErrorContent),
 }
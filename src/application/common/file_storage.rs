@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+/// **FileStorage** - интерфейс файлового хранилища
+///
+/// Объекты адресуются ключом вида `prefix/name.ext`.
+#[async_trait]
+pub trait FileStorage {
+    /// **Put** - сохранение объекта с указанным типом содержимого
+    async fn put(&self, key: &str, data: Vec<u8>, content_type: &str);
+    
+    /// **Remove** - удаление объекта, отсутствие объекта не является ошибкой
+    async fn remove(&self, key: &str);
+    
+    /// **Url** - публичный адрес объекта
+    fn url(&self, key: &str) -> String;
+}
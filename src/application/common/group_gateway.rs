@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::group::{Group, GroupId};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::UserId;
+
+#[async_trait]
+pub trait GroupReader {
+    async fn get_group(&self, group_id: &GroupId) -> Option<Group>;
+    async fn get_group_by_title_not_sensitive(&self, title: &str) -> Option<Group>;
+    async fn get_groups_range(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Group>;
+    async fn count_groups(&self) -> u64;
+    async fn is_user_in_group(&self, group_id: &GroupId, user_id: &UserId) -> bool;
+    async fn get_group_user_ids(&self, group_id: &GroupId) -> Vec<UserId>;
+    async fn is_role_linked_to_group(&self, group_id: &GroupId, role_id: &RoleId) -> bool;
+    async fn get_group_role_ids(&self, group_id: &GroupId) -> Vec<RoleId>;
+}
+
+#[async_trait]
+pub trait GroupWriter {
+    async fn save_group(&self, data: &Group);
+}
+
+#[async_trait]
+pub trait GroupLinker {
+    async fn link_user_to_group(&self, group_id: &GroupId, user_id: &UserId);
+    async fn unlink_user_from_group(&self, group_id: &GroupId, user_id: &UserId);
+    async fn link_role_to_group(&self, group_id: &GroupId, role_id: &RoleId);
+    async fn unlink_role_from_group(&self, group_id: &GroupId, role_id: &RoleId);
+}
+
+#[async_trait]
+pub trait GroupRemover {
+    /// Вместе с группой удаляются ее участники и роли
+    async fn remove_group(&self, group_id: &GroupId);
+}
+
+pub trait GroupGateway: GroupReader + GroupWriter + GroupLinker + GroupRemover + Send + Sync {}
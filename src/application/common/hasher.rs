@@ -4,4 +4,6 @@ use async_trait::async_trait;
 pub trait Hasher: Send + Sync {
     async fn hash(&self, value: &str) -> String;
     async fn verify(&self, value: &str, hash: &str) -> bool;
+    /// Хеш построен с устаревшими параметрами и должен быть пересчитан
+    fn needs_rehash(&self, hash: &str) -> bool;
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::service::ServiceTextId;
 use crate::domain::models::session::SessionId;
 use crate::domain::models::user::{UserId, UserState};
 
@@ -6,6 +8,7 @@ pub trait IdProvider: Send + Sync {
     fn user_id(&self) -> Option<&UserId>;
     fn user_state(&self) -> Option<&UserState>;
     fn permissions(&self) -> &Vec<String>;
+    fn service_permissions(&self, service_text_id: &ServiceTextId) -> Option<&Vec<PermissionTextId>>;
     fn client(&self) -> &str;
     fn os(&self) -> &str;
     fn device(&self) -> &str;
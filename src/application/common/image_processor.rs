@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::exceptions::Message;
+
+/// **ImageProcessor** - интерфейс обработки изображений
+#[async_trait]
+pub trait ImageProcessor {
+    /// **Square variants** - квадратные варианты изображения в формате WebP
+    ///
+    /// Для каждого размера стороны в пикселях возвращается изображение,
+    /// обрезанное по центру до квадрата и уменьшенное до этого размера.
+    /// Ошибка возвращается, если данные не являются изображением 
+    /// одного из поддерживаемых форматов.
+    async fn square_variants(&self, data: Vec<u8>, sizes: &[u32]) -> Result<Vec<Vec<u8>>, Message>;
+}
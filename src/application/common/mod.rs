@@ -11,4 +11,15 @@ pub mod hasher;
 pub mod confirm_code;
 pub mod email_sender;
 pub mod access_log_gateway;
-pub mod server;
\ No newline at end of file
+pub mod ban_gateway;
+pub mod rate_limiter;
+pub mod pagination;
+pub mod one_time_token;
+pub mod server;
+pub mod file_storage;
+pub mod image_processor;
+pub mod breached_password_checker;
+pub mod password_history_gateway;
+pub mod sms_sender;
+pub mod organization_gateway;
+pub mod group_gateway;
\ No newline at end of file
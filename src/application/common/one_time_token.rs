@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+/// **OneTimeToken** - интерфейс для работы с одноразовыми токенами
+///
+/// Позволяет выпускать токены, к которым привязаны произвольные данные,
+/// и погашать их. Используется в ссылках из писем (например, отмена смены email).
+///
+/// Сохраняет состояния.
+#[async_trait]
+pub trait OneTimeToken {
+    /// **Issue** - выпуск токена
+    ///
+    /// Сохраняет данные и возвращает токен, который действителен `ttl` секунд.
+    async fn issue(&self, payload: &str, ttl: u32) -> String;
+
+    /// **Redeem** - погашение токена
+    ///
+    /// Возвращает привязанные к токену данные и удаляет токен.
+    /// Повторное погашение и просроченный токен возвращают `None`.
+    async fn redeem(&self, token: &str) -> Option<String>;
+
+    /// **Peek** - чтение данных токена без погашения
+    ///
+    /// Позволяет проверить ввод до погашения, чтобы ошибка 
+    /// пользователя не делала ссылку недействительной.
+    async fn peek(&self, token: &str) -> Option<String>;
+}
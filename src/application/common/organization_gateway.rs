@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::organization::{Organization, OrganizationId};
+use crate::domain::models::permission::{PermissionId, PermissionTextId};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+
+#[async_trait]
+pub trait OrganizationReader: Send + Sync {
+    async fn get_organization(&self, organization_id: &OrganizationId) -> Option<Organization>;
+    async fn get_organization_by_title_not_sensitive(&self, title: &str) -> Option<Organization>;
+    async fn get_organizations_range(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Organization>;
+    async fn count_organizations(&self) -> u64;
+    async fn is_user_in_organization(&self, organization_id: &OrganizationId, user_id: &UserId) -> bool;
+    /// Разрешения ролей пользователя в организации с учетом наследования ролей,
+    /// за вычетом личных запретов пользователя
+    async fn get_user_organization_permissions(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId
+    ) -> HashMap<ServiceTextId, Vec<PermissionTextId>>;
+    /// Идентификаторы тех же разрешений по всем сервисам
+    async fn get_user_organization_permission_ids(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId
+    ) -> Vec<PermissionId>;
+}
+
+#[async_trait]
+pub trait OrganizationWriter {
+    async fn save_organization(&self, data: &Organization);
+}
+
+#[async_trait]
+pub trait OrganizationLinker {
+    async fn link_user_to_organization(&self, organization_id: &OrganizationId, user_id: &UserId);
+    /// Вместе с членством удаляются роли пользователя в организации
+    async fn unlink_user_from_organization(&self, organization_id: &OrganizationId, user_id: &UserId);
+    async fn is_role_linked_to_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    ) -> bool;
+    async fn link_role_to_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    );
+    async fn unlink_role_from_user_in_organization(
+        &self,
+        organization_id: &OrganizationId,
+        user_id: &UserId,
+        role_id: &RoleId
+    );
+}
+
+pub trait OrganizationGateway: OrganizationReader + OrganizationWriter + OrganizationLinker + Send + Sync {}
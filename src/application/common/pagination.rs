@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::exceptions::Message;
+
+/// **CursorValue** - значение поля сортировки в курсоре
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CursorValue {
+    Text(String),
+    Time(DateTime<Utc>),
+    Null,
+}
+
+/// **Cursor** - позиция в упорядоченной выборке
+///
+/// Хранит значение поля сортировки и id последней записи страницы.
+/// Следующая страница начинается строго после этой пары (keyset pagination),
+/// поэтому вставка новых строк не сдвигает уже отданные страницы.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cursor {
+    pub value: CursorValue,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn new(value: CursorValue, id: Uuid) -> Self {
+        Self { value, id }
+    }
+
+    /// Курсор передается клиенту непрозрачной строкой
+    pub fn encode(&self) -> String {
+        serde_json::to_vec(self)
+            .unwrap()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    pub fn decode(raw: &str) -> Result<Self, Message> {
+        let error = || Message::new("cursor_invalid");
+
+        if !raw.len().is_multiple_of(2) || !raw.is_ascii() {
+            return Err(error());
+        }
+        let bytes = (0..raw.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&raw[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| error())?;
+
+        serde_json::from_slice(&bytes).map_err(|_| error())
+    }
+}
+
+/// **PageDTO** - единый конверт ответа для списков
+///
+/// `next_cursor` отсутствует на последней странице,
+/// `total` заполняется только по запросу.
+#[derive(Debug, Serialize)]
+pub struct PageDTO<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+}
+
+impl<T> PageDTO<T> {
+    /// Строки запрашиваются с запасом в одну запись:
+    /// по ней определяется, есть ли следующая страница.
+    pub fn from_rows<R>(
+        mut rows: Vec<R>,
+        per_page: u64,
+        total: Option<u64>,
+        cursor_of: impl Fn(&R) -> Cursor,
+        map: impl FnMut(R) -> T,
+    ) -> Self {
+        let next_cursor = if rows.len() as u64 > per_page {
+            rows.truncate(per_page as usize);
+            rows.last().map(|row| cursor_of(row).encode())
+        } else {
+            None
+        };
+
+        Self {
+            items: rows.into_iter().map(map).collect(),
+            next_cursor,
+            total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor::new(CursorValue::Text("bob".to_string()), Uuid::new_v4());
+        let decoded = Cursor::decode(&cursor.encode()).unwrap();
+        assert!(matches!(decoded.value, CursorValue::Text(ref text) if text == "bob"));
+        assert_eq!(decoded.id, cursor.id);
+    }
+
+    #[test]
+    fn test_decode_invalid_cursor() {
+        assert!(Cursor::decode("zz").is_err());
+        assert!(Cursor::decode("7b7d").is_err());
+        assert!(Cursor::decode("7b7").is_err());
+    }
+}
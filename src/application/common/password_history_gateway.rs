@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::models::user::UserId;
+
+#[async_trait]
+pub trait PasswordHistoryReader {
+    /// Хеши предыдущих паролей пользователя, от новых к старым
+    async fn get_password_history(&self, user_id: &UserId) -> Vec<String>;
+}
+
+#[async_trait]
+pub trait PasswordHistoryWriter {
+    /// Добавляет хеш в историю и удаляет записи сверх ее размера
+    async fn save_password_history(&self, user_id: &UserId, hashed_password: &str);
+}
+
+#[async_trait]
+pub trait PasswordHistoryRemover {
+    async fn remove_password_history(&self, user_id: &UserId);
+}
+
+pub trait PasswordHistoryGateway: PasswordHistoryReader + PasswordHistoryWriter + PasswordHistoryRemover { }
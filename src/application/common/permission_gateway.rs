@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 
-use crate::domain::models::permission::{Permission as PermissionDomain, PermissionId, PermissionTextId};
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::permission::{Permission as PermissionDomain, PermissionEffect, PermissionId, PermissionTextId};
 use crate::domain::models::role::RoleId;
 use crate::domain::models::service::ServiceId;
 use crate::domain::models::user::UserId;
@@ -11,9 +12,19 @@ pub trait PermissionReader {
     async fn get_permissions_by_service_id(&self, service_id: &ServiceId) -> Vec<PermissionDomain>;
     async fn get_permissions_by_ids(&self, permission_ids: &Vec<PermissionId>) -> Option<Vec<PermissionDomain>>;
     async fn get_permissions_by_text_ids(&self, permission_text_ids: &Vec<PermissionTextId>) -> Option<Vec<PermissionDomain>>;
-    async fn get_permissions_list(&self, limit: &u64, offset: &u64) -> Vec<PermissionDomain>;
+    async fn get_permissions_list(&self, after: Option<&Cursor>, limit: &u64) -> Vec<PermissionDomain>;
+    async fn count_permissions(&self) -> u64;
     async fn get_role_permissions(&self, role_id: &RoleId) -> Vec<PermissionDomain>;
+    /// Разрешения родительских ролей, которые наследует роль
+    async fn get_role_inherited_permissions(&self, role_id: &RoleId) -> Vec<PermissionDomain>;
+    /// Глобально действующие разрешения пользователя: разрешения ролей без областей
+    /// с учетом наследования и личные выдачи за вычетом личных запретов
     async fn get_user_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain>;
+    /// Разрешения, выданные непосредственно ролям пользователя или ему лично,
+    /// за вычетом личных запретов
+    async fn get_user_direct_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain>;
+    /// Разрешения ролей групп пользователя и их родительских ролей за вычетом личных запретов
+    async fn get_user_group_permissions(&self, user_id: &UserId) -> Vec<PermissionDomain>;
 }
 
 #[async_trait]
@@ -33,6 +44,9 @@ pub trait PermissionLinker {
     async fn link_permission_to_role(&self, role_id: &RoleId, permission_id: &PermissionId);
     async fn link_permissions_to_role(&self, role_id: &RoleId, permission_ids: &Vec<PermissionId>);
     async fn unlink_permission_from_role(&self, role_id: &RoleId, permission_id: &PermissionId);
+    async fn get_user_permission_effect(&self, user_id: &UserId, permission_id: &PermissionId) -> Option<PermissionEffect>;
+    async fn link_permission_to_user(&self, user_id: &UserId, permission_id: &PermissionId, effect: &PermissionEffect);
+    async fn unlink_permission_from_user(&self, user_id: &UserId, permission_id: &PermissionId);
 }
 
 
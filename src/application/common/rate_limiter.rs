@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+/// **RateLimiter** - интерфейс ограничения частоты операций
+///
+/// Позволяет выполнять операцию не чаще одного раза за период,
+/// идентифицируя её по уникальному ключу.
+#[async_trait]
+pub trait RateLimiter {
+    /// **Acquire** - захват слота на выполнение операции
+    ///
+    /// Если слот свободен, он занимается на `period` секунд.
+    /// Иначе возвращается количество секунд до освобождения слота.
+    async fn acquire(&self, key: &str, period: u32) -> Result<(), u64>;
+    
+    /// **Release** - досрочное освобождение слота, например если операция не удалась
+    async fn release(&self, key: &str);
+}
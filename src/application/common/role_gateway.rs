@@ -1,6 +1,8 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
-use crate::domain::models::role::{Role as RoleDomain, RoleId};
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::role::{Role as RoleDomain, RoleId, RoleUserLink};
 use crate::domain::models::user::UserId;
 
 #[async_trait]
@@ -9,14 +11,19 @@ pub trait RoleReader {
     async fn get_roles_by_ids(&self, role_ids: &Vec<RoleId>) -> Option<Vec<RoleDomain>>;
     async fn get_roles_range(
         &self, 
-        limit: &u64, 
-        offset: &u64
+        after: Option<&Cursor>, 
+        limit: &u64
     ) -> Vec<RoleDomain>;
+    async fn count_roles(&self) -> u64;
 
     async fn get_user_roles(&self, user_id: &UserId) -> Vec<RoleDomain>;
+    /// Все предки роли по цепочке родителей, без самой роли
+    async fn get_role_ancestors(&self, role_id: &RoleId) -> Vec<RoleDomain>;
     
     async fn get_role_by_title_not_sensitive(&self, title: &String) -> Option<RoleDomain>;
     async fn get_default_role(&self) -> Option<RoleDomain>;
+    /// Пользователи, у которых назначение роли вступило в силу в промежутке (since, until]
+    async fn get_users_with_started_role_links(&self, since: &DateTime<Utc>, until: &DateTime<Utc>) -> Vec<UserId>;
 }
 
 #[async_trait]
@@ -28,8 +35,12 @@ pub trait RoleWriter {
 #[async_trait]
 pub trait RoleLinker {
     async fn link_role_to_user(&self, role_id: &RoleId, user_id: &UserId);
+    /// Назначение роли с областями и сроком действия
+    async fn save_role_user_link(&self, link: &RoleUserLink);
     async fn unlink_role_from_user(&self, role_id: &RoleId, user_id: &UserId);
     async fn is_role_linked_to_user(&self, role_id: &RoleId, user_id: &UserId) -> bool;
+    /// Удаляет назначения, срок которых истек к until, и возвращает их пользователей
+    async fn remove_expired_role_links(&self, until: &DateTime<Utc>) -> Vec<UserId>;
 }
 
 #[async_trait]
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 
+use crate::application::common::pagination::Cursor;
 use crate::domain::models::service::{Service, ServiceId, ServiceTextId};
 
 #[async_trait]
 pub trait ServiceReader {
     async fn get_service_by_id(&self, service_id: &ServiceId) -> Option<Service>;
-    async fn get_services(&self, limit: &u64, offset: &u64) -> Vec<Service>;
+    async fn get_services(&self, after: Option<&Cursor>, limit: &u64) -> Vec<Service>;
+    async fn count_services(&self) -> u64;
     async fn get_services_by_text_id(&self, text_id: &ServiceTextId) -> Option<Service>;
 }
 
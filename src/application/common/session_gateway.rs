@@ -2,7 +2,9 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 
+use crate::application::common::pagination::Cursor;
 use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::resource_scope::PermissionScopes;
 use crate::domain::models::service::ServiceTextId;
 use crate::domain::models::session::{Session, SessionId, SessionTokenHash};
 use crate::domain::models::user::{UserId, UserState};
@@ -13,12 +15,19 @@ pub trait SessionReader {
     async fn get_session_by_token_hash(
         &self, 
         token_hash: &SessionTokenHash
-    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>)>;
+    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>, PermissionScopes)>;
     async fn get_session_by_token_hash_from_cache(
         &self, 
         token_hash: &SessionTokenHash
-    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>)>;
+    ) -> Option<(Session, UserState, HashMap<ServiceTextId, Vec<PermissionTextId>>, PermissionScopes)>;
     async fn get_user_sessions(&self, user_id: &UserId) -> Vec<Session>;
+    async fn get_user_sessions_range(
+        &self,
+        user_id: &UserId,
+        after: Option<&Cursor>,
+        limit: &u64
+    ) -> Vec<Session>;
+    async fn count_user_sessions(&self, user_id: &UserId) -> u64;
 }
 
 #[async_trait]
@@ -28,7 +37,8 @@ pub trait SessionWriter {
         &self, 
         data: &Session, 
         user_state: &UserState,
-        permissions: &HashMap<ServiceTextId, Vec<PermissionTextId>>    
+        permissions: &HashMap<ServiceTextId, Vec<PermissionTextId>>,
+        scopes: &PermissionScopes
     );
 }
 
@@ -36,6 +46,8 @@ pub trait SessionWriter {
 pub trait SessionRemover {
     async fn remove_session(&self, session_id: &SessionId);
     async fn remove_user_sessions(&self, user_id: &UserId);
+    /// Сбрасывает кеш сессий пользователя, чтобы разрешения пересчитались при следующем запросе
+    async fn remove_user_sessions_from_cache(&self, user_id: &UserId);
 }
 
 
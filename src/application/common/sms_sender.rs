@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::exceptions::Message;
+
+/// **SmsSender** - интерфейс отправки SMS
+#[async_trait]
+pub trait SmsSender {
+    /// **Send** - отправка сообщения на номер в формате E.164
+    ///
+    /// Текст переводится из каталога на язык `locale`.
+    /// Сообщение, не доставленное за `ttl` секунд, можно не доставлять.
+    async fn send(&self, to: &str, content: &Message, locale: &str, ttl: u32);
+}
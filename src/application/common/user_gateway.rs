@@ -1,20 +1,77 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 
-use crate::domain::models::user::{User as UserDomain, UserId};
+use crate::application::common::pagination::Cursor;
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::{User as UserDomain, UserId, UserState};
+
+/// **UserFilter** - условия поиска пользователей
+///
+/// Незаданные условия не ограничивают выборку.
+/// Имя пользователя и email ищутся по префиксу без учета регистра.
+#[derive(Debug, Default, Clone)]
+pub struct UserFilter {
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub state: Option<UserState>,
+    pub role_id: Option<RoleId>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub last_login_from: Option<DateTime<Utc>>,
+    pub last_login_to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    Username,
+    Email,
+    #[default]
+    CreatedAt,
+    LastLogin,
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
 
 #[async_trait]
 pub trait UserReader {
     async fn get_user_by_id(&self, user_id: &UserId) -> Option<UserDomain>;
     async fn get_users_by_ids(&self, user_ids: &Vec<UserId>) -> Option<Vec<UserDomain>>;
-    async fn get_users_list(&self, limit: &u64, offset: &u64) -> Vec<UserDomain>;
+    async fn get_users_by_filter(
+        &self,
+        filter: &UserFilter,
+        sort: &UserSortField,
+        order: &SortOrder,
+        after: Option<&Cursor>,
+        limit: &u64
+    ) -> Vec<(UserDomain, Option<DateTime<Utc>>)>;
+    async fn count_users_by_filter(&self, filter: &UserFilter) -> u64;
     async fn get_user_by_username_not_sensitive(&self, username: &String) -> Option<UserDomain>;
     async fn get_user_by_email_not_sensitive(&self, email: &String) -> Option<UserDomain>;
+    async fn get_user_by_phone(&self, phone: &str) -> Option<UserDomain>;
+    async fn get_users_by_usernames_or_emails_not_sensitive(
+        &self,
+        usernames: &[String],
+        emails: &[String]
+    ) -> Vec<UserDomain>;
 
 }
 
 #[async_trait]
 pub trait UserWriter {
     async fn save_user(&self, data: &UserDomain);
+    /// **Import users** - сохранение пачки пользователей вместе с ролями в одной транзакции
+    ///
+    /// Пользователи, нарушающие уникальность, пропускаются вместе со своими ролями.
+    /// Возвращает идентификаторы пропущенных пользователей.
+    async fn import_users(&self, data: &[UserDomain], role_links: &[(RoleId, UserId)]) -> Vec<UserId>;
 }
 
-pub trait UserGateway: UserReader + UserWriter {}
\ No newline at end of file
+pub trait UserGateway: UserReader + UserWriter + Send + Sync {}
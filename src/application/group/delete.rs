@@ -0,0 +1,63 @@
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupGateway;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteGroupDTO {
+    pub id: GroupId,
+}
+
+pub struct DeleteGroup<'a> {
+    pub group_gateway: &'a dyn GroupGateway,
+    pub session_remover: &'a dyn SessionRemover,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<DeleteGroupDTO, ()> for DeleteGroup<'_> {
+    async fn execute(&self, data: DeleteGroupDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_delete_group(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+        
+        self.group_gateway.get_group(&data.id).await.ok_or_else(|| {
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("group_not_found"))
+            )
+        })?;
+
+        let user_ids = self.group_gateway.get_group_user_ids(&data.id).await;
+        self.group_gateway.remove_group(&data.id).await;
+        
+        // Участники теряют роли группы, кэш их сессий сбрасывается
+        for user_id in user_ids.iter() {
+            self.session_remover.remove_user_sessions_from_cache(user_id).await;
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupReader;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct GetGroupByIdDTO {
+    pub id: GroupId,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupByIdResultDTO {
+    id: GroupId,
+    title: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    user_ids: Vec<UserId>,
+}
+
+pub struct GetGroupById<'a> {
+    pub group_reader: &'a dyn GroupReader,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<GetGroupByIdDTO, GroupByIdResultDTO> for GetGroupById<'_> {
+    async fn execute(&self, data: GetGroupByIdDTO) -> Result<GroupByIdResultDTO, ApplicationError> {
+        
+        match self.access_service.ensure_can_get_group(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+        
+        let group = self.group_reader.get_group(&data.id).await.ok_or_else(|| {
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("group_not_found"))
+            )
+        })?;
+        
+        Ok(GroupByIdResultDTO {
+            user_ids: self.group_reader.get_group_user_ids(&group.id).await,
+            id: group.id,
+            title: group.title,
+            description: group.description,
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupGateway;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::permission_gateway::PermissionReader;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::application::common::user_gateway::UserReader;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::models::permission::PermissionId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct LinkGroupUserDTO {
+    pub group_id: GroupId,
+    pub user_id: UserId,
+}
+
+pub struct LinkGroupUser<'a> {
+    pub group_gateway: &'a dyn GroupGateway,
+    pub user_reader: &'a dyn UserReader,
+    pub permission_reader: &'a dyn PermissionReader,
+    pub session_remover: &'a dyn SessionRemover,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<LinkGroupUserDTO, ()> for LinkGroupUser<'_> {
+    async fn execute(&self, data: LinkGroupUserDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_manage_group_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.group_gateway.get_group(&data.group_id).await.is_none() {
+            validator_err_map.insert("group_id".to_string(), Message::new("group_not_found"));
+        }
+
+        if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        // Новый участник получит все роли группы
+        let mut group_permission_ids: Vec<PermissionId> = Vec::new();
+        for role_id in self.group_gateway.get_group_role_ids(&data.group_id).await.iter() {
+            group_permission_ids.extend(
+                self.permission_reader.get_role_permissions(role_id).await
+                    .into_iter()
+                    .chain(self.permission_reader.get_role_inherited_permissions(role_id).await)
+                    .map(|permission| permission.id)
+            );
+        }
+
+        let actor_permission_ids: Vec<PermissionId> = match self.id_provider.user_id() {
+            Some(user_id) => self.permission_reader.get_user_permissions(user_id).await
+                .into_iter()
+                .map(|permission| permission.id)
+                .collect(),
+            None => Vec::new()
+        };
+
+        match self.access_service.ensure_can_grant_group_roles(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions(),
+            &actor_permission_ids,
+            &group_permission_ids
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        if self.group_gateway.is_user_in_group(&data.group_id, &data.user_id).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("group_user_already_member"))
+                )
+            )
+        }
+
+        self.group_gateway.link_user_to_group(&data.group_id, &data.user_id).await;
+        self.session_remover.remove_user_sessions_from_cache(&data.user_id).await;
+
+        Ok(())
+    }
+}
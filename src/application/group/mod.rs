@@ -0,0 +1,9 @@
+pub mod create;
+pub mod get_by_id;
+pub mod get_range;
+pub mod update;
+pub mod delete;
+pub mod link_user;
+pub mod unlink_user;
+pub mod link_role;
+pub mod unlink_role;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupGateway;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::role_gateway::RoleReader;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::models::role::RoleId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct UnlinkGroupRoleDTO {
+    pub group_id: GroupId,
+    pub role_id: RoleId,
+}
+
+pub struct UnlinkGroupRole<'a> {
+    pub group_gateway: &'a dyn GroupGateway,
+    pub role_reader: &'a dyn RoleReader,
+    pub session_remover: &'a dyn SessionRemover,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<UnlinkGroupRoleDTO, ()> for UnlinkGroupRole<'_> {
+    async fn execute(&self, data: UnlinkGroupRoleDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_link_group_role(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.group_gateway.get_group(&data.group_id).await.is_none() {
+            validator_err_map.insert("group_id".to_string(), Message::new("group_not_found"));
+        }
+
+        if self.role_reader.get_role(&data.role_id).await.is_none() {
+            validator_err_map.insert("role_id".to_string(), Message::new("role_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        if !self.group_gateway.is_role_linked_to_group(&data.group_id, &data.role_id).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("group_role_not_linked"))
+                )
+            )
+        }
+
+        self.group_gateway.unlink_role_from_group(&data.group_id, &data.role_id).await;
+
+        // Роли группы меняются у всех участников сразу
+        for user_id in self.group_gateway.get_group_user_ids(&data.group_id).await.iter() {
+            self.session_remover.remove_user_sessions_from_cache(user_id).await;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupGateway;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::application::common::user_gateway::UserReader;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct UnlinkGroupUserDTO {
+    pub group_id: GroupId,
+    pub user_id: UserId,
+}
+
+pub struct UnlinkGroupUser<'a> {
+    pub group_gateway: &'a dyn GroupGateway,
+    pub user_reader: &'a dyn UserReader,
+    pub session_remover: &'a dyn SessionRemover,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<UnlinkGroupUserDTO, ()> for UnlinkGroupUser<'_> {
+    async fn execute(&self, data: UnlinkGroupUserDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_manage_group_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.group_gateway.get_group(&data.group_id).await.is_none() {
+            validator_err_map.insert("group_id".to_string(), Message::new("group_not_found"));
+        }
+
+        if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        if !self.group_gateway.is_user_in_group(&data.group_id, &data.user_id).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("group_user_not_member"))
+                )
+            )
+        }
+
+        self.group_gateway.unlink_user_from_group(&data.group_id, &data.user_id).await;
+        self.session_remover.remove_user_sessions_from_cache(&data.user_id).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::group_gateway::GroupGateway;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::group::GroupId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::group::GroupService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateGroupDTO {
+    pub id: GroupId,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GroupResultDTO {
+    id: GroupId,
+    title: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+pub struct UpdateGroup<'a> {
+    pub group_gateway: &'a dyn GroupGateway,
+    pub group_service: &'a GroupService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
+}
+
+impl Interactor<UpdateGroupDTO, GroupResultDTO> for UpdateGroup<'_> {
+    async fn execute(&self, data: UpdateGroupDTO) -> Result<GroupResultDTO, ApplicationError> {
+        
+        match self.access_service.ensure_can_update_group(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        self.validator.validate_group_title(&data.title).unwrap_or_else(|e| {
+            validator_err_map.insert("title".to_string(), e);
+        });
+
+        if let Some(description) = &data.description {
+            self.validator.validate_group_description(description).unwrap_or_else(|e| {
+                validator_err_map.insert("description".to_string(), e);
+            });
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let old_group = self.group_gateway.get_group(&data.id).await.ok_or_else(|| {
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("group_not_found"))
+            )
+        })?;
+
+        if let Some(group) = self.group_gateway.get_group_by_title_not_sensitive(&data.title).await {
+            if group.id != old_group.id {
+                return Err(
+                    ApplicationError::InvalidData(
+                        ErrorContent::Map(HashMap::from([("title".to_string(), Message::new("group_title_taken"))]))
+                    )
+                )
+            }
+        }
+        
+        let new_group = self.group_service.update_group(
+            old_group,
+            data.title,
+            data.description
+        );
+        
+        self.group_gateway.save_group(&new_group).await;
+        
+        Ok(GroupResultDTO {
+            id: new_group.id,
+            title: new_group.title,
+            description: new_group.description,
+            created_at: new_group.created_at,
+            updated_at: new_group.updated_at,
+        })
+    }
+}
@@ -1,7 +1,4 @@
 use chrono::Utc;
-use rand::distributions::Alphanumeric;
-use rand::Rng;
-use rand::seq::SliceRandom;
 use strum::IntoEnumIterator;
 
 use crate::application::common::hasher::Hasher;
@@ -20,13 +17,15 @@ use crate::domain::services::external::ExternalService;
 use crate::domain::services::permission::PermissionService;
 use crate::domain::services::role::RoleService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
 
 pub async fn service_permissions(
     service_gateway: &dyn ServiceGateway,
     permission_gateway: &dyn PermissionGateway,
     permission_service: &PermissionService,
     service_text_id: ServiceTextId,
-    external_service: &ExternalService
+    external_service: &ExternalService,
+    validator: &ValidatorService
 ) {
     let permission_text_ids = UMSPermission::iter().map(|permission| {
         permission.to_string()
@@ -36,13 +35,15 @@ pub async fn service_permissions(
         service_gateway, 
         permission_gateway,
         permission_service,
-        external_service
+        external_service,
+        validator
     };
 
     executor.execute(
         ServiceSyncDTO {
             service_text_id,
-            permission_text_ids
+            permission_text_ids,
+            attribute_schema: None
         }
     ).await.map_err(|e| {
         log::error!("Service permissions sync error: {}", e);
@@ -64,6 +65,7 @@ pub async fn control_account(
             let role = role_service.create_role(
                 "Control".to_string(),
                 Some("Временная роль для инициализации системы".to_string()),
+                None,
             );
             
             let permission_text_ids = vec![
@@ -112,31 +114,7 @@ pub async fn control_account(
             
             // Create control user
             
-            let password: String = {
-                let mut rng = rand::thread_rng();
-                
-                let numeric: String = (0..2)
-                    .map(|_| rng.gen_range('0'..'9'))
-                    .collect();
-                
-                let alphabetic: String = (0..2)
-                    .map(|_| rng.gen_range('A'..'Z'))
-                    .collect();
-
-                let alphanumeric: String = rng
-                    .sample_iter(&Alphanumeric)
-                    .take(8)
-                    .map(char::from)
-                    .collect();
-
-                rng = rand::thread_rng();
-                let mut password: Vec<char> = format!(
-                    "{}{}{}", numeric, alphabetic, alphanumeric
-                ).chars().collect();
-                password.shuffle(&mut rng);
-
-                password.into_iter().collect()
-            };
+            let password = user_service.generate_password(12);
             
             let password_hash = password_hasher.hash(password.as_str()).await;
             
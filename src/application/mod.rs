@@ -5,3 +5,6 @@ pub mod role;
 pub mod initial;
 pub mod service;
 pub mod permission;
+pub mod ban;
+pub mod organization;
+pub mod group;
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationGateway;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::organization::OrganizationService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateOrganizationDTO {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateOrganizationResultDTO {
+    id: OrganizationId,
+    title: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+pub struct CreateOrganization<'a> {
+    pub organization_gateway: &'a dyn OrganizationGateway,
+    pub organization_service: &'a OrganizationService,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<CreateOrganizationDTO, CreateOrganizationResultDTO> for CreateOrganization<'_> {
+    async fn execute(&self, data: CreateOrganizationDTO) -> Result<CreateOrganizationResultDTO, ApplicationError> {
+        
+        match self.access_service.ensure_can_create_organization(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        self.validator.validate_organization_title(&data.title).unwrap_or_else(|e| {
+            validator_err_map.insert("title".to_string(), e);
+        });
+
+        if let Some(description) = &data.description {
+            self.validator.validate_organization_description(description).unwrap_or_else(|e| {
+                validator_err_map.insert("description".to_string(), e);
+            });
+        }
+
+        if validator_err_map.is_empty() && self.organization_gateway
+            .get_organization_by_title_not_sensitive(&data.title).await.is_some() {
+            validator_err_map.insert("title".to_string(), Message::new("organization_title_taken"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let organization = self.organization_service.create_organization(
+            data.title,
+            data.description
+        );
+        
+        self.organization_gateway.save_organization(&organization).await;
+        
+        Ok(CreateOrganizationResultDTO {
+            id: organization.id,
+            title: organization.title,
+            description: organization.description,
+            created_at: organization.created_at,
+        })
+    }
+}
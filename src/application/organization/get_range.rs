@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationReader;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct OrganizationRangeDTO {
+    pub cursor: Option<String>,
+    pub per_page: u64,
+    pub with_total: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationItemResult {
+    id: OrganizationId,
+    title: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+pub type OrganizationRangeResultDTO = PageDTO<OrganizationItemResult>;
+
+pub struct GetOrganizationRange<'a> {
+    pub organization_reader: &'a dyn OrganizationReader,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
+}
+
+impl Interactor<OrganizationRangeDTO, OrganizationRangeResultDTO> for GetOrganizationRange<'_> {
+    async fn execute(&self, data: OrganizationRangeDTO) -> Result<OrganizationRangeResultDTO, ApplicationError> {
+        
+        match self.access_service.ensure_can_get_organization(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        }
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
+        });
+
+        self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
+            validator_err_map.insert("per_page".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let organizations = self.organization_reader.get_organizations_range(
+            cursor.as_ref(),
+            &(data.per_page + 1)
+        ).await;
+        let total = match data.with_total {
+            true => Some(self.organization_reader.count_organizations().await),
+            false => None
+        };
+        
+        Ok(PageDTO::from_rows(
+            organizations,
+            data.per_page,
+            total,
+            |organization| Cursor::new(CursorValue::Time(organization.created_at), organization.id),
+            |organization| OrganizationItemResult {
+                id: organization.id,
+                title: organization.title,
+                description: organization.description,
+                created_at: organization.created_at,
+                updated_at: organization.updated_at,
+            }
+        ))
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationGateway;
+use crate::application::common::permission_gateway::PermissionReader;
+use crate::application::common::role_gateway::RoleReader;
+use crate::application::organization::actor_organization_permissions;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::models::permission::PermissionId;
+use crate::domain::models::role::RoleId;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct LinkOrganizationRoleDTO {
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+    pub role_id: RoleId,
+}
+
+pub struct LinkOrganizationRole<'a> {
+    pub organization_gateway: &'a dyn OrganizationGateway,
+    pub role_reader: &'a dyn RoleReader,
+    pub permission_reader: &'a dyn PermissionReader,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub ums_service_text_id: &'a ServiceTextId,
+}
+
+impl Interactor<LinkOrganizationRoleDTO, ()> for LinkOrganizationRole<'_> {
+    async fn execute(&self, data: LinkOrganizationRoleDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_manage_organization_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions(),
+            &actor_organization_permissions(
+                self.organization_gateway,
+                &*self.id_provider,
+                &data.organization_id,
+                self.ums_service_text_id
+            ).await
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.organization_gateway.get_organization(&data.organization_id).await.is_none() {
+            validator_err_map.insert("organization_id".to_string(), Message::new("organization_not_found"));
+        } else if !self.organization_gateway.is_user_in_organization(&data.organization_id, &data.user_id).await {
+            // Роли в организации назначаются только ее участникам
+            validator_err_map.insert("user_id".to_string(), Message::new("organization_user_not_member"));
+        }
+
+        if self.role_reader.get_role(&data.role_id).await.is_none() {
+            validator_err_map.insert("role_id".to_string(), Message::new("role_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let role_permission_ids: Vec<PermissionId> = self.permission_reader
+            .get_role_permissions(&data.role_id).await
+            .into_iter()
+            .chain(self.permission_reader.get_role_inherited_permissions(&data.role_id).await)
+            .map(|permission| permission.id)
+            .collect();
+        let actor_permission_ids = match self.id_provider.user_id() {
+            Some(user_id) => self.organization_gateway
+                .get_user_organization_permission_ids(&data.organization_id, user_id).await,
+            None => Vec::new()
+        };
+
+        match self.access_service.ensure_can_link_organization_role(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions(),
+            &actor_permission_ids,
+            &role_permission_ids
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        if self.organization_gateway.is_role_linked_to_user_in_organization(
+            &data.organization_id,
+            &data.user_id,
+            &data.role_id
+        ).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("role_already_linked"))
+                )
+            )
+        }
+
+        self.organization_gateway.link_role_to_user_in_organization(
+            &data.organization_id,
+            &data.user_id,
+            &data.role_id
+        ).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationGateway;
+use crate::application::common::user_gateway::UserReader;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct LinkOrganizationUserDTO {
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+}
+
+pub struct LinkOrganizationUser<'a> {
+    pub organization_gateway: &'a dyn OrganizationGateway,
+    pub user_reader: &'a dyn UserReader,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<LinkOrganizationUserDTO, ()> for LinkOrganizationUser<'_> {
+    async fn execute(&self, data: LinkOrganizationUserDTO) -> Result<(), ApplicationError> {
+        
+        // Добавить в организацию любого пользователя системы может только глобальный
+        // администратор: права, полученные в самой организации, здесь не учитываются
+        match self.access_service.ensure_can_manage_organization_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions(),
+            &Vec::new()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.organization_gateway.get_organization(&data.organization_id).await.is_none() {
+            validator_err_map.insert("organization_id".to_string(), Message::new("organization_not_found"));
+        }
+
+        if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        if self.organization_gateway.is_user_in_organization(&data.organization_id, &data.user_id).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("organization_user_already_member"))
+                )
+            )
+        }
+
+        self.organization_gateway.link_user_to_organization(&data.organization_id, &data.user_id).await;
+
+        Ok(())
+    }
+}
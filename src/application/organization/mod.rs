@@ -0,0 +1,28 @@
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::organization_gateway::OrganizationReader;
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::service::ServiceTextId;
+
+pub mod create;
+pub mod get_range;
+pub mod link_user;
+pub mod unlink_user;
+pub mod link_role;
+pub mod unlink_role;
+
+/// Разрешения UMS, которые текущий пользователь получил через роли в организации
+async fn actor_organization_permissions(
+    organization_reader: &dyn OrganizationReader,
+    id_provider: &dyn IdProvider,
+    organization_id: &OrganizationId,
+    ums_service_text_id: &ServiceTextId
+) -> Vec<PermissionTextId> {
+    match id_provider.user_id() {
+        Some(user_id) => organization_reader
+            .get_user_organization_permissions(organization_id, user_id).await
+            .remove(ums_service_text_id)
+            .unwrap_or_default(),
+        None => Vec::new()
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationGateway;
+use crate::application::common::user_gateway::UserReader;
+use crate::application::organization::actor_organization_permissions;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::organization::OrganizationId;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct UnlinkOrganizationUserDTO {
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+}
+
+pub struct UnlinkOrganizationUser<'a> {
+    pub organization_gateway: &'a dyn OrganizationGateway,
+    pub user_reader: &'a dyn UserReader,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub ums_service_text_id: &'a ServiceTextId,
+}
+
+impl Interactor<UnlinkOrganizationUserDTO, ()> for UnlinkOrganizationUser<'_> {
+    async fn execute(&self, data: UnlinkOrganizationUserDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_manage_organization_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions(),
+            &actor_organization_permissions(
+                self.organization_gateway,
+                &*self.id_provider,
+                &data.organization_id,
+                self.ums_service_text_id
+            ).await
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.organization_gateway.get_organization(&data.organization_id).await.is_none() {
+            validator_err_map.insert("organization_id".to_string(), Message::new("organization_not_found"));
+        }
+
+        if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        if !self.organization_gateway.is_user_in_organization(&data.organization_id, &data.user_id).await {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("organization_user_not_member"))
+                )
+            )
+        }
+
+        self.organization_gateway.unlink_user_from_organization(&data.organization_id, &data.user_id).await;
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionReader;
 use crate::application::common::role_gateway::RoleReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::{PermissionId, PermissionTextId};
 use crate::domain::models::role::RoleId;
 use crate::domain::models::service::ServiceId;
@@ -23,6 +23,9 @@ pub struct PermissionItemResult{
     
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    
+    /// Разрешение унаследовано от родительской роли
+    inherited: bool,
 }
 
 pub type GetRolePermissionsResultDTO = Vec<PermissionItemResult>;
@@ -45,12 +48,12 @@ impl Interactor<RoleId, GetRolePermissionsResultDTO> for GetRolePermissions<'_>
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -60,16 +63,21 @@ impl Interactor<RoleId, GetRolePermissionsResultDTO> for GetRolePermissions<'_>
             &data
         ).await.ok_or(
             ApplicationError::InvalidData(
-                ErrorContent::Message("Роль не найдена".to_string())
+                ErrorContent::Message(Message::new("role_not_found"))
             )
         )?;
         
         let permissions = self.permission_reader.get_role_permissions(
             &data
         ).await;
+        let direct_permission_ids: Vec<PermissionId> = permissions.iter().map(|permission| permission.id).collect();
+        let inherited_permissions = self.permission_reader.get_role_inherited_permissions(
+            &data
+        ).await.into_iter().filter(|permission| !direct_permission_ids.contains(&permission.id));
         
         Ok(
-            permissions.into_iter().map(|u| PermissionItemResult {
+            permissions.into_iter().chain(inherited_permissions).map(|u| PermissionItemResult {
+                inherited: !direct_permission_ids.contains(&u.id),
                 id: u.id,
                 text_id: u.text_id,
                 service_id: u.service_id,
@@ -6,7 +6,7 @@ use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionReader;
 use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::{PermissionId, PermissionTextId};
 use crate::domain::models::service::ServiceId;
 use crate::domain::models::user::UserId;
@@ -23,6 +23,11 @@ pub struct PermissionItemResult{
     
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    
+    /// Разрешение получено только через родительские роли собственных ролей пользователя
+    inherited: bool,
+    /// Разрешение получено через роли групп пользователя
+    via_group: bool,
 }
 
 pub type GetUserPermissionsResultDTO = Vec<PermissionItemResult>;
@@ -45,12 +50,12 @@ impl Interactor<UserId, GetUserPermissionsResultDTO> for GetUserPermissions<'_>
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -60,23 +65,35 @@ impl Interactor<UserId, GetUserPermissionsResultDTO> for GetUserPermissions<'_>
             &data
         ).await.ok_or(
             ApplicationError::InvalidData(
-                ErrorContent::Message("Пользователь не найден".to_string())
+                ErrorContent::Message(Message::new("user_not_found"))
             )
         )?;
         
         let permissions = self.permission_reader.get_user_permissions(
             &data
         ).await;
+        let direct_permission_ids: Vec<PermissionId> = self.permission_reader.get_user_direct_permissions(
+            &data
+        ).await.into_iter().map(|permission| permission.id).collect();
+        let group_permission_ids: Vec<PermissionId> = self.permission_reader.get_user_group_permissions(
+            &data
+        ).await.into_iter().map(|permission| permission.id).collect();
         
         Ok(
-            permissions.into_iter().map(|u| PermissionItemResult {
-                id: u.id,
-                text_id: u.text_id,
-                service_id: u.service_id,
-                title: u.title,
-                description: u.description,
-                created_at: u.created_at,
-                updated_at: u.updated_at
+            permissions.into_iter().map(|u| {
+                let is_direct = direct_permission_ids.contains(&u.id);
+                let via_group = !is_direct && group_permission_ids.contains(&u.id);
+                PermissionItemResult {
+                    inherited: !is_direct && !via_group,
+                    via_group,
+                    id: u.id,
+                    text_id: u.text_id,
+                    service_id: u.service_id,
+                    title: u.title,
+                    description: u.description,
+                    created_at: u.created_at,
+                    updated_at: u.updated_at
+                }
             }).collect()
         )
     }
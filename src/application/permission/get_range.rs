@@ -6,8 +6,9 @@ use serde::{Deserialize, Serialize};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
 use crate::application::common::permission_gateway::PermissionReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::{PermissionId, PermissionTextId};
 use crate::domain::models::service::ServiceId;
 use crate::domain::services::access::AccessService;
@@ -15,8 +16,9 @@ use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetPermissionRangeDTO {
-    pub page: u64,
+    pub cursor: Option<String>,
     pub per_page: u64,
+    pub with_total: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -32,7 +34,7 @@ pub struct PermissionItemResult{
     updated_at: Option<DateTime<Utc>>,
 }
 
-pub type GetPermissionRangeResultDTO = Vec<PermissionItemResult>;
+pub type GetPermissionRangeResultDTO = PageDTO<PermissionItemResult>;
 
 
 pub struct GetPermissionRange<'a> {
@@ -52,24 +54,25 @@ impl Interactor<GetPermissionRangeDTO, GetPermissionRangeResultDTO> for GetPermi
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_page(&data.page).unwrap_or_else(|e| {
-            validator_err_map.insert("page".to_string(), e.to_string());
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
         });
         
         self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
-            validator_err_map.insert("per_page".to_string(), e.to_string());
+            validator_err_map.insert("per_page".to_string(), e);
         });
         
         if !validator_err_map.is_empty() {
@@ -81,12 +84,20 @@ impl Interactor<GetPermissionRangeDTO, GetPermissionRangeResultDTO> for GetPermi
         }
         
         let permissions = self.permission_reader.get_permissions_list(
-            &data.per_page,
-            &(data.page * data.per_page)
+            cursor.as_ref(),
+            &(data.per_page + 1)
         ).await;
+        let total = match data.with_total {
+            true => Some(self.permission_reader.count_permissions().await),
+            false => None
+        };
         
-        Ok(
-            permissions.into_iter().map(|u| PermissionItemResult {
+        Ok(PageDTO::from_rows(
+            permissions,
+            data.per_page,
+            total,
+            |u| Cursor::new(CursorValue::Time(u.created_at), u.id),
+            |u| PermissionItemResult {
                 id: u.id,
                 text_id: u.text_id,
                 service_id: u.service_id,
@@ -94,7 +105,7 @@ impl Interactor<GetPermissionRangeDTO, GetPermissionRangeResultDTO> for GetPermi
                 description: u.description,
                 created_at: u.created_at,
                 updated_at: u.updated_at
-            }).collect()
-        )
+            }
+        ))
     }
 }
\ No newline at end of file
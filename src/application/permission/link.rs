@@ -7,7 +7,7 @@ use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionGateway;
 use crate::application::common::role_gateway::RoleReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::PermissionId;
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
@@ -36,24 +36,24 @@ impl Interactor<LinkRolePermissionDTO, ()> for LinkRolePermission<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         if self.role_reader.get_role(&data.role_id).await.is_none() {
-            validator_err_map.insert("role_id".to_string(), "Роль не найдена".to_string());
+            validator_err_map.insert("role_id".to_string(), Message::new("role_not_found"));
         }
 
         if self.permission_gateway.get_permission_by_id(&data.permission_id).await.is_none() {
-            validator_err_map.insert("permission_id".to_string(), "Разрешение не найдено".to_string());
+            validator_err_map.insert("permission_id".to_string(), Message::new("permission_not_found"));
         }
 
         if !validator_err_map.is_empty() {
@@ -67,7 +67,7 @@ impl Interactor<LinkRolePermissionDTO, ()> for LinkRolePermission<'_> {
         if self.permission_gateway.is_permission_linked_to_role(&data.role_id, &data.permission_id).await {
             return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Разрешение уже привязано к данной роли".to_string())
+                    ErrorContent::Message(Message::new("permission_already_linked"))
                 )
             )
         }
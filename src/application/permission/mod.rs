@@ -3,4 +3,6 @@ pub mod get_by_user;
 pub mod get_by_role;
 pub mod update;
 pub mod link;
-pub mod unlink;
\ No newline at end of file
+pub mod unlink;
+pub mod link_user;
+pub mod unlink_user;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::permission_gateway::PermissionGateway;
+use crate::application::common::user_gateway::UserReader;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::permission::PermissionId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Deserialize)]
+pub struct UnlinkUserPermissionDTO {
+    pub user_id: UserId,
+    pub permission_id: PermissionId,
+}
+
+pub struct UnlinkUserPermission<'a> {
+    pub user_reader: &'a dyn UserReader,
+    pub permission_gateway: &'a dyn PermissionGateway,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<UnlinkUserPermissionDTO, ()> for UnlinkUserPermission<'_> {
+    async fn execute(&self, data: UnlinkUserPermissionDTO) -> Result<(), ApplicationError> {
+        
+        match self.access_service.ensure_can_link_permission(
+            self.id_provider.is_auth(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
+        }
+
+        if self.permission_gateway.get_permission_by_id(&data.permission_id).await.is_none() {
+            validator_err_map.insert("permission_id".to_string(), Message::new("permission_not_found"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        if self.permission_gateway.get_user_permission_effect(&data.user_id, &data.permission_id).await.is_none() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("user_permission_not_linked"))
+                )
+            )
+        }
+        
+        self.permission_gateway.unlink_permission_from_user(&data.user_id, &data.permission_id).await;
+        
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::{PermissionId, PermissionTextId};
 use crate::domain::models::service::ServiceId;
 use crate::domain::models::user::UserId;
@@ -55,25 +55,25 @@ impl Interactor<UpdatePermissionDTO, UpdatePermissionResultDTO> for UpdatePermis
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         self.validator.validate_permission_title(&data.title).unwrap_or_else(|e| {
-            validator_err_map.insert("title".to_string(), e.to_string());
+            validator_err_map.insert("title".to_string(), e);
         });
         
         if let Some(description) = &data.description {
             self.validator.validate_permission_description(description).unwrap_or_else(|e| {
-                validator_err_map.insert("description".to_string(), e.to_string());
+                validator_err_map.insert("description".to_string(), e);
             });
         }
         
@@ -89,7 +89,7 @@ impl Interactor<UpdatePermissionDTO, UpdatePermissionResultDTO> for UpdatePermis
             &data.id
         ).await.ok_or(
             ApplicationError::InvalidData(
-                ErrorContent::Message("Указанный идентификатор разрешения не найден".to_string())
+                ErrorContent::Message(Message::new("permission_not_found"))
             )
         )?;
         
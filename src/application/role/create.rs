@@ -7,7 +7,7 @@ use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionGateway;
 use crate::application::common::role_gateway::RoleGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::permission::{PermissionId, PermissionTextId};
 use crate::domain::models::role::RoleId;
 use crate::domain::models::service::ServiceId;
@@ -19,7 +19,8 @@ use crate::domain::services::validator::ValidatorService;
 pub struct CreateRoleDTO {
     pub title: String,
     pub description: Option<String>,
-    pub permissions: Vec<PermissionId>
+    pub permissions: Vec<PermissionId>,
+    pub parent_id: Option<RoleId>,
 }
 
 #[derive(Debug, Serialize)]
@@ -37,6 +38,7 @@ pub struct CreateRoleResultDTO{
     id: RoleId,
     title: String,
     description: Option<String>,
+    parent_id: Option<RoleId>,
     permissions: Vec<PermissionItem>
 }
 
@@ -61,27 +63,27 @@ impl Interactor<CreateRoleDTO, CreateRoleResultDTO> for CreateRole<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
         
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         self.validator.validate_role_title(&data.title).unwrap_or_else(|e| {
-            validator_err_map.insert("title".to_string(), e.to_string());
+            validator_err_map.insert("title".to_string(), e);
         });
 
         if data.description.is_some() {
             self.validator.validate_role_description(&data.description.clone().unwrap()).unwrap_or_else(
                 |e| {
-                    validator_err_map.insert("description".to_string(), e.to_string());
+                    validator_err_map.insert("description".to_string(), e);
                 }
             );
         }
@@ -97,7 +99,13 @@ impl Interactor<CreateRoleDTO, CreateRoleResultDTO> for CreateRole<'_> {
         let role_by_title = self.role_gateway.get_role_by_title_not_sensitive(&data.title).await;
         
         if role_by_title.is_some() {
-            validator_err_map.insert("title".to_string(), "Роль с таким названием уже существует".to_string());
+            validator_err_map.insert("title".to_string(), Message::new("role_title_taken"));
+        }
+        
+        if let Some(parent_id) = &data.parent_id {
+            if self.role_gateway.get_role(parent_id).await.is_none() {
+                validator_err_map.insert("parent_id".to_string(), Message::new("role_not_found"));
+            }
         }
         
         if !validator_err_map.is_empty() {
@@ -114,7 +122,7 @@ impl Interactor<CreateRoleDTO, CreateRoleResultDTO> for CreateRole<'_> {
             Some(permissions) => permissions,
             None => {
                 validator_err_map.insert(
-                    "permissions".to_string(), "Не все указанные права были найдены".to_string()
+                    "permissions".to_string(), Message::new("permissions_not_found")
                 );
                 return Err(
                     ApplicationError::InvalidData(
@@ -127,6 +135,7 @@ impl Interactor<CreateRoleDTO, CreateRoleResultDTO> for CreateRole<'_> {
         let role = self.role_service.create_role(
             data.title,
             data.description,
+            data.parent_id,
         );
         
         self.role_gateway.save_role(&role).await;
@@ -142,6 +151,7 @@ impl Interactor<CreateRoleDTO, CreateRoleResultDTO> for CreateRole<'_> {
             id: role.id,
             title: role.title,
             description: role.description,
+            parent_id: role.parent_id,
             permissions: permissions.iter().map(|permission| {
                 PermissionItem {
                     id: permission.id,
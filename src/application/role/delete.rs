@@ -4,7 +4,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
 
@@ -31,12 +31,12 @@ impl Interactor<DeleteRoleDTO, ()> for DeleteRole<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -44,7 +44,7 @@ impl Interactor<DeleteRoleDTO, ()> for DeleteRole<'_> {
         
         self.role_gateway.get_role(&data.id).await.ok_or_else(|| {
             ApplicationError::NotFound(
-                ErrorContent::Message("Роль не найдена".to_string())
+                ErrorContent::Message(Message::new("role_not_found"))
             )
         })?;
 
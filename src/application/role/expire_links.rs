@@ -0,0 +1,42 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::application::common::exceptions::ApplicationError;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::role_gateway::RoleGateway;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::domain::models::user::UserId;
+
+/// Промежуток между запусками фоновой задачи
+pub struct ExpireRoleLinksDTO {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+}
+
+pub struct ExpireRoleLinks<'a> {
+    pub role_gateway: &'a dyn RoleGateway,
+    pub session_remover: &'a dyn SessionRemover,
+}
+
+impl Interactor<ExpireRoleLinksDTO, ()> for ExpireRoleLinks<'_> {
+    async fn execute(&self, data: ExpireRoleLinksDTO) -> Result<(), ApplicationError> {
+        let mut affected_users: HashSet<UserId> = HashSet::new();
+        
+        affected_users.extend(self.role_gateway.remove_expired_role_links(&data.until).await);
+        affected_users.extend(
+            self.role_gateway.get_users_with_started_role_links(&data.since, &data.until).await
+        );
+        
+        // Разрешения в кеше сессий пересчитываются при следующем запросе
+        for user_id in affected_users.iter() {
+            self.session_remover.remove_user_sessions_from_cache(user_id).await;
+        }
+        
+        if !affected_users.is_empty() {
+            log::info!("Назначения ролей изменились у пользователей: {}", affected_users.len());
+        }
+        
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
 
@@ -21,6 +21,7 @@ pub struct RoleByIdResultDTO{
     description: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    parent_id: Option<RoleId>,
 }
 
 
@@ -42,12 +43,12 @@ impl Interactor<GetRoleByIdDTO, RoleByIdResultDTO> for GetRoleById<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -57,7 +58,7 @@ impl Interactor<GetRoleByIdDTO, RoleByIdResultDTO> for GetRoleById<'_> {
             Some(role) => role,
             None => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Роль не найдена".to_string())
+                    ErrorContent::Message(Message::new("role_not_found"))
                 )
             )
         };
@@ -68,6 +69,7 @@ impl Interactor<GetRoleByIdDTO, RoleByIdResultDTO> for GetRoleById<'_> {
             description: role.description,
             created_at: role.created_at,
             updated_at: role.updated_at,
+            parent_id: role.parent_id,
         })
     }
 }
\ No newline at end of file
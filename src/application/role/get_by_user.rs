@@ -6,7 +6,7 @@ use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleReader;
 use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
 
@@ -22,6 +22,7 @@ pub struct RoleItemResult{
     description: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    parent_id: Option<RoleId>,
 }
 
 pub type UserRolesResultDTO = Vec<RoleItemResult>;
@@ -46,12 +47,12 @@ impl Interactor<GetUserRolesDTO, UserRolesResultDTO> for GetUserRoles<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -61,7 +62,7 @@ impl Interactor<GetUserRolesDTO, UserRolesResultDTO> for GetUserRoles<'_> {
             Some(_) => (),
             None => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Пользователь не найден".to_string())
+                    ErrorContent::Message(Message::new("user_not_found"))
                 )
             )
         };
@@ -74,6 +75,7 @@ impl Interactor<GetUserRolesDTO, UserRolesResultDTO> for GetUserRoles<'_> {
             description: role.description,
             created_at: role.created_at,
             updated_at: role.updated_at,
+            parent_id: role.parent_id,
         }).collect())
     }
 }
\ No newline at end of file
@@ -6,16 +6,18 @@ use serde::{Deserialize, Serialize};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
 use crate::application::common::role_gateway::RoleReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct RoleRangeDTO {
-    pub page: u64,
+    pub cursor: Option<String>,
     pub per_page: u64,
+    pub with_total: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -25,9 +27,10 @@ pub struct RoleItemResult{
     description: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    parent_id: Option<RoleId>,
 }
 
-pub type RoleRangeResultDTO = Vec<RoleItemResult>;
+pub type RoleRangeResultDTO = PageDTO<RoleItemResult>;
 
 
 pub struct GetRoleRange<'a> {
@@ -49,24 +52,25 @@ impl Interactor<RoleRangeDTO, RoleRangeResultDTO> for GetRoleRange<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         }
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_page(&data.page).unwrap_or_else(|e| {
-            validator_err_map.insert("page".to_string(), e.to_string());
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
         });
 
         self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
-            validator_err_map.insert("per_page".to_string(), e.to_string());
+            validator_err_map.insert("per_page".to_string(), e);
         });
 
         if !validator_err_map.is_empty() {
@@ -79,19 +83,27 @@ impl Interactor<RoleRangeDTO, RoleRangeResultDTO> for GetRoleRange<'_> {
         
         
         let roles = self.role_gateway.get_roles_range(
-            &data.per_page,
-            &(data.page * data.per_page)
+            cursor.as_ref(),
+            &(data.per_page + 1)
         ).await;
+        let total = match data.with_total {
+            true => Some(self.role_gateway.count_roles().await),
+            false => None
+        };
         
-        Ok(
-            roles.iter().map(|role| RoleItemResult{
+        Ok(PageDTO::from_rows(
+            roles,
+            data.per_page,
+            total,
+            |role| Cursor::new(CursorValue::Time(role.created_at), role.id),
+            |role| RoleItemResult{
                 id: role.id,
-                title: role.title.clone(),
-                description: role.description.clone(),
+                title: role.title,
+                description: role.description,
                 created_at: role.created_at,
-                updated_at: role.updated_at
-            }).collect()
-        )
-        
+                updated_at: role.updated_at,
+                parent_id: role.parent_id
+            }
+        ))
     }
 }
\ No newline at end of file
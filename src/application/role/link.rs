@@ -1,28 +1,40 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleGateway;
+use crate::application::common::session_gateway::SessionRemover;
 use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
-use crate::domain::models::role::RoleId;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::resource_scope::ResourceScope;
+use crate::domain::models::role::{RoleId, RoleUserLink};
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct LinkRoleUserDTO {
     pub role_id: RoleId,
     pub user_id: UserId,
+    /// Пустой список - роль действует глобально
+    #[serde(default)]
+    pub scopes: Vec<ResourceScope>,
+    /// Срок действия назначения, None - без ограничения
+    pub starts_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 pub struct LinkRoleUser<'a> {
     pub role_gateway: &'a dyn RoleGateway,
     pub user_reader: &'a dyn UserReader,
+    pub session_remover: &'a dyn SessionRemover,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
 }
 
 impl Interactor<LinkRoleUserDTO, ()> for LinkRoleUser<'_> {
@@ -37,26 +49,38 @@ impl Interactor<LinkRoleUserDTO, ()> for LinkRoleUser<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         if self.role_gateway.get_role(&data.role_id).await.is_none() {
-            validator_err_map.insert("role_id".to_string(), "Роль не найдена".to_string());
+            validator_err_map.insert("role_id".to_string(), Message::new("role_not_found"));
         }
 
         if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
-            validator_err_map.insert("user_id".to_string(), "Пользователь не найден".to_string());
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
         }
 
+        if let Some(error) = data.scopes.iter()
+            .find_map(|scope| self.validator.validate_resource_scope(scope).err()) {
+            validator_err_map.insert("scopes".to_string(), error);
+        }
+
+        self.validator.validate_role_link_period(
+            data.starts_at.as_ref(),
+            data.expires_at.as_ref()
+        ).unwrap_or_else(|e| {
+            validator_err_map.insert("expires_at".to_string(), e);
+        });
+
         if !validator_err_map.is_empty() {
             return Err(
                 ApplicationError::InvalidData(
@@ -68,12 +92,19 @@ impl Interactor<LinkRoleUserDTO, ()> for LinkRoleUser<'_> {
         if self.role_gateway.is_role_linked_to_user(&data.role_id, &data.user_id).await {
             return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Роль уже привязана к данному пользователю".to_string())
+                    ErrorContent::Message(Message::new("role_already_linked"))
                 )
             )
         }
 
-        self.role_gateway.link_role_to_user(&data.role_id, &data.user_id).await;
+        self.role_gateway.save_role_user_link(&RoleUserLink {
+            role_id: data.role_id,
+            user_id: data.user_id,
+            scopes: data.scopes,
+            starts_at: data.starts_at,
+            expires_at: data.expires_at,
+        }).await;
+        self.session_remover.remove_user_sessions_from_cache(&data.user_id).await;
 
         Ok(())
     }
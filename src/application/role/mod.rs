@@ -7,3 +7,4 @@ pub mod update;
 pub mod delete;
 pub mod link;
 pub mod unlink;
+pub mod expire_links;
\ No newline at end of file
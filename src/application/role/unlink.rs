@@ -5,9 +5,10 @@ use serde::Deserialize;
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
-use crate::application::common::role_gateway::{RoleGateway, RoleReader};
+use crate::application::common::role_gateway::RoleGateway;
+use crate::application::common::session_gateway::SessionRemover;
 use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
@@ -21,6 +22,7 @@ pub struct UnlinkRoleUserDTO {
 pub struct UnlinkRoleUser<'a> {
     pub role_gateway: &'a dyn RoleGateway,
     pub user_reader: &'a dyn UserReader,
+    pub session_remover: &'a dyn SessionRemover,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
 }
@@ -36,24 +38,24 @@ impl Interactor<UnlinkRoleUserDTO, ()> for UnlinkRoleUser<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         if self.role_gateway.get_role(&data.role_id).await.is_none() {
-            validator_err_map.insert("role_id".to_string(), "Роль не найдена".to_string());
+            validator_err_map.insert("role_id".to_string(), Message::new("role_not_found"));
         }
 
         if self.user_reader.get_user_by_id(&data.user_id).await.is_none() {
-            validator_err_map.insert("user_id".to_string(), "Пользователь не найден".to_string());
+            validator_err_map.insert("user_id".to_string(), Message::new("user_not_found"));
         }
 
         if !validator_err_map.is_empty() {
@@ -67,12 +69,13 @@ impl Interactor<UnlinkRoleUserDTO, ()> for UnlinkRoleUser<'_> {
         if !self.role_gateway.is_role_linked_to_user(&data.role_id, &data.user_id).await {
             return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Роль не привязана к данному пользователю".to_string())
+                    ErrorContent::Message(Message::new("role_not_linked"))
                 )
             )
         }
         
         self.role_gateway.unlink_role_from_user(&data.role_id, &data.user_id).await;
+        self.session_remover.remove_user_sessions_from_cache(&data.user_id).await;
         
         Ok(())
     }
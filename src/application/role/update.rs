@@ -1,14 +1,14 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::role::RoleId;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::role::RoleService;
@@ -19,6 +19,18 @@ pub struct UpdateRoleDTO {
     pub id: RoleId,
     pub title: String,
     pub description: Option<String>,
+    /// Поле отсутствует - родитель не меняется, `null` - роль становится корневой
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub parent_id: Option<Option<RoleId>>,
+}
+
+/// Присутствующее в запросе поле, в том числе `null`, оборачивается в `Some`
+fn deserialize_present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Serialize)]
@@ -28,6 +40,7 @@ pub struct RoleResultDTO{
     description: Option<String>,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    parent_id: Option<RoleId>,
 }
 
 
@@ -51,26 +64,26 @@ impl Interactor<UpdateRoleDTO, RoleResultDTO> for UpdateRole<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         self.validator.validate_role_title(&data.title).unwrap_or_else(|e| {
-            validator_err_map.insert("title".to_string(), e.to_string());
+            validator_err_map.insert("title".to_string(), e);
         });
 
         if data.description.is_some() {
             self.validator.validate_role_description(&data.description.clone().unwrap()).unwrap_or_else(
                 |e| {
-                    validator_err_map.insert("description".to_string(), e.to_string());
+                    validator_err_map.insert("description".to_string(), e);
                 }
             );
         }
@@ -87,29 +100,48 @@ impl Interactor<UpdateRoleDTO, RoleResultDTO> for UpdateRole<'_> {
             Some(role) => role,
             None => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Роль не найдена".to_string())
+                    ErrorContent::Message(Message::new("role_not_found"))
                 )
             )
         };
 
+        if let Some(Some(parent_id)) = &data.parent_id {
+            self.role_gateway.get_role(parent_id).await.ok_or_else(
+                || ApplicationError::InvalidData(
+                    ErrorContent::Map(HashMap::from([("parent_id".to_string(), Message::new("role_not_found"))]))
+                )
+            )?;
+            
+            let parent_chain: Vec<RoleId> = std::iter::once(*parent_id).chain(
+                self.role_gateway.get_role_ancestors(parent_id).await.into_iter().map(|role| role.id)
+            ).collect();
+            self.role_service.ensure_no_parent_cycle(&old_role.id, &parent_chain).map_err(
+                |error| ApplicationError::InvalidData(
+                    ErrorContent::Map(HashMap::from([("parent_id".to_string(), error)]))
+                )
+            )?;
+        }
+
         self.role_gateway.get_role_by_title_not_sensitive(&data.title).await.ok_or_else(
             || ApplicationError::InvalidData(
                 ErrorContent::Map(
-                    [("title".to_string(), "Роль с таким названием уже существует".to_string())]
+                    [("title".to_string(), Message::new("role_title_taken"))]
                     .iter().cloned().collect()
                 )
             )
         )?;
         
+        let parent_id = data.parent_id.unwrap_or(old_role.parent_id);
         let new_role = match self.role_service.update_role(
             old_role,
             data.title,
-            data.description
+            data.description,
+            parent_id
         ) {
             Ok(role) => role,
             Err(error) => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message(error.to_string())
+                    ErrorContent::Message(error.into())
                 )
             )
         };
@@ -122,6 +154,29 @@ impl Interactor<UpdateRoleDTO, RoleResultDTO> for UpdateRole<'_> {
             description: new_role.description,
             created_at: new_role.created_at,
             updated_at: new_role.updated_at,
+            parent_id: new_role.parent_id,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> UpdateRoleDTO {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_parent_id_absent_null_and_set() {
+        let id = Uuid::new_v4();
+        let base = format!(r#""id": "{}", "title": "role", "description": null"#, id);
+
+        assert_eq!(parse(&format!("{{{}}}", base)).parent_id, None);
+        assert_eq!(parse(&format!(r#"{{{}, "parent_id": null}}"#, base)).parent_id, Some(None));
+        assert_eq!(
+            parse(&format!(r#"{{{}, "parent_id": "{}"}}"#, base, id)).parent_id,
+            Some(Some(id))
+        );
+    }
+}
@@ -5,7 +5,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::service_gateway::ServiceReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::service::{ServiceId, ServiceTextId};
 use crate::domain::services::access::AccessService;
 
@@ -37,12 +37,12 @@ impl Interactor<ServiceId, ServiceResultDTO> for GetService<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -52,7 +52,7 @@ impl Interactor<ServiceId, ServiceResultDTO> for GetService<'_> {
             &data
         ).await.ok_or(
             ApplicationError::InvalidData(
-                ErrorContent::Message("Сервис не найден".to_string())
+                ErrorContent::Message(Message::new("service_not_found"))
             )
         )?;
         
@@ -6,16 +6,18 @@ use serde::{Deserialize, Serialize};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
 use crate::application::common::service_gateway::ServiceReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::service::{ServiceId, ServiceTextId};
 use crate::domain::services::access::AccessService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetServiceRangeDTO {
-    pub page: u64,
+    pub cursor: Option<String>,
     pub per_page: u64,
+    pub with_total: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -28,7 +30,7 @@ pub struct ServiceItemResult{
     updated_at: Option<DateTime<Utc>>,
 }
 
-pub type GetServiceRangeResultDTO = Vec<ServiceItemResult>;
+pub type GetServiceRangeResultDTO = PageDTO<ServiceItemResult>;
 
 
 pub struct GetServiceRange<'a> {
@@ -49,24 +51,25 @@ impl Interactor<GetServiceRangeDTO, GetServiceRangeResultDTO> for GetServiceRang
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_page(&data.page).unwrap_or_else(|e| {
-            validator_err_map.insert("page".to_string(), e.to_string());
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
         });
         
         self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
-            validator_err_map.insert("per_page".to_string(), e.to_string());
+            validator_err_map.insert("per_page".to_string(), e);
         });
         
         if !validator_err_map.is_empty() {
@@ -77,20 +80,28 @@ impl Interactor<GetServiceRangeDTO, GetServiceRangeResultDTO> for GetServiceRang
             )
         }
         
-        let permissions = self.service_reader.get_services(
-            &data.page,
-            &(data.page * data.per_page)
+        let services = self.service_reader.get_services(
+            cursor.as_ref(),
+            &(data.per_page + 1)
         ).await;
+        let total = match data.with_total {
+            true => Some(self.service_reader.count_services().await),
+            false => None
+        };
         
-        Ok(
-            permissions.into_iter().map(|u| ServiceItemResult {
+        Ok(PageDTO::from_rows(
+            services,
+            data.per_page,
+            total,
+            |u| Cursor::new(CursorValue::Time(u.created_at), u.id),
+            |u| ServiceItemResult {
                 id: u.id,
                 text_id: u.text_id,
                 title: u.title,
                 description: u.description,
                 created_at: u.created_at,
                 updated_at: u.updated_at,
-            }).collect()
-        )
+            }
+        ))
     }
 }
\ No newline at end of file
@@ -1,15 +1,24 @@
-use crate::application::common::exceptions::ApplicationError;
+use std::collections::HashMap;
+
+use serde_json::Value;
+use strum::IntoEnumIterator;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
 use crate::application::common::permission_gateway::PermissionGateway;
 use crate::application::common::service_gateway::ServiceGateway;
+use crate::domain::exceptions::Message;
+use crate::domain::models::attribute_permission::AttributePermission;
 use crate::domain::models::permission::{Permission, PermissionTextId};
 use crate::domain::models::service::ServiceTextId;
 use crate::domain::services::external::ExternalService;
 use crate::domain::services::permission::PermissionService;
+use crate::domain::services::validator::ValidatorService;
 
 pub struct ServiceSyncDTO {
     pub service_text_id: ServiceTextId,
     pub permission_text_ids: Vec<PermissionTextId>,
+    pub attribute_schema: Option<Value>,
 }
 
 
@@ -17,11 +26,27 @@ pub struct ServiceSync<'a> {
     pub service_gateway: &'a dyn ServiceGateway,
     pub permission_gateway: &'a dyn PermissionGateway,
     pub permission_service: &'a PermissionService,
-    pub external_service: &'a ExternalService
+    pub external_service: &'a ExternalService,
+    pub validator: &'a ValidatorService,
 }
 
 impl Interactor<ServiceSyncDTO, ()> for ServiceSync<'_> {
     async fn execute(&self, data: ServiceSyncDTO) -> Result<(), ApplicationError> {
+        if let Some(attribute_schema) = &data.attribute_schema {
+            let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+            self.validator.validate_attribute_schema(attribute_schema).unwrap_or_else(|e| {
+                validator_err_map.insert("attribute_schema".to_string(), e);
+            });
+
+            if !validator_err_map.is_empty() {
+                return Err(
+                    ApplicationError::InvalidData(
+                        ErrorContent::Map(validator_err_map)
+                    )
+                )
+            }
+        }
+        
         let service = match self.service_gateway.get_services_by_text_id(
             &data.service_text_id
         ).await {
@@ -36,13 +61,31 @@ impl Interactor<ServiceSyncDTO, ()> for ServiceSync<'_> {
                 service
             }
         };
+        let service = if service.attribute_schema != data.attribute_schema {
+            let service = self.external_service.update_service_attribute_schema(
+                service,
+                data.attribute_schema.clone()
+            );
+            self.service_gateway.save_service(&service).await;
+            service
+        } else {
+            service
+        };
+        
+        let mut permission_text_ids = data.permission_text_ids.clone();
+        if data.attribute_schema.is_some() {
+            permission_text_ids.extend(AttributePermission::iter().map(|permission| {
+                permission.to_string()
+            }).filter(|permission| !data.permission_text_ids.contains(permission)));
+        }
+        
         let permission_text_ids_from_repo = self.permission_gateway.get_permissions_by_service_id(
             &service.id
         ).await.iter().map(|permission| {
             permission.text_id.clone()
         }).collect::<Vec<PermissionTextId>>();
 
-        let permissions_to_add = data.permission_text_ids.iter().filter(
+        let permissions_to_add = permission_text_ids.iter().filter(
             |permission_text_id| {
                 !permission_text_ids_from_repo.contains(permission_text_id)
             }
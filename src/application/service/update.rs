@@ -7,7 +7,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::service_gateway::ServiceGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::service::{ServiceId, ServiceTextId};
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
@@ -51,25 +51,25 @@ impl Interactor<UpdateServiceDTO, UpdateServiceResultDTO> for UpdateService<'_>
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         self.validator.validate_service_title(&data.title).unwrap_or_else(|e| {
-            validator_err_map.insert("title".to_string(), e.to_string());
+            validator_err_map.insert("title".to_string(), e);
         });
         
         if let Some(description) = &data.description {
             self.validator.validate_service_description(description).unwrap_or_else(|e| {
-                validator_err_map.insert("description".to_string(), e.to_string());
+                validator_err_map.insert("description".to_string(), e);
             });
         }
         
@@ -85,7 +85,7 @@ impl Interactor<UpdateServiceDTO, UpdateServiceResultDTO> for UpdateService<'_>
             &data.id
         ).await.ok_or(
             ApplicationError::InvalidData(
-                ErrorContent::Message("Указанный идентификатор сервиса не найден".to_string())
+                ErrorContent::Message(Message::new("service_not_found"))
             )
         )?;
         
@@ -4,18 +4,24 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::application::common::access_log_gateway::AccessLogWriter;
+use crate::application::common::ban_gateway::BanReader;
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::hasher::Hasher;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::session_gateway::SessionGateway;
-use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::password_policy::PasswordPolicy;
 use crate::domain::models::session::SessionTokenHash;
 use crate::domain::models::user::UserState;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::access_log::AccessLogService;
+use crate::domain::services::ban::BanService;
 use crate::domain::services::session::SessionService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -32,25 +38,33 @@ pub struct CreateSessionResultDTO{
     state: UserState,
     first_name: Option<String>,
     last_name: Option<String>,
+    /// Сессия ограничена: до смены пароля доступна только она
+    password_change_required: bool,
 }
 
 pub struct CreateSession<'a> {
     pub session_gateway: &'a dyn SessionGateway,
-    pub user_gateway: &'a dyn UserReader,
+    pub user_gateway: &'a dyn UserGateway,
+    pub ban_reader: &'a dyn BanReader,
     pub access_log_writer: &'a dyn AccessLogWriter,
     pub access_log_service: &'a AccessLogService,
     pub session_service: &'a SessionService,
+    pub ban_service: &'a BanService,
+    pub user_service: &'a UserService,
+    pub state_effects: UserStateEffects<'a>,
     pub session_hasher: &'a dyn Hasher,
     pub id_provider: Box<dyn IdProvider>,
     pub password_hasher: &'a dyn Hasher,
     pub validator: &'a ValidatorService,
-    pub access_service: &'a AccessService
+    pub normalizer: &'a NormalizerService,
+    pub access_service: &'a AccessService,
+    pub password_policy: &'a PasswordPolicy,
 }
 
 impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> for CreateSession<'_> {
     async fn execute(
         &self, 
-        data: CreateSessionDTO
+        mut data: CreateSessionDTO
     ) -> Result<(CreateSessionResultDTO, SessionTokenHash), ApplicationError> {
 
         match self.access_service.ensure_can_create_session(
@@ -61,21 +75,23 @@ impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> fo
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 _ => panic!("Unexpected error")
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
         
+        data.username = self.normalizer.normalize_username(&data.username);
+
         self.validator.validate_username(&data.username).unwrap_or_else(|e| {
-            validator_err_map.insert("username".to_string(), e.to_string());
+            validator_err_map.insert("username".to_string(), e);
         });
 
-        self.validator.validate_password(&data.password).unwrap_or_else(|e| {
-            validator_err_map.insert("password".to_string(), e.to_string());
+        self.validator.validate_login_password(&data.password).unwrap_or_else(|e| {
+            validator_err_map.insert("password".to_string(), e);
         });
 
 
@@ -87,11 +103,11 @@ impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> fo
             )
         }
         
-        let user = match self.user_gateway.get_user_by_username_not_sensitive(&data.username).await {
+        let mut user = match self.user_gateway.get_user_by_username_not_sensitive(&data.username).await {
             Some(user) => user,
             None => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Неверная пара имя пользователя и пароль".to_string())
+                    ErrorContent::Message(Message::new("invalid_credentials"))
                 )
             )
         };
@@ -114,24 +130,75 @@ impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> fo
                 self.access_log_writer.save_rec(&access_log).await;
                 return Err(
                     ApplicationError::InvalidData(
-                        ErrorContent::Message("Неверная пара имя пользователя и пароль".to_string())
+                        ErrorContent::Message(Message::new("invalid_credentials"))
                     )
                 )
             }
         };
         
+        if user.state == UserState::Banned {
+            let ban = self.ban_reader.get_user_active_ban(&user.id).await;
+            match ban {
+                // Срок бана истек - возвращаем пользователя в состояние до бана
+                Some(ban) if self.ban_service.is_expired(&ban) => {
+                    let (unbanned_user, effects) = self.user_service.change_state(
+                        user,
+                        self.ban_service.restored_state(Some(&ban))
+                    ).map_err(
+                        |error| ApplicationError::InvalidData(
+                            ErrorContent::Message(error.into())
+                        )
+                    )?;
+                    user = unbanned_user;
+                    self.user_gateway.save_user(&user).await;
+                    self.state_effects.apply(&user, &effects).await;
+                },
+                ban => {
+                    self.access_log_writer.save_rec(&access_log).await;
+                    return Err(
+                        ApplicationError::Forbidden(
+                            ErrorContent::Message(match ban {
+                                Some(ban) => match ban.expires_at {
+                                    Some(expires_at) => Message::new("account_banned_until")
+                                        .arg("expires_at", expires_at.format("%d/%m/%Y %H:%M %Z"))
+                                        .arg("reason", ban.reason),
+                                    None => Message::new("account_banned_forever")
+                                        .arg("reason", ban.reason)
+                                },
+                                None => Message::new("account_banned")
+                            })
+                        )
+                    )
+                }
+            }
+        }
+
         if user.state == UserState::Inactive {
             return {
                 self.access_log_writer.save_rec(&access_log).await;
                 Err(
                     ApplicationError::InvalidData(
-                        ErrorContent::Message("Сначала подтвердите свой email".to_string())
+                        ErrorContent::Message(Message::new("email_not_confirmed"))
                     )
                 )
             }
         }
         
         
+        // Хеш с устаревшими параметрами пересчитывается, пока известен пароль
+        if self.password_hasher.needs_rehash(&user.hashed_password) {
+            user = self.user_service.rehash_password(
+                user,
+                self.password_hasher.hash(&data.password).await
+            );
+            self.user_gateway.save_user(&user).await;
+        }
+
+        let password_change_required = self.user_service.is_password_change_required(
+            &user,
+            self.password_policy.max_age_days
+        );
+        
         let session_token = self.session_service.create_session_token();
         let session_token_hash = self.session_hasher.hash(&session_token).await;
 
@@ -143,6 +210,10 @@ impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> fo
             self.id_provider.os().to_string(),
             self.id_provider.device().to_string(),
         );
+        let session = match password_change_required {
+            true => self.session_service.restrict(session),
+            false => session
+        };
         
         self.session_gateway.save_session(&session).await;
         
@@ -157,6 +228,7 @@ impl Interactor<CreateSessionDTO, (CreateSessionResultDTO, SessionTokenHash)> fo
                 state: user.state,
                 first_name: user.first_name,
                 last_name: user.last_name,
+                password_change_required,
             },
             session_token
         ))
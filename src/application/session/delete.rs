@@ -4,7 +4,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::session_gateway::SessionGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::session::SessionId;
 use crate::domain::services::access::AccessService;
 
@@ -33,12 +33,12 @@ impl Interactor<DeleteSessionDTO, ()> for DeleteSession<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -48,7 +48,7 @@ impl Interactor<DeleteSessionDTO, ()> for DeleteSession<'_> {
             Some(session) => session,
             None => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Сессия не найдена".to_string())
+                    ErrorContent::Message(Message::new("session_not_found"))
                 )
             )
         };
@@ -1,42 +1,62 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::hasher::Hasher;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::organization_gateway::OrganizationReader;
 use crate::application::common::session_gateway::SessionGateway;
+use crate::domain::exceptions::Message;
+use crate::domain::models::organization::OrganizationId;
 use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::resource_scope::PermissionScopes;
 use crate::domain::models::service::ServiceTextId;
 use crate::domain::models::session::{SessionId, SessionToken};
 use crate::domain::models::user::{UserId, UserState};
+use crate::domain::services::access::AccessService;
 use crate::domain::services::session::SessionService;
 use crate::domain::services::validator::ValidatorService;
 
+#[derive(Debug, Deserialize)]
+pub struct EPSessionDTO {
+    pub session_token: SessionToken,
+    /// Контекст организации: разрешения ролей в ней возвращаются отдельно
+    pub organization_id: Option<OrganizationId>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct EPSessionResultDTO{
     pub session_id: SessionId,
     pub user_id: UserId,
     pub user_state: UserState,
-    pub permissions: HashMap<ServiceTextId, Vec<PermissionTextId>>
+    pub organization_id: Option<OrganizationId>,
+    pub permissions: HashMap<ServiceTextId, Vec<PermissionTextId>>,
+    /// Разрешения ролей в организации `organization_id`, в глобальные не входят
+    pub organization_permissions: HashMap<ServiceTextId, Vec<PermissionTextId>>,
+    /// Разрешения, действующие только в пределах областей ресурсов
+    pub scopes: PermissionScopes
 }
 
 pub struct EPSession<'a> {
     pub session_gateway: &'a dyn SessionGateway,
+    pub organization_reader: &'a dyn OrganizationReader,
     pub session_service: &'a SessionService,
     pub session_hasher: &'a dyn Hasher,
     pub id_provider: Box<dyn IdProvider>,
     pub validator_service: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub ums_service_text_id: &'a ServiceTextId,
 }
 
-impl Interactor<SessionToken, EPSessionResultDTO> for EPSession<'_> {
-    async fn execute(&self, data: SessionToken) -> Result<EPSessionResultDTO, ApplicationError> {
-        let session_token_hash = match self.validator_service.validate_session_token(&data) {
-            Ok(_) => self.session_hasher.hash(data.as_str()).await,
+impl Interactor<EPSessionDTO, EPSessionResultDTO> for EPSession<'_> {
+    async fn execute(&self, data: EPSessionDTO) -> Result<EPSessionResultDTO, ApplicationError> {
+        let session_token_hash = match self.validator_service.validate_session_token(&data.session_token) {
+            Ok(_) => self.session_hasher.hash(data.session_token.as_str()).await,
             Err(error) => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message(error.to_string())
+                    ErrorContent::Message(error)
                 )
             )
         };
@@ -46,7 +66,8 @@ impl Interactor<SessionToken, EPSessionResultDTO> for EPSession<'_> {
         let (
             mut session, 
             user_state, 
-            permissions
+            permissions,
+            scopes
         ) = match self.session_gateway.get_session_by_token_hash_from_cache(
             &session_token_hash
         ).await {
@@ -57,7 +78,7 @@ impl Interactor<SessionToken, EPSessionResultDTO> for EPSession<'_> {
                     data
                 },
                 None => return Err(ApplicationError::Unauthorized(
-                    ErrorContent::Message("Токен не существует".to_string())
+                    ErrorContent::Message(Message::new("session_token_not_found"))
                 ))
             }
         };
@@ -74,7 +95,7 @@ impl Interactor<SessionToken, EPSessionResultDTO> for EPSession<'_> {
         ) {
             log::warn!("Сессия {} не прошла проверку по отпечатку! IP: {}", session.id, self.id_provider.ip());
             return Err(ApplicationError::Unauthorized(
-                ErrorContent::Message("Отпечаток сессии не совпадает с клиентским".to_string())
+                ErrorContent::Message(Message::new("session_fingerprint_mismatch"))
             ))
         }
         
@@ -87,15 +108,45 @@ impl Interactor<SessionToken, EPSessionResultDTO> for EPSession<'_> {
             self.session_gateway.save_session_to_cache(
                 &session,
                 &user_state,
-                &permissions
+                &permissions,
+                &scopes
             ).await;
         }
         
+        if session.restricted {
+            return Ok(EPSessionResultDTO{
+                session_id: session.id,
+                user_id: session.user_id,
+                user_state,
+                organization_id: None,
+                permissions: self.access_service.restricted_session_permissions(self.ums_service_text_id),
+                organization_permissions: HashMap::new(),
+                scopes: PermissionScopes::new()
+            })
+        }
+        
+        // Разрешения организации не кешируются вместе с сессией и не смешиваются
+        // с глобальными, иначе роль в одной организации давала бы права во всей UMS
+        let mut organization_permissions = HashMap::new();
+        if let Some(organization_id) = &data.organization_id {
+            if !self.organization_reader.is_user_in_organization(organization_id, &session.user_id).await {
+                return Err(ApplicationError::Forbidden(
+                    ErrorContent::Message(Message::new("organization_access_denied"))
+                ))
+            }
+            
+            organization_permissions = self.organization_reader
+                .get_user_organization_permissions(organization_id, &session.user_id).await;
+        }
+        
         Ok(EPSessionResultDTO{
             session_id: session.id,
             user_id: session.user_id,
             user_state,
-            permissions
+            organization_id: data.organization_id,
+            permissions,
+            organization_permissions,
+            scopes
         })
     }
 }
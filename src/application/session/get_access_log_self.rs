@@ -7,15 +7,17 @@ use crate::application::common::access_log_gateway::AccessLogReader;
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
-use crate::domain::exceptions::DomainError;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::access_log::AccessLogId;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetAccessLogSelfDTO {
-    pub page: u64,
+    pub cursor: Option<String>,
     pub per_page: u64,
+    pub with_total: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -31,7 +33,7 @@ pub struct AccessLogItemResult{
     pub created_at: DateTime<Utc>,
 }
 
-pub type AccessLogResultDTO = Vec<AccessLogItemResult>;
+pub type AccessLogResultDTO = PageDTO<AccessLogItemResult>;
 
 
 pub struct GetAccessLogSelf<'a> {
@@ -53,24 +55,25 @@ impl Interactor<GetAccessLogSelfDTO, AccessLogResultDTO> for GetAccessLogSelf<'_
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             } 
         }
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_page(&data.page).unwrap_or_else(|e| {
-            validator_err_map.insert("page".to_string(), e.to_string());
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
         });
 
         self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
-            validator_err_map.insert("per_page".to_string(), e.to_string());
+            validator_err_map.insert("per_page".to_string(), e);
         });
 
         if !validator_err_map.is_empty() {
@@ -83,20 +86,28 @@ impl Interactor<GetAccessLogSelfDTO, AccessLogResultDTO> for GetAccessLogSelf<'_
         
         let records = self.access_log_reader.get_user_records(
             self.id_provider.user_id().unwrap(),
-            &data.per_page,
-            &(data.page * data.per_page)
+            cursor.as_ref(),
+            &(data.per_page + 1)
         ).await;
-        
-        Ok(
-            records.iter().map(|session| AccessLogItemResult {
-                id: session.id.clone(),
-                is_success: session.is_success.clone(),
-                ip: session.ip.clone(),
-                client: session.client.clone(),
-                os: session.os.clone(),
-                device: session.device.clone(),
-                created_at: session.created_at.clone(),
-            }).collect()
-        )
+        let total = match data.with_total {
+            true => Some(self.access_log_reader.count_user_records(self.id_provider.user_id().unwrap()).await),
+            false => None
+        };
+
+        Ok(PageDTO::from_rows(
+            records,
+            data.per_page,
+            total,
+            |record| Cursor::new(CursorValue::Time(record.created_at), record.id),
+            |record| AccessLogItemResult {
+                id: record.id,
+                is_success: record.is_success,
+                ip: record.ip,
+                client: record.client,
+                os: record.os,
+                device: record.device,
+                created_at: record.created_at,
+            }
+        ))
     }
 }
\ No newline at end of file
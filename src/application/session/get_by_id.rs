@@ -5,7 +5,7 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::session_gateway::SessionReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::session::SessionId;
 use crate::domain::services::access::AccessService;
 
@@ -41,12 +41,12 @@ impl Interactor<SessionId, SessionByIdResultDTO> for GetSessionById<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -56,7 +56,7 @@ impl Interactor<SessionId, SessionByIdResultDTO> for GetSessionById<'_> {
             Some(session) => session,
             None => return Err(
                 ApplicationError::NotFound(
-                    ErrorContent::Message("Запрашиваемая сессия не найдена".to_string())
+                    ErrorContent::Message(Message::new("session_not_found"))
                 )
             ),
         };
@@ -1,14 +1,26 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
 use crate::application::common::session_gateway::SessionReader;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::session::SessionId;
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct SessionsByUserIdDTO {
+    pub user_id: UserId,
+    pub cursor: Option<String>,
+    pub per_page: u64,
+    pub with_total: bool,
+}
 
 #[derive(Debug, Serialize)]
 pub struct SessionItemResult{
@@ -21,22 +33,23 @@ pub struct SessionItemResult{
     updated_at: Option<DateTime<Utc>>,
 }
 
-pub type SessionsByUserIdResultDTO = Vec<SessionItemResult>;
+pub type SessionsByUserIdResultDTO = PageDTO<SessionItemResult>;
 
 
 pub struct GetSessionsByUserId<'a> {
     pub session_reader: &'a dyn SessionReader,
     pub id_provider: Box<dyn IdProvider>,
-    pub access_service: &'a AccessService
+    pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
 }
 
-impl Interactor<UserId, SessionsByUserIdResultDTO> for GetSessionsByUserId<'_> {
-    async fn execute(&self, data: UserId) -> Result<SessionsByUserIdResultDTO, ApplicationError> {
+impl Interactor<SessionsByUserIdDTO, SessionsByUserIdResultDTO> for GetSessionsByUserId<'_> {
+    async fn execute(&self, data: SessionsByUserIdDTO) -> Result<SessionsByUserIdResultDTO, ApplicationError> {
 
         match self.access_service.ensure_can_get_sessions(
             self.id_provider.is_auth(),
             self.id_provider.user_id(),
-            &data,
+            &data.user_id,
             self.id_provider.user_state(),
             &self.id_provider.permissions()
         ) {
@@ -44,29 +57,59 @@ impl Interactor<UserId, SessionsByUserIdResultDTO> for GetSessionsByUserId<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             } 
         }
         
-        let sessions = self.session_reader.get_user_sessions(&data).await;
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
+        });
+
+        self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
+            validator_err_map.insert("per_page".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let sessions = self.session_reader.get_user_sessions_range(
+            &data.user_id,
+            cursor.as_ref(),
+            &(data.per_page + 1)
+        ).await;
+        let total = match data.with_total {
+            true => Some(self.session_reader.count_user_sessions(&data.user_id).await),
+            false => None
+        };
         
-        Ok(
-            sessions.iter().map(|session| SessionItemResult {
-                id: session.id.clone(),
-                ip: session.ip.clone(),
-                client: session.client.clone(),
-                os: session.os.clone(),
-                device: session.device.clone(),
-                created_at: session.created_at.clone(),
-                updated_at: session.updated_at.clone()
-            }).collect()
-        )
+        Ok(PageDTO::from_rows(
+            sessions,
+            data.per_page,
+            total,
+            |session| Cursor::new(CursorValue::Time(session.created_at), session.id),
+            |session| SessionItemResult {
+                id: session.id,
+                ip: session.ip,
+                client: session.client,
+                os: session.os,
+                device: session.device,
+                created_at: session.created_at,
+                updated_at: session.updated_at
+            }
+        ))
     }
 }
\ No newline at end of file
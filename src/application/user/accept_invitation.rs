@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::application::common::breached_password_checker::BreachedPasswordChecker;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::hasher::Hasher;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::one_time_token::OneTimeToken;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::exceptions::Message;
+use crate::domain::models::user::{UserId, UserState};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptInvitationDTO {
+    pub token: String,
+    pub password: String,
+}
+
+pub struct AcceptInvitation<'a> {
+    pub one_time_token: &'a dyn OneTimeToken,
+    pub user_gateway: &'a dyn UserGateway,
+    pub user_service: &'a UserService,
+    pub state_effects: UserStateEffects<'a>,
+    pub password_hasher: &'a dyn Hasher,
+    pub breached_password_checker: &'a dyn BreachedPasswordChecker,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<AcceptInvitationDTO, ()> for AcceptInvitation<'_> {
+    async fn execute(&self, data: AcceptInvitationDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_accept_invitation(
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(e) => return Err(
+                ApplicationError::Forbidden(
+                    ErrorContent::Message(e.into())
+                )
+            )
+        };
+
+        let invalid_link = || ApplicationError::InvalidData(
+            ErrorContent::Message(Message::new("link_expired"))
+        );
+
+        let payload = self.one_time_token.peek(&data.token).await.ok_or_else(invalid_link)?;
+
+        let user_id = payload
+            .strip_prefix("invitation:")
+            .and_then(|user_id| user_id.parse::<UserId>().ok())
+            .ok_or_else(invalid_link)?;
+
+        let user = self.user_gateway.get_user_by_id(&user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        if user.state != UserState::Inactive {
+            return Err(invalid_link())
+        }
+
+        // Пароль проверяется до погашения токена, чтобы ошибка ввода не сжигала ссылку
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let is_breached = self.breached_password_checker.is_breached(&data.password).await;
+        self.validator.validate_password(
+            &data.password,
+            &[&user.username, &user.email],
+            is_breached
+        ).unwrap_or_else(|e| {
+            validator_err_map.insert("password".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        if self.one_time_token.redeem(&data.token).await.is_none() {
+            return Err(invalid_link())
+        }
+
+        let hashed_password = self.password_hasher.hash(&data.password).await;
+
+        let new_user = self.user_service.update_user(
+            user.clone(),
+            user.username,
+            user.email,
+            user.first_name,
+            user.last_name,
+            hashed_password
+        );
+
+        let (new_user, effects) = self.user_service.change_state(new_user, UserState::Active).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error.into())
+            )
+        )?;
+
+        self.user_gateway.save_user(&new_user).await;
+        self.state_effects.apply(&new_user, &effects).await;
+
+        Ok(())
+    }
+}
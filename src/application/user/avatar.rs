@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+use crate::application::common::file_storage::FileStorage;
+use crate::domain::models::avatar::AvatarSize;
+use crate::domain::models::user::User;
+use crate::domain::services::avatar::AvatarService;
+
+#[derive(Debug, Serialize)]
+pub struct AvatarDTO {
+    small: String,
+    medium: String,
+    large: String,
+}
+
+impl AvatarDTO {
+    pub fn from_user(
+        user: &User,
+        avatar_service: &AvatarService,
+        file_storage: &dyn FileStorage,
+    ) -> Option<Self> {
+        let avatar_id = user.avatar_id.as_ref()?;
+        let url = |size: AvatarSize| {
+            file_storage.url(&avatar_service.object_key(&user.id, avatar_id, &size))
+        };
+        
+        Some(AvatarDTO {
+            small: url(AvatarSize::Small),
+            medium: url(AvatarSize::Medium),
+            large: url(AvatarSize::Large),
+        })
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::common::confirm_code::ConfirmCode;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::one_time_token::OneTimeToken;
+use crate::application::common::user_gateway::UserGateway;
+use crate::config::Extra;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeEmailSelfDTO {
+    pub email: String,
+    pub code: u32,
+}
+
+pub struct ChangeEmailSelf<'a> {
+    pub confirm_code: &'a dyn ConfirmCode,
+    pub one_time_token: &'a dyn OneTimeToken,
+    pub user_gateway: &'a dyn UserGateway,
+    pub email_sender: &'a dyn EmailSender,
+    pub extra: &'a Extra,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<ChangeEmailSelfDTO, ()> for ChangeEmailSelf<'_> {
+    async fn execute(&self, mut data: ChangeEmailSelfDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_change_email_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.email = self.normalizer.normalize_email(&data.email);
+
+        self.validator.validate_email(&data.email).unwrap_or_else(|e| {
+            validator_err_map.insert("email".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        self.confirm_code.confirm(
+            &format!("email_change:{}:{}", user.id, self.normalizer.canonical_email(&data.email)),
+            data.code
+        ).await.map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error)
+            )
+        )?;
+
+        // Пока код шел до пользователя, почту мог занять кто-то другой
+        if self.user_gateway.get_user_by_email_not_sensitive(&data.email).await.is_some() {
+            validator_err_map.insert("email".to_string(), Message::new("email_taken"));
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let new_user = self.user_service.update_user(
+            user.clone(),
+            user.username.clone(),
+            data.email.clone(),
+            user.first_name,
+            user.last_name,
+            user.hashed_password
+        );
+
+        self.user_gateway.save_user(&new_user).await;
+
+        let context: BTreeMap<String, Value> = {
+            let mut context = BTreeMap::new();
+            context.insert("username".to_string(), Value::String(user.username.clone()));
+            context.insert("ip".to_string(), Value::String(self.id_provider.ip().to_string()));
+            context.insert("change_time".to_string(), Value::String(
+                self.user_service.format_local_time(&new_user, chrono::Utc::now())
+            ));
+            context.insert("new_email".to_string(), Value::String(new_user.email.clone()));
+            context.insert("company".to_string(), Value::String(self.extra.company.clone()));
+            context.insert("company_url".to_string(), Value::String(self.extra.company_url.clone()));
+            context.insert("support_email".to_string(), Value::String(self.extra.support_email.clone()));
+            if let Some(revert_email_url) = &self.extra.revert_email_url {
+                let revert_token = self.one_time_token.issue(
+                    &format!("email_revert:{}:{}", user.id, user.email),
+                    259200 // 3 days
+                ).await;
+                context.insert("revert_email_url".to_string(), Value::String(
+                    format!("{}?token={}", revert_email_url, revert_token)
+                ));
+            }
+            context
+        };
+
+        self.email_sender.send_template(
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_email_changed"),
+            "email_changed.html",
+            Some(context),
+            13,
+            3600
+        ).await;
+
+        Ok(())
+    }
+}
@@ -3,14 +3,20 @@ use std::collections::{BTreeMap, HashMap};
 use serde::Deserialize;
 use serde_json::Value;
 
-use crate::application::common::email_sender::EmailSender;
+use crate::application::common::breached_password_checker::BreachedPasswordChecker;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::hasher::Hasher;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::common::password_history_gateway::PasswordHistoryGateway;
+use crate::application::common::session_gateway::SessionGateway;
 use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::password_reuse::PasswordReuseCheck;
 use crate::config::Extra;
+use crate::domain::exceptions::Message;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::session::SessionService;
 use crate::domain::services::user::UserService;
 use crate::domain::services::validator::ValidatorService;
 
@@ -23,9 +29,14 @@ pub struct ChangePasswordDTO {
 pub struct ChangePassword<'a> {
     pub email_sender: &'a dyn EmailSender,
     pub user_gateway: &'a dyn UserGateway,
+    pub password_history_gateway: &'a dyn PasswordHistoryGateway,
+    pub session_gateway: &'a dyn SessionGateway,
+    pub session_service: &'a SessionService,
     pub user_service: &'a UserService,
     pub validator: &'a ValidatorService,
     pub password_hasher: &'a dyn Hasher,
+    pub password_reuse: PasswordReuseCheck<'a>,
+    pub breached_password_checker: &'a dyn BreachedPasswordChecker,
     pub access_service: &'a AccessService,
     pub id_provider: Box<dyn IdProvider>,
     pub extra: &'a Extra,
@@ -34,7 +45,7 @@ pub struct ChangePassword<'a> {
 impl Interactor<ChangePasswordDTO, ()> for ChangePassword<'_> {
     async fn execute(&self, data: ChangePasswordDTO) -> Result<(), ApplicationError> {
 
-        match self.access_service.ensure_can_update_user_self(
+        match self.access_service.ensure_can_change_password_self(
             self.id_provider.is_auth(),
             self.id_provider.user_state(),
             self.id_provider.permissions()
@@ -42,19 +53,30 @@ impl Interactor<ChangePasswordDTO, ()> for ChangePassword<'_> {
             Ok(_) => (),
             Err(error) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(error.to_string())
+                    ErrorContent::Message(error.into())
                 )
             )
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_password(&data.new_password).unwrap_or_else(|e| {
-            validator_err_map.insert("new_password".to_string(), e.to_string());
+        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let is_breached = self.breached_password_checker.is_breached(&data.new_password).await;
+        self.validator.validate_password(
+            &data.new_password,
+            &[&user.username, &user.email],
+            is_breached
+        ).unwrap_or_else(|e| {
+            validator_err_map.insert("new_password".to_string(), e);
         });
         
         if data.old_password == data.new_password {
             validator_err_map.insert(
-                "new_password".to_string(), "Новый пароль совпадает с текущим".to_string()
+                "new_password".to_string(), Message::new("password_unchanged")
             );
         }
 
@@ -66,12 +88,6 @@ impl Interactor<ChangePasswordDTO, ()> for ChangePassword<'_> {
             )
         }
 
-        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
-            ApplicationError::NotFound(
-                ErrorContent::Message("Пользователь не найден".to_string())
-            )
-        )?;
-
         let is_valid = self.password_hasher.verify(
             &data.old_password, 
             &user.hashed_password
@@ -80,32 +96,46 @@ impl Interactor<ChangePasswordDTO, ()> for ChangePassword<'_> {
         if !is_valid {
             return Err(ApplicationError::InvalidData(
                 ErrorContent::Map(
-                    [("old_password".to_string(), "Неверный пароль".to_string())]
+                    [("old_password".to_string(), Message::new("password_incorrect"))]
                         .iter().cloned().collect()
                 )
             ))
         }
         
+        self.password_reuse.ensure_not_reused(&user, &data.new_password).await?;
+        
         let new_user = self.user_service.update_user(
             user.clone(),
             user.username.clone(),
             user.email.clone(),
-            user.state,
             user.first_name,
             user.last_name,
             self.password_hasher.hash(&data.new_password).await,
         );
         
         self.user_gateway.save_user(&new_user).await;
+        self.password_history_gateway.save_password_history(&user.id, &user.hashed_password).await;
+        
+        // После смены пароля сессия получает права ролей пользователя
+        if let Some(session) = self.session_gateway.get_session(
+            self.id_provider.session_id().unwrap()
+        ).await.filter(|session| session.restricted) {
+            let session = self.session_service.lift_restriction(session);
+            self.session_gateway.save_session(&session).await;
+            
+            if let Some((_, user_state, permissions, scopes)) = self.session_gateway
+                .get_session_by_token_hash_from_cache(&session.token_hash).await {
+                self.session_gateway.save_session_to_cache(&session, &user_state, &permissions, &scopes).await;
+            }
+        }
         
         let context: BTreeMap<String, Value> = {
             let mut context = BTreeMap::new();
             context.insert("username".to_string(), Value::String(user.username.clone()));
             context.insert("ip".to_string(), Value::String(self.id_provider.ip().to_string()));
-            context.insert("change_time".to_string(), Value::String({
-                let now = chrono::Utc::now();
-                now.format("%d/%m/%Y %H:%M %Z").to_string()
-            }));
+            context.insert("change_time".to_string(), Value::String(
+                self.user_service.format_local_time(&new_user, chrono::Utc::now())
+            ));
             context.insert("email".to_string(), Value::String(user.email.clone()));
             context.insert("company".to_string(), Value::String(self.extra.company.clone()));
             context.insert("company_url".to_string(), Value::String(self.extra.company_url.clone()));
@@ -114,8 +144,8 @@ impl Interactor<ChangePasswordDTO, ()> for ChangePassword<'_> {
         };
         
         self.email_sender.send_template(
-            &user.email,
-            "Изменение пароля",
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_password_changed"),
             "successfully_reset_password.html",
             Some(context),
             13,
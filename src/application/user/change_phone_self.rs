@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::confirm_code::ConfirmCode;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::sms_sender::SmsSender;
+use crate::application::common::user_gateway::UserGateway;
+use crate::config::Extra;
+use crate::domain::exceptions::Message;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePhoneSelfDTO {
+    /// `None` - удалить номер телефона, новый номер сохраняется
+    /// только после подтверждения кодом из SMS
+    pub phone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangePhoneSelfResultDTO {
+    phone: Option<String>,
+    phone_verified: bool,
+}
+
+pub struct ChangePhoneSelf<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub confirm_code: &'a dyn ConfirmCode,
+    pub sms_sender: &'a dyn SmsSender,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub extra: &'a Extra,
+}
+
+impl Interactor<ChangePhoneSelfDTO, ChangePhoneSelfResultDTO> for ChangePhoneSelf<'_> {
+    async fn execute(&self, data: ChangePhoneSelfDTO) -> Result<ChangePhoneSelfResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_update_user_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return Err(
+                ApplicationError::Forbidden(
+                    ErrorContent::Message(error.into())
+                )
+            )
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let phone = match &data.phone {
+            Some(phone) => match self.validator.normalize_phone(phone) {
+                Ok(phone) => Some(phone),
+                Err(e) => {
+                    validator_err_map.insert("phone".to_string(), e);
+                    None
+                }
+            },
+            None => None
+        };
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+        
+        if phone.is_some() && phone == user.phone && user.phone_verified {
+            return Err(ApplicationError::InvalidData(
+                ErrorContent::Message(Message::new("phone_already_verified"))
+            ))
+        }
+
+        let user = match &phone {
+            Some(phone) => {
+                if let Some(owner) = self.user_gateway.get_user_by_phone(phone).await {
+                    if owner.id != user.id {
+                        return Err(ApplicationError::Conflict(
+                            ErrorContent::Map(
+                                [("phone".to_string(), Message::new("phone_taken"))]
+                                    .into_iter().collect()
+                            )
+                        ))
+                    }
+                }
+                
+                let code = self.confirm_code.generate(
+                    &format!("phone_change:{}:{}", user.id, phone)
+                ).await.map_err(
+                    |error| ApplicationError::InvalidData(
+                        ErrorContent::Message(error)
+                    )
+                )?;
+                
+                self.sms_sender.send(
+                    phone,
+                    &Message::new("sms_phone_confirm_code")
+                        .arg("company", &self.extra.company)
+                        .arg("code", code),
+                    &user.locale,
+                    900 // 15 minutes
+                ).await;
+                
+                // Номер занимается только в ConfirmPhone, иначе его мог бы занять любой
+                user
+            },
+            None => {
+                let user = self.user_service.set_phone(user, None);
+                self.user_gateway.save_user(&user).await;
+                user
+            }
+        };
+
+        Ok(ChangePhoneSelfResultDTO {
+            phone: user.phone,
+            phone_verified: user.phone_verified,
+        })
+    }
+}
@@ -4,15 +4,18 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::application::common::confirm_code::ConfirmCode;
-use crate::application::common::email_sender::EmailSender;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::state_effects::UserStateEffects;
 use crate::config::Extra;
+use crate::domain::exceptions::Message;
 use crate::domain::models::user::UserState;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -27,13 +30,15 @@ pub struct ConfirmUser<'a> {
     pub email_sender: &'a dyn EmailSender,
     pub extra: &'a Extra,
     pub user_service: &'a UserService,
+    pub state_effects: UserStateEffects<'a>,
     pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
     pub access_service: &'a AccessService,
     pub id_provider: Box<dyn IdProvider>,
 }
 
 impl Interactor<ConfirmUserDTO, ()> for ConfirmUser<'_> {
-    async fn execute(&self, data: ConfirmUserDTO) -> Result<(), ApplicationError> {
+    async fn execute(&self, mut data: ConfirmUserDTO) -> Result<(), ApplicationError> {
 
         match self.access_service.ensure_can_confirm_user(
             &self.id_provider.is_auth(),
@@ -42,14 +47,16 @@ impl Interactor<ConfirmUserDTO, ()> for ConfirmUser<'_> {
             Ok(_) => (),
             Err(e) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(e.to_string())
+                    ErrorContent::Message(e.into())
                 )
             )
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.email = self.normalizer.normalize_email(&data.email);
+
         self.validator.validate_email(&data.email).unwrap_or_else(|e| {
-            validator_err_map.insert("email".to_string(), e.to_string());
+            validator_err_map.insert("email".to_string(), e);
         });
 
         if !validator_err_map.is_empty() {
@@ -62,41 +69,38 @@ impl Interactor<ConfirmUserDTO, ()> for ConfirmUser<'_> {
 
         let user = self.user_gateway.get_user_by_email_not_sensitive(&data.email).await.ok_or(
             ApplicationError::NotFound(
-                ErrorContent::Message("Пользователь не найден".to_string())
+                ErrorContent::Message(Message::new("user_not_found"))
             )
         )?;
 
         match user.state {
             UserState::Active => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Пользователь уже активирован".to_string())
+                    ErrorContent::Message(Message::new("user_already_active"))
                 )
             ),
             UserState::Inactive => (),
             _ => return Err(
                 ApplicationError::InvalidData(
-                    ErrorContent::Message("Невозможно активировать пользователя".to_string())
+                    ErrorContent::Message(Message::new("user_activation_forbidden"))
                 )
             )
         }
 
         self.confirm_code.confirm(&user.email, data.code).await.map_err(
             |error| ApplicationError::InvalidData(
-                ErrorContent::Message(error.to_string())
+                ErrorContent::Message(error)
             )
         )?;
         
-        let new_user = self.user_service.update_user(
-            user.clone(),
-            user.username.clone(),
-            user.email.clone(),
-            UserState::Active,
-            user.first_name,
-            user.last_name,
-            user.hashed_password
-        );
+        let (new_user, effects) = self.user_service.change_state(user.clone(), UserState::Active).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error.into())
+            )
+        )?;
         
         self.user_gateway.save_user(&new_user).await;
+        self.state_effects.apply(&new_user, &effects).await;
 
         let context: BTreeMap<String, Value> = {
             let mut context = BTreeMap::new();
@@ -107,8 +111,8 @@ impl Interactor<ConfirmUserDTO, ()> for ConfirmUser<'_> {
         };
 
         self.email_sender.send_template(
-            &user.email,
-            "Подтверждение почты",
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_email_confirmed"),
             "email_confirm_success.html",
             Some(context),
             13,
@@ -0,0 +1,91 @@
+use serde::Deserialize;
+
+use crate::application::common::confirm_code::ConfirmCode;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::Message;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct ConfirmPhoneDTO {
+    pub phone: String,
+    pub code: u32,
+}
+
+pub struct ConfirmPhone<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub confirm_code: &'a dyn ConfirmCode,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<ConfirmPhoneDTO, ()> for ConfirmPhone<'_> {
+    async fn execute(&self, data: ConfirmPhoneDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_update_user_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return Err(
+                ApplicationError::Forbidden(
+                    ErrorContent::Message(error.into())
+                )
+            )
+        };
+
+        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let phone = self.validator.normalize_phone(&data.phone).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Map(
+                    [("phone".to_string(), error)].into_iter().collect()
+                )
+            )
+        )?;
+
+        if user.phone.as_ref() == Some(&phone) && user.phone_verified {
+            return Err(ApplicationError::InvalidData(
+                ErrorContent::Message(Message::new("phone_already_verified"))
+            ))
+        }
+
+        self.confirm_code.confirm(
+            &format!("phone_change:{}:{}", user.id, phone),
+            data.code
+        ).await.map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error)
+            )
+        )?;
+
+        // Пока код шел до пользователя, номер мог подтвердить кто-то другой
+        if let Some(owner) = self.user_gateway.get_user_by_phone(&phone).await {
+            if owner.id != user.id {
+                return Err(ApplicationError::Conflict(
+                    ErrorContent::Map(
+                        [("phone".to_string(), Message::new("phone_taken"))]
+                            .into_iter().collect()
+                    )
+                ))
+            }
+        }
+
+        let new_user = self.user_service.set_phone(user, Some(phone));
+        let new_user = self.user_service.verify_phone(new_user);
+        self.user_gateway.save_user(&new_user).await;
+
+        Ok(())
+    }
+}
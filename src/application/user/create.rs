@@ -3,7 +3,8 @@ use std::collections::{BTreeMap, HashMap};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::application::common::email_sender::EmailSender;
+use crate::application::common::breached_password_checker::BreachedPasswordChecker;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::hasher::Hasher;
 use crate::application::common::id_provider::IdProvider;
@@ -11,9 +12,11 @@ use crate::application::common::interactor::Interactor;
 use crate::application::common::role_gateway::RoleGateway;
 use crate::application::common::user_gateway::UserGateway;
 use crate::config::Extra;
+use crate::domain::exceptions::Message;
 use crate::domain::models::user::{UserId, UserState};
 use crate::domain::services::access::AccessService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -23,6 +26,8 @@ pub struct CreateUserDTO {
     pub password: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -41,14 +46,16 @@ pub struct CreateUser<'a> {
     pub email_sender: &'a dyn EmailSender,
     pub user_service: &'a UserService,
     pub password_hasher: &'a dyn Hasher,
+    pub breached_password_checker: &'a dyn BreachedPasswordChecker,
     pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
     pub access_service: &'a AccessService,
     pub id_provider: Box<dyn IdProvider>,
     pub extra: &'a Extra,
 }
 
 impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
-    async fn execute(&self, data: CreateUserDTO) -> Result<CreateUserResultDTO, ApplicationError> {
+    async fn execute(&self, mut data: CreateUserDTO) -> Result<CreateUserResultDTO, ApplicationError> {
         
         match self.access_service.ensure_can_create_user(
             &self.id_provider.permissions()
@@ -56,33 +63,53 @@ impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
             Ok(_) => (),
             Err(e) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(e.to_string())
+                    ErrorContent::Message(e.into())
                 )
             )
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.username = self.normalizer.normalize_username(&data.username);
+        data.email = self.normalizer.normalize_email(&data.email);
+
         self.validator.validate_username(&data.username).unwrap_or_else(|e| {
-            validator_err_map.insert("username".to_string(), e.to_string());
+            validator_err_map.insert("username".to_string(), e);
         });
 
-        self.validator.validate_password(&data.password).unwrap_or_else(|e| {
-            validator_err_map.insert("password".to_string(), e.to_string());
+        let is_breached = self.breached_password_checker.is_breached(&data.password).await;
+        self.validator.validate_password(
+            &data.password,
+            &[&data.username, &data.email],
+            is_breached
+        ).unwrap_or_else(|e| {
+            validator_err_map.insert("password".to_string(), e);
         });
 
         self.validator.validate_email(&data.email).unwrap_or_else(|e| {
-            validator_err_map.insert("email".to_string(), e.to_string());
+            validator_err_map.insert("email".to_string(), e);
         });
 
         if let Some(first_name) = &data.first_name {
             self.validator.validate_first_name(first_name).unwrap_or_else(|e| {
-                validator_err_map.insert("first_name".to_string(), e.to_string());
+                validator_err_map.insert("first_name".to_string(), e);
             });
         }
 
         if let Some(last_name) = &data.last_name {
             self.validator.validate_last_name(last_name).unwrap_or_else(|e| {
-                validator_err_map.insert("last_name".to_string(), e.to_string());
+                validator_err_map.insert("last_name".to_string(), e);
+            });
+        }
+
+        if let Some(locale) = &data.locale {
+            self.validator.validate_locale(locale).unwrap_or_else(|e| {
+                validator_err_map.insert("locale".to_string(), e);
+            });
+        }
+
+        if let Some(timezone) = &data.timezone {
+            self.validator.validate_timezone(timezone).unwrap_or_else(|e| {
+                validator_err_map.insert("timezone".to_string(), e);
             });
         }
 
@@ -99,7 +126,7 @@ impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
             None => {
                 return Err(ApplicationError::Forbidden(
                     ErrorContent::Message(
-                        "Сервис на стадии инициализации. Роль по умолчанию не установлена!".to_string()
+                        Message::new("default_role_not_set")
                     )
                 ))
             }
@@ -118,12 +145,12 @@ impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
         // };
         
         if user_by_username.is_some() {
-            validator_err_map.insert("username".to_string(), "Имя пользователя занято".to_string());
+            validator_err_map.insert("username".to_string(), Message::new("username_taken"));
         }
 
 
         if user_by_email.is_some() {
-            validator_err_map.insert("email".to_string(), "Пользователь с таким Email уже существует".to_string());
+            validator_err_map.insert("email".to_string(), Message::new("email_taken"));
         }
 
 
@@ -146,6 +173,9 @@ impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
             data.first_name,
             data.last_name,
         );
+        let locale = data.locale.unwrap_or(user.locale.clone());
+        let timezone = data.timezone.unwrap_or(user.timezone.clone());
+        let user = self.user_service.set_preferences(user, locale, timezone);
 
         self.user_gateway.save_user(&user).await;
         self.role_gateway.link_role_to_user(&default_role_id, &user.id).await;
@@ -159,8 +189,8 @@ impl Interactor<CreateUserDTO, CreateUserResultDTO> for CreateUser<'_> {
         };
         
         self.email_sender.send_template(
-            &user.email,
-            "Регистрация на сайте",
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_registration"),
             "registration.html",
             Some(context),
             13,
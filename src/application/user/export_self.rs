@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::common::access_log_gateway::AccessLogReader;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::pagination::{Cursor, CursorValue};
+use crate::application::common::permission_gateway::PermissionReader;
+use crate::application::common::rate_limiter::RateLimiter;
+use crate::application::common::role_gateway::RoleReader;
+use crate::application::common::session_gateway::SessionReader;
+use crate::application::common::user_gateway::UserReader;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::access_log::AccessLogId;
+use crate::domain::models::permission::{PermissionId, PermissionTextId};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::service::ServiceId;
+use crate::domain::models::session::SessionId;
+use crate::domain::models::user::{UserId, UserState};
+use crate::domain::services::access::AccessService;
+
+#[derive(Debug, Serialize)]
+pub struct UserExportItem {
+    pub id: UserId,
+    username: String,
+    email: String,
+    state: UserState,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoleExportItem {
+    id: RoleId,
+    title: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PermissionExportItem {
+    id: PermissionId,
+    text_id: PermissionTextId,
+    service_id: ServiceId,
+    title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SessionExportItem {
+    id: SessionId,
+    ip: String,
+    client: String,
+    os: String,
+    device: String,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AccessLogExportItem {
+    id: AccessLogId,
+    is_success: bool,
+    ip: String,
+    client: String,
+    os: String,
+    device: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExportUserSelfResultDTO {
+    pub user: UserExportItem,
+    pub roles: Vec<RoleExportItem>,
+    pub permissions: Vec<PermissionExportItem>,
+    pub sessions: Vec<SessionExportItem>,
+    pub access_log: Vec<AccessLogExportItem>,
+    pub exported_at: DateTime<Utc>,
+}
+
+
+pub struct ExportUserSelf<'a> {
+    pub user_reader: &'a dyn UserReader,
+    pub role_reader: &'a dyn RoleReader,
+    pub permission_reader: &'a dyn PermissionReader,
+    pub session_reader: &'a dyn SessionReader,
+    pub access_log_reader: &'a dyn AccessLogReader,
+    pub rate_limiter: &'a dyn RateLimiter,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<(), ExportUserSelfResultDTO> for ExportUserSelf<'_> {
+    async fn execute(&self, _data: ()) -> Result<ExportUserSelfResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_export_user_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let user_id = self.id_provider.user_id().unwrap();
+
+        // Одна выгрузка в сутки
+        let rate_limit_key = format!("export:{}", user_id);
+        self.rate_limiter.acquire(
+            &rate_limit_key,
+            86400
+        ).await.map_err(|retry_after| ApplicationError::TooManyRequests(
+            ErrorContent::Message(
+                Message::new("export_rate_limited").arg("hours", retry_after.div_ceil(3600))
+            )
+        ))?;
+
+        let result = self.assemble(user_id).await;
+        if result.is_err() {
+            // Неудачная выгрузка не расходует суточный лимит
+            self.rate_limiter.release(&rate_limit_key).await;
+        }
+        result
+    }
+}
+
+impl ExportUserSelf<'_> {
+    async fn assemble(&self, user_id: &UserId) -> Result<ExportUserSelfResultDTO, ApplicationError> {
+        let user = self.user_reader.get_user_by_id(user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let roles = self.role_reader.get_user_roles(user_id).await;
+        let permissions = self.permission_reader.get_user_permissions(user_id).await;
+        let sessions = self.session_reader.get_user_sessions(user_id).await;
+
+        let mut access_log = Vec::new();
+        let per_page: u64 = 100;
+        let mut cursor: Option<Cursor> = None;
+        loop {
+            let records = self.access_log_reader.get_user_records(
+                user_id,
+                cursor.as_ref(),
+                &per_page
+            ).await;
+            let count = records.len() as u64;
+            cursor = records.last().map(|record| Cursor::new(CursorValue::Time(record.created_at), record.id));
+            access_log.extend(records);
+            if count < per_page {
+                break;
+            }
+        }
+
+        Ok(ExportUserSelfResultDTO {
+            user: UserExportItem {
+                id: user.id,
+                username: user.username,
+                email: user.email,
+                state: user.state,
+                first_name: user.first_name,
+                last_name: user.last_name,
+                created_at: user.created_at,
+                updated_at: user.updated_at,
+            },
+            roles: roles.into_iter().map(|role| RoleExportItem {
+                id: role.id,
+                title: role.title,
+                description: role.description,
+            }).collect(),
+            permissions: permissions.into_iter().map(|permission| PermissionExportItem {
+                id: permission.id,
+                text_id: permission.text_id,
+                service_id: permission.service_id,
+                title: permission.title,
+            }).collect(),
+            sessions: sessions.into_iter().map(|session| SessionExportItem {
+                id: session.id,
+                ip: session.ip,
+                client: session.client,
+                os: session.os,
+                device: session.device,
+                created_at: session.created_at,
+                updated_at: session.updated_at,
+            }).collect(),
+            access_log: access_log.into_iter().map(|record| AccessLogExportItem {
+                id: record.id,
+                is_success: record.is_success,
+                ip: record.ip,
+                client: record.client,
+                os: record.os,
+                device: record.device,
+                created_at: record.created_at,
+            }).collect(),
+            exported_at: Utc::now(),
+        })
+    }
+}
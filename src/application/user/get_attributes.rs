@@ -0,0 +1,83 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::service_gateway::ServiceGateway;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+
+#[derive(Debug, Deserialize)]
+pub struct GetUserAttributesDTO {
+    pub user_id: Option<UserId>,
+    pub namespace: ServiceTextId,
+}
+
+pub struct GetUserAttributes<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub service_gateway: &'a dyn ServiceGateway,
+    pub user_service: &'a UserService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+}
+
+impl Interactor<GetUserAttributesDTO, Value> for GetUserAttributes<'_> {
+    async fn execute(&self, data: GetUserAttributesDTO) -> Result<Value, ApplicationError> {
+        
+        let user_id = match data.user_id.or(self.id_provider.user_id().cloned()) {
+            Some(user_id) => user_id,
+            None => return Err(
+                ApplicationError::Unauthorized(
+                    ErrorContent::Message(DomainError::AuthorizationRequired.into())
+                )
+            )
+        };
+        
+        match self.access_service.ensure_can_get_user_attributes(
+            self.id_provider.is_auth(),
+            self.id_provider.user_id(),
+            &user_id,
+            self.id_provider.user_state(),
+            self.id_provider.service_permissions(&data.namespace)
+        ) {
+            Ok(_) => (),
+            Err(error) => match error {
+                DomainError::AccessDenied => return Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => return Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+        
+        match self.service_gateway.get_services_by_text_id(&data.namespace).await {
+            Some(service) if service.attribute_schema.is_some() => (),
+            _ => return Err(
+                ApplicationError::NotFound(
+                    ErrorContent::Message(Message::new("attribute_namespace_not_found"))
+                )
+            )
+        };
+        
+        let user = match self.user_gateway.get_user_by_id(&user_id).await {
+            Some(user) => user,
+            None => return Err(
+                ApplicationError::NotFound(
+                    ErrorContent::Message(Message::new("user_not_found"))
+                )
+            ),
+        };
+
+        Ok(self.user_service.get_attributes(&user, &data.namespace))
+    }
+}
@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::file_storage::FileStorage;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
+use crate::application::user::avatar::AvatarDTO;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::avatar::AvatarService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetUserByIdDTO {
@@ -19,6 +22,7 @@ pub struct UserByIdResultDTO{
     username: String,
     first_name: Option<String>,
     last_name: Option<String>,
+    avatar: Option<AvatarDTO>,
 }
 
 
@@ -26,6 +30,8 @@ pub struct GetUserById<'a> {
     pub user_reader: &'a dyn UserReader,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
+    pub avatar_service: &'a AvatarService,
+    pub file_storage: &'a dyn FileStorage,
     
 }
 
@@ -43,12 +49,12 @@ impl Interactor<GetUserByIdDTO, UserByIdResultDTO> for GetUserById<'_> {
             Err(error) => match error {
                 DomainError::AccessDenied => return Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => return Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
@@ -58,12 +64,13 @@ impl Interactor<GetUserByIdDTO, UserByIdResultDTO> for GetUserById<'_> {
             Some(u) => u,
             None => return Err(
                 ApplicationError::NotFound(
-                    ErrorContent::Message("Пользователь не найден".to_string())
+                    ErrorContent::Message(Message::new("user_not_found"))
                 )
             ),
         };
 
         Ok(UserByIdResultDTO {
+            avatar: AvatarDTO::from_user(&user, self.avatar_service, self.file_storage),
             id: user.id,
             username: user.username,
             first_name: user.first_name,
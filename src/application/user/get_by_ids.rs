@@ -1,11 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::file_storage::FileStorage;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserReader;
+use crate::application::user::avatar::AvatarDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::user::UserId;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::avatar::AvatarService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetUsersByIdsDTO {
@@ -19,6 +23,7 @@ pub struct UserItemResult{
     username: String,
     first_name: Option<String>,
     last_name: Option<String>,
+    avatar: Option<AvatarDTO>,
 }
 
 
@@ -29,6 +34,8 @@ pub struct GetUsersByIds<'a> {
     pub user_reader: &'a dyn UserReader,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
+    pub avatar_service: &'a AvatarService,
+    pub file_storage: &'a dyn FileStorage,
 }
 
 impl Interactor<GetUsersByIdsDTO, UsersByIdsResultDTO> for GetUsersByIds<'_> {
@@ -44,7 +51,7 @@ impl Interactor<GetUsersByIdsDTO, UsersByIdsResultDTO> for GetUsersByIds<'_> {
             Ok(_) => (),
             Err(e) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(e.to_string())
+                    ErrorContent::Message(e.into())
                 )
             )
         };
@@ -52,12 +59,13 @@ impl Interactor<GetUsersByIdsDTO, UsersByIdsResultDTO> for GetUsersByIds<'_> {
         let users = match self.user_reader.get_users_by_ids(&data.ids).await {
             Some(u) => u,
             None => return Err(ApplicationError::NotFound(
-                ErrorContent::Message("Запрашиваемые пользователи не найдены".to_string())
+                ErrorContent::Message(Message::new("users_not_found"))
             )),
 
         };
         Ok(
             users.into_iter().map(|u| UserItemResult {
+                avatar: AvatarDTO::from_user(&u, self.avatar_service, self.file_storage),
                 id: u.id,
                 username: u.username,
                 first_name: u.first_name,
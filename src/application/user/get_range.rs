@@ -1,20 +1,39 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::file_storage::FileStorage;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
-use crate::application::common::user_gateway::UserReader;
-use crate::domain::exceptions::DomainError;
-use crate::domain::models::user::UserId;
+use crate::application::common::pagination::{Cursor, CursorValue, PageDTO};
+use crate::application::common::user_gateway::{SortOrder, UserFilter, UserReader, UserSortField};
+use crate::application::user::avatar::AvatarDTO;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::{UserId, UserState};
 use crate::domain::services::access::AccessService;
+use crate::domain::services::avatar::AvatarService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
 pub struct GetUserRangeDTO {
-    pub page: u64,
+    pub cursor: Option<String>,
     pub per_page: u64,
+    pub with_total: bool,
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub state: Option<UserState>,
+    pub role_id: Option<RoleId>,
+    pub created_from: Option<DateTime<Utc>>,
+    pub created_to: Option<DateTime<Utc>>,
+    pub last_login_from: Option<DateTime<Utc>>,
+    pub last_login_to: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub sort: UserSortField,
+    #[serde(default)]
+    pub order: SortOrder,
 }
 
 #[derive(Debug, Serialize)]
@@ -23,15 +42,19 @@ pub struct UserItemResult{
     username: String,
     first_name: Option<String>,
     last_name: Option<String>,
+    last_login: Option<DateTime<Utc>>,
+    avatar: Option<AvatarDTO>,
 }
 
-pub type GetUserRangeResultDTO = Vec<UserItemResult>;
+pub type GetUserRangeResultDTO = PageDTO<UserItemResult>;
 
 
 pub struct GetUserRange<'a> {
     pub user_reader: &'a dyn UserReader,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
+    pub avatar_service: &'a AvatarService,
+    pub file_storage: &'a dyn FileStorage,
     pub validator: &'a ValidatorService,
 }
 
@@ -45,25 +68,60 @@ impl Interactor<GetUserRangeDTO, GetUserRangeResultDTO> for GetUserRange<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_page(&data.page).unwrap_or_else(|e| {
-            validator_err_map.insert("page".to_string(), e.to_string());
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        let cursor = data.cursor.as_deref().map(Cursor::decode).transpose().unwrap_or_else(|e| {
+            validator_err_map.insert("cursor".to_string(), e);
+            None
         });
         
         self.validator.validate_per_page(&data.per_page).unwrap_or_else(|e| {
-            validator_err_map.insert("per_page".to_string(), e.to_string());
+            validator_err_map.insert("per_page".to_string(), e);
         });
+
+        // Курсор должен соответствовать выбранной сортировке
+        if let Some(cursor) = &cursor {
+            let is_valid = matches!(
+                (&data.sort, &cursor.value),
+                (UserSortField::Username | UserSortField::Email, CursorValue::Text(_)) |
+                (UserSortField::CreatedAt, CursorValue::Time(_)) |
+                (UserSortField::LastLogin, CursorValue::Time(_) | CursorValue::Null)
+            );
+            if !is_valid {
+                validator_err_map.insert(
+                    "cursor".to_string(),
+                    Message::new("cursor_sort_mismatch")
+                );
+            }
+        }
+
+        if let (Some(from), Some(to)) = (&data.created_from, &data.created_to) {
+            if from > to {
+                validator_err_map.insert(
+                    "created_from".to_string(),
+                    Message::new("period_invalid")
+                );
+            }
+        }
+
+        if let (Some(from), Some(to)) = (&data.last_login_from, &data.last_login_to) {
+            if from > to {
+                validator_err_map.insert(
+                    "last_login_from".to_string(),
+                    Message::new("period_invalid")
+                );
+            }
+        }
         
         if !validator_err_map.is_empty() {
             return Err(
@@ -73,18 +131,53 @@ impl Interactor<GetUserRangeDTO, GetUserRangeResultDTO> for GetUserRange<'_> {
             )
         }
         
-        let users = self.user_reader.get_users_list(
-            &data.per_page,
-            &(data.page * data.per_page)
+        let filter = UserFilter {
+            username: data.username,
+            email: data.email,
+            state: data.state,
+            role_id: data.role_id,
+            created_from: data.created_from,
+            created_to: data.created_to,
+            last_login_from: data.last_login_from,
+            last_login_to: data.last_login_to,
+        };
+
+        let users = self.user_reader.get_users_by_filter(
+            &filter,
+            &data.sort,
+            &data.order,
+            cursor.as_ref(),
+            &(data.per_page + 1)
         ).await;
+        let total = match data.with_total {
+            true => Some(self.user_reader.count_users_by_filter(&filter).await),
+            false => None
+        };
         
-        Ok(
-            users.into_iter().map(|u| UserItemResult {
+        Ok(PageDTO::from_rows(
+            users,
+            data.per_page,
+            total,
+            |(u, last_login)| Cursor::new(
+                match data.sort {
+                    UserSortField::Username => CursorValue::Text(u.username.clone()),
+                    UserSortField::Email => CursorValue::Text(u.email.clone()),
+                    UserSortField::CreatedAt => CursorValue::Time(u.created_at),
+                    UserSortField::LastLogin => match last_login {
+                        Some(last_login) => CursorValue::Time(*last_login),
+                        None => CursorValue::Null
+                    }
+                },
+                u.id
+            ),
+            |(u, last_login)| UserItemResult {
+                avatar: AvatarDTO::from_user(&u, self.avatar_service, self.file_storage),
                 id: u.id,
                 username: u.username,
                 first_name: u.first_name,
                 last_name: u.last_name,
-            }).collect()
-        )
+                last_login,
+            }
+        ))
     }
 }
\ No newline at end of file
@@ -1,11 +1,14 @@
 use serde::Serialize;
 
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::file_storage::FileStorage;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserReader;
+use crate::application::user::avatar::AvatarDTO;
 use crate::domain::models::user::{UserId, UserState};
 use crate::domain::services::access::AccessService;
+use crate::domain::services::avatar::AvatarService;
 
 #[derive(Debug, Serialize)]
 pub struct UserSelfResultDTO{
@@ -15,6 +18,11 @@ pub struct UserSelfResultDTO{
     state: UserState,
     first_name: Option<String>,
     last_name: Option<String>,
+    avatar: Option<AvatarDTO>,
+    phone: Option<String>,
+    phone_verified: bool,
+    locale: String,
+    timezone: String,
 }
 
 
@@ -22,6 +30,8 @@ pub struct GetUserSelf<'a> {
     pub user_reader: &'a dyn UserReader,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
+    pub avatar_service: &'a AvatarService,
+    pub file_storage: &'a dyn FileStorage,
     
 }
 
@@ -36,7 +46,7 @@ impl Interactor<(), UserSelfResultDTO> for GetUserSelf<'_> {
             Ok(_) => (),
             Err(error) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(error.to_string())
+                    ErrorContent::Message(error.into())
                 )
             )
         };
@@ -45,12 +55,17 @@ impl Interactor<(), UserSelfResultDTO> for GetUserSelf<'_> {
         let user = self.user_reader.get_user_by_id(self.id_provider.user_id().unwrap()).await.unwrap();
 
         Ok(UserSelfResultDTO {
+            avatar: AvatarDTO::from_user(&user, self.avatar_service, self.file_storage),
             id: user.id,
             username: user.username,
             email: user.email,
             state: user.state,
             first_name: user.first_name,
             last_name: user.last_name,
+            phone: user.phone,
+            phone_verified: user.phone_verified,
+            locale: user.locale,
+            timezone: user.timezone,
         })
     }
 }
\ No newline at end of file
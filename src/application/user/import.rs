@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::role_gateway::RoleGateway;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::{User, UserId, UserState, UNUSABLE_PASSWORD_HASH};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct ImportUserDTO {
+    pub username: String,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub password_hash: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+pub struct ImportUsersDTO {
+    pub users: Vec<ImportUserDTO>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportRowErrorDTO {
+    pub row: usize,
+    pub errors: HashMap<String, Message>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportUsersResultDTO {
+    dry_run: bool,
+    total: usize,
+    valid: usize,
+    imported: usize,
+    pub errors: Vec<ImportRowErrorDTO>,
+}
+
+impl ImportUsersResultDTO {
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
+struct CheckedRow {
+    row: usize,
+    user: ImportUserDTO,
+    role_ids: Vec<RoleId>,
+    errors: HashMap<String, Message>,
+}
+
+/// Количество строк, проверяемых и сохраняемых за один запрос к базе
+const BATCH_SIZE: usize = 500;
+
+pub struct ImportUsers<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub role_gateway: &'a dyn RoleGateway,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<ImportUsersDTO, ImportUsersResultDTO> for ImportUsers<'_> {
+    async fn execute(&self, data: ImportUsersDTO) -> Result<ImportUsersResultDTO, ApplicationError> {
+        
+        match self.access_service.ensure_can_import_users(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => match error {
+                DomainError::AccessDenied => return Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => return Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+        
+        if data.users.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Message(Message::new("import_empty"))
+                )
+            )
+        }
+        
+        // Роли из файла может выдать только тот, кто вправе назначать роли
+        if data.users.iter().any(|user| !user.roles.is_empty()) {
+            match self.access_service.ensure_can_link_role_user(
+                self.id_provider.is_auth(),
+                self.id_provider.user_state(),
+                self.id_provider.permissions()
+            ) {
+                Ok(_) => (),
+                Err(error) => match error {
+                    DomainError::AccessDenied => return Err(
+                        ApplicationError::Forbidden(
+                            ErrorContent::Message(error.into())
+                        )
+                    ),
+                    DomainError::AuthorizationRequired => return Err(
+                        ApplicationError::Unauthorized(
+                            ErrorContent::Message(error.into())
+                        )
+                    )
+                }
+            };
+        }
+        
+        let default_role_id = self.role_gateway.get_default_role().await.map(|role| role.id);
+        let mut role_ids: HashMap<String, Option<RoleId>> = HashMap::new();
+        let mut seen_usernames: HashMap<String, usize> = HashMap::new();
+        let mut seen_emails: HashMap<String, usize> = HashMap::new();
+        
+        let total = data.users.len();
+        let mut errors: Vec<ImportRowErrorDTO> = vec![];
+        let mut valid: Vec<(usize, ImportUserDTO, Vec<RoleId>)> = vec![];
+        
+        let mut rows = data.users.into_iter().enumerate().map(|(index, user)| (index + 1, user));
+        loop {
+            let batch = rows.by_ref().take(BATCH_SIZE).collect::<Vec<(usize, ImportUserDTO)>>();
+            if batch.is_empty() {
+                break
+            }
+            
+            let mut checked: Vec<CheckedRow> = vec![];
+            for (row, mut user) in batch {
+                let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+                
+                user.username = self.normalizer.normalize_username(&user.username);
+                user.email = self.normalizer.normalize_email(&user.email);
+                
+                self.validator.validate_username(&user.username).unwrap_or_else(|e| {
+                    validator_err_map.insert("username".to_string(), e);
+                });
+                
+                self.validator.validate_email(&user.email).unwrap_or_else(|e| {
+                    validator_err_map.insert("email".to_string(), e);
+                });
+                
+                if let Some(first_name) = &user.first_name {
+                    self.validator.validate_first_name(first_name).unwrap_or_else(|e| {
+                        validator_err_map.insert("first_name".to_string(), e);
+                    });
+                }
+                
+                if let Some(last_name) = &user.last_name {
+                    self.validator.validate_last_name(last_name).unwrap_or_else(|e| {
+                        validator_err_map.insert("last_name".to_string(), e);
+                    });
+                }
+                
+                if let Some(password_hash) = &user.password_hash {
+                    self.validator.validate_password_hash(password_hash).unwrap_or_else(|e| {
+                        validator_err_map.insert("password_hash".to_string(), e);
+                    });
+                }
+                
+                let username_canonical = self.normalizer.canonical_username(&user.username);
+                if let Some(other_row) = seen_usernames.get(&username_canonical) {
+                    validator_err_map.insert(
+                        "username".to_string(),
+                        Message::new("import_username_duplicate").arg("row", other_row)
+                    );
+                } else {
+                    seen_usernames.insert(username_canonical, row);
+                }
+                
+                let email_canonical = self.normalizer.canonical_email(&user.email);
+                if let Some(other_row) = seen_emails.get(&email_canonical) {
+                    validator_err_map.insert(
+                        "email".to_string(),
+                        Message::new("import_email_duplicate").arg("row", other_row)
+                    );
+                } else {
+                    seen_emails.insert(email_canonical, row);
+                }
+                
+                let mut user_role_ids: Vec<RoleId> = vec![];
+                for title in user.roles.iter() {
+                    let key = title.to_lowercase();
+                    if !role_ids.contains_key(&key) {
+                        let role_id = self.role_gateway.get_role_by_title_not_sensitive(title).await.map(
+                            |role| role.id
+                        );
+                        role_ids.insert(key.clone(), role_id);
+                    }
+                    match role_ids.get(&key).unwrap() {
+                        Some(role_id) => if !user_role_ids.contains(role_id) {
+                            user_role_ids.push(*role_id)
+                        },
+                        None => {
+                            validator_err_map.insert(
+                                "roles".to_string(),
+                                Message::new("import_role_not_found").arg("title", title)
+                            );
+                        }
+                    }
+                }
+                if user.roles.is_empty() {
+                    match default_role_id {
+                        Some(role_id) => user_role_ids.push(role_id),
+                        None => {
+                            validator_err_map.insert(
+                                "roles".to_string(),
+                                Message::new("default_role_not_set")
+                            );
+                        }
+                    }
+                }
+                
+                checked.push(CheckedRow {
+                    row,
+                    user,
+                    role_ids: user_role_ids,
+                    errors: validator_err_map
+                });
+            }
+            
+            let existing = self.user_gateway.get_users_by_usernames_or_emails_not_sensitive(
+                &checked.iter().map(|checked| checked.user.username.clone()).collect::<Vec<_>>(),
+                &checked.iter().map(|checked| checked.user.email.clone()).collect::<Vec<_>>()
+            ).await;
+            
+            for CheckedRow { row, user, role_ids: user_role_ids, errors: mut validator_err_map } in checked {
+                let username_canonical = self.normalizer.canonical_username(&user.username);
+                let email_canonical = self.normalizer.canonical_email(&user.email);
+                if existing.iter().any(|other| self.normalizer.canonical_username(&other.username) == username_canonical) {
+                    validator_err_map.insert("username".to_string(), Message::new("username_taken"));
+                }
+                if existing.iter().any(|other| self.normalizer.canonical_email(&other.email) == email_canonical) {
+                    validator_err_map.insert("email".to_string(), Message::new("email_taken"));
+                }
+                
+                if validator_err_map.is_empty() {
+                    valid.push((row, user, user_role_ids));
+                } else {
+                    errors.push(ImportRowErrorDTO { row, errors: validator_err_map });
+                }
+            }
+        }
+        
+        let valid_count = valid.len();
+        if data.dry_run {
+            return Ok(ImportUsersResultDTO {
+                dry_run: true,
+                total,
+                valid: valid_count,
+                imported: 0,
+                errors,
+            })
+        }
+        
+        let mut imported = 0;
+        let mut valid = valid.into_iter();
+        loop {
+            let batch = valid.by_ref().take(BATCH_SIZE).collect::<Vec<(usize, ImportUserDTO, Vec<RoleId>)>>();
+            if batch.is_empty() {
+                break
+            }
+            
+            let mut rows: Vec<usize> = vec![];
+            let mut users: Vec<User> = vec![];
+            let mut links: Vec<(RoleId, UserId)> = vec![];
+            for (row, user, user_role_ids) in batch {
+                let user = self.user_service.create_user(
+                    user.username,
+                    user.email,
+                    UserState::Active,
+                    // Без пароля вход невозможен до его восстановления
+                    user.password_hash.unwrap_or_else(|| UNUSABLE_PASSWORD_HASH.to_string()),
+                    user.first_name,
+                    user.last_name,
+                );
+                links.extend(user_role_ids.into_iter().map(|role_id| (role_id, user.id)));
+                rows.push(row);
+                users.push(user);
+            }
+            
+            let skipped = self.user_gateway.import_users(&users, &links).await;
+            imported += users.len() - skipped.len();
+            if skipped.is_empty() {
+                continue
+            }
+            
+            // Пока шел импорт, имя или почту мог занять кто-то другой
+            let skipped = rows.into_iter().zip(users)
+                .filter(|(_, user)| skipped.contains(&user.id))
+                .collect::<Vec<(usize, User)>>();
+            let existing = self.user_gateway.get_users_by_usernames_or_emails_not_sensitive(
+                &skipped.iter().map(|(_, user)| user.username.clone()).collect::<Vec<_>>(),
+                &skipped.iter().map(|(_, user)| user.email.clone()).collect::<Vec<_>>()
+            ).await;
+            
+            for (row, user) in skipped {
+                let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+                let username_canonical = self.normalizer.canonical_username(&user.username);
+                let email_canonical = self.normalizer.canonical_email(&user.email);
+                if existing.iter().any(|other| self.normalizer.canonical_username(&other.username) == username_canonical) {
+                    validator_err_map.insert("username".to_string(), Message::new("username_taken"));
+                }
+                if existing.iter().any(|other| self.normalizer.canonical_email(&other.email) == email_canonical) {
+                    validator_err_map.insert("email".to_string(), Message::new("email_taken"));
+                }
+                if validator_err_map.is_empty() {
+                    validator_err_map.insert("user".to_string(), Message::new("import_user_conflict"));
+                }
+                errors.push(ImportRowErrorDTO { row, errors: validator_err_map });
+            }
+        }
+        errors.sort_by_key(|error| error.row);
+        
+        Ok(ImportUsersResultDTO {
+            dry_run: false,
+            total,
+            valid: valid_count,
+            imported,
+            errors,
+        })
+    }
+}
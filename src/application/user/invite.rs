@@ -0,0 +1,233 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::one_time_token::OneTimeToken;
+use crate::application::common::role_gateway::RoleGateway;
+use crate::application::common::user_gateway::UserGateway;
+use crate::config::Extra;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::role::RoleId;
+use crate::domain::models::user::{UserId, UserState, UNUSABLE_PASSWORD_HASH};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
+use crate::domain::services::validator::ValidatorService;
+
+/// Срок действия приглашения в днях
+const INVITATION_TTL_DAYS: u32 = 7;
+
+#[derive(Debug, Deserialize)]
+pub struct InviteUserDTO {
+    pub username: String,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub role_ids: Vec<RoleId>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InviteUserResultDTO {
+    id: UserId,
+    username: String,
+    email: String,
+    state: UserState,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    role_ids: Vec<RoleId>,
+}
+
+pub struct InviteUser<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub role_gateway: &'a dyn RoleGateway,
+    pub email_sender: &'a dyn EmailSender,
+    pub one_time_token: &'a dyn OneTimeToken,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub extra: &'a Extra,
+}
+
+impl Interactor<InviteUserDTO, InviteUserResultDTO> for InviteUser<'_> {
+    async fn execute(&self, mut data: InviteUserDTO) -> Result<InviteUserResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_invite_user(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => match error {
+                DomainError::AccessDenied => return Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => return Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let invitation_url = match &self.extra.invitation_url {
+            Some(invitation_url) => invitation_url,
+            None => return Err(ApplicationError::ServiceUnavailable(
+                ErrorContent::Message(
+                    Message::new("invitation_url_not_set")
+                )
+            ))
+        };
+
+        // Произвольные роли может выдать только тот, кто вправе назначать роли
+        if !data.role_ids.is_empty() {
+            match self.access_service.ensure_can_link_role_user(
+                self.id_provider.is_auth(),
+                self.id_provider.user_state(),
+                self.id_provider.permissions()
+            ) {
+                Ok(_) => (),
+                Err(error) => match error {
+                    DomainError::AccessDenied => return Err(
+                        ApplicationError::Forbidden(
+                            ErrorContent::Message(error.into())
+                        )
+                    ),
+                    DomainError::AuthorizationRequired => return Err(
+                        ApplicationError::Unauthorized(
+                            ErrorContent::Message(error.into())
+                        )
+                    )
+                }
+            };
+        }
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.username = self.normalizer.normalize_username(&data.username);
+        data.email = self.normalizer.normalize_email(&data.email);
+
+        self.validator.validate_username(&data.username).unwrap_or_else(|e| {
+            validator_err_map.insert("username".to_string(), e);
+        });
+
+        self.validator.validate_email(&data.email).unwrap_or_else(|e| {
+            validator_err_map.insert("email".to_string(), e);
+        });
+
+        if let Some(first_name) = &data.first_name {
+            self.validator.validate_first_name(first_name).unwrap_or_else(|e| {
+                validator_err_map.insert("first_name".to_string(), e);
+            });
+        }
+
+        if let Some(last_name) = &data.last_name {
+            self.validator.validate_last_name(last_name).unwrap_or_else(|e| {
+                validator_err_map.insert("last_name".to_string(), e);
+            });
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let role_ids = if data.role_ids.is_empty() {
+            match self.role_gateway.get_default_role().await {
+                Some(role) => vec![role.id],
+                None => return Err(ApplicationError::ServiceUnavailable(
+                    ErrorContent::Message(
+                        Message::new("default_role_not_set")
+                    )
+                ))
+            }
+        } else {
+            let mut role_ids = data.role_ids.clone();
+            role_ids.sort();
+            role_ids.dedup();
+            
+            if self.role_gateway.get_roles_by_ids(&role_ids).await.is_none() {
+                validator_err_map.insert("role_ids".to_string(), Message::new("roles_not_found"));
+            }
+            role_ids
+        };
+
+        if self.user_gateway.get_user_by_username_not_sensitive(&data.username).await.is_some() {
+            validator_err_map.insert("username".to_string(), Message::new("username_taken"));
+        }
+
+        if self.user_gateway.get_user_by_email_not_sensitive(&data.email).await.is_some() {
+            validator_err_map.insert("email".to_string(), Message::new("email_taken"));
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+        
+        let user = self.user_service.create_user(
+            data.username,
+            data.email,
+            UserState::Inactive,
+            // Пароль задает сам пользователь при принятии приглашения
+            UNUSABLE_PASSWORD_HASH.to_string(),
+            data.first_name,
+            data.last_name,
+        );
+
+        self.user_gateway.save_user(&user).await;
+        for role_id in role_ids.iter() {
+            self.role_gateway.link_role_to_user(role_id, &user.id).await;
+        }
+        
+        let token = self.one_time_token.issue(
+            &format!("invitation:{}", user.id),
+            INVITATION_TTL_DAYS * 86400
+        ).await;
+
+        let context: BTreeMap<String, Value> = {
+            let mut context = BTreeMap::new();
+            context.insert("username".to_string(), Value::String(user.username.clone()));
+            context.insert("company".to_string(), Value::String(self.extra.company.clone()));
+            context.insert("company_url".to_string(), Value::String(self.extra.company_url.clone()));
+            context.insert("invitation_days".to_string(), Value::from(INVITATION_TTL_DAYS));
+            context.insert("invitation_url".to_string(), Value::String(
+                format!("{}?token={}", invitation_url, token)
+            ));
+            context
+        };
+
+        self.email_sender.send_template(
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_invitation"),
+            "invitation.html",
+            Some(context),
+            13,
+            3600
+        ).await;
+
+        Ok(InviteUserResultDTO {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+            state: user.state,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            role_ids,
+        })
+    }
+}
@@ -8,4 +8,20 @@ pub mod update_self;
 pub mod send_confirm_code;
 pub mod confirm;
 pub mod change_password;
-pub mod reset_password;
\ No newline at end of file
+pub mod reset_password;
+pub mod export_self;
+pub mod send_email_change_code;
+pub mod change_email_self;
+pub mod revert_email_change;
+pub mod get_attributes;
+pub mod update_attributes;
+pub mod avatar;
+pub mod upload_avatar;
+pub mod import;
+pub mod invite;
+pub mod accept_invitation;
+pub mod change_phone_self;
+pub mod confirm_phone;
+pub mod update_preferences_self;
+pub mod state_effects;
+pub mod password_reuse;
\ No newline at end of file
@@ -0,0 +1,43 @@
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::hasher::Hasher;
+use crate::application::common::password_history_gateway::PasswordHistoryReader;
+use crate::domain::exceptions::Message;
+use crate::domain::models::user::User;
+
+/// **PasswordReuseCheck** - запрет повторного использования паролей
+///
+/// Новый пароль не должен совпадать с последними `history_size` паролями: текущим
+/// и предыдущими из истории, в которой поэтому хранится на один пароль меньше.
+pub struct PasswordReuseCheck<'a> {
+    pub password_history_reader: &'a dyn PasswordHistoryReader,
+    pub password_hasher: &'a dyn Hasher,
+    pub history_size: usize,
+}
+
+impl PasswordReuseCheck<'_> {
+    pub async fn ensure_not_reused(
+        &self,
+        user: &User,
+        new_password: &str,
+    ) -> Result<(), ApplicationError> {
+        if self.history_size == 0 {
+            return Ok(())
+        }
+        
+        let mut previous_hashes = vec![user.hashed_password.clone()];
+        previous_hashes.extend(self.password_history_reader.get_password_history(&user.id).await);
+        
+        for hashed_password in previous_hashes.iter() {
+            if self.password_hasher.verify(new_password, hashed_password).await {
+                return Err(ApplicationError::InvalidData(
+                    ErrorContent::Map(
+                        [("new_password".to_string(), Message::new("password_reused"))]
+                            .iter().cloned().collect()
+                    )
+                ))
+            }
+        }
+        
+        Ok(())
+    }
+}
@@ -4,16 +4,21 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::application::common::confirm_code::ConfirmCode;
-use crate::application::common::email_sender::EmailSender;
+use crate::application::common::breached_password_checker::BreachedPasswordChecker;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::hasher::Hasher;
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::session_gateway::SessionRemover;
+use crate::application::common::password_history_gateway::PasswordHistoryGateway;
 use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::password_reuse::PasswordReuseCheck;
 use crate::config::Extra;
+use crate::domain::exceptions::Message;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -27,9 +32,13 @@ pub struct ResetPassword<'a> {
     pub email_sender: &'a dyn EmailSender,
     pub confirm_code: &'a dyn ConfirmCode,
     pub user_gateway: &'a dyn UserGateway,
+    pub password_history_gateway: &'a dyn PasswordHistoryGateway,
     pub user_service: &'a UserService,
     pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
     pub password_hasher: &'a dyn Hasher,
+    pub password_reuse: PasswordReuseCheck<'a>,
+    pub breached_password_checker: &'a dyn BreachedPasswordChecker,
     pub access_service: &'a AccessService,
     pub session_remover: &'a dyn SessionRemover,
     pub id_provider: Box<dyn IdProvider>,
@@ -37,7 +46,7 @@ pub struct ResetPassword<'a> {
 }
 
 impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
-    async fn execute(&self, data: ResetPasswordDTO) -> Result<(), ApplicationError> {
+    async fn execute(&self, mut data: ResetPasswordDTO) -> Result<(), ApplicationError> {
 
         match self.access_service.ensure_can_reset_password(
             self.id_provider.is_auth(),
@@ -46,18 +55,16 @@ impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
             Ok(_) => (),
             Err(error) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(error.to_string())
+                    ErrorContent::Message(error.into())
                 )
             )
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
-        self.validator.validate_email(&data.email).unwrap_or_else(|e| {
-            validator_err_map.insert("email".to_string(), e.to_string());
-        });
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.email = self.normalizer.normalize_email(&data.email);
 
-        self.validator.validate_password(&data.new_password).unwrap_or_else(|e| {
-            validator_err_map.insert("new_password".to_string(), e.to_string());
+        self.validator.validate_email(&data.email).unwrap_or_else(|e| {
+            validator_err_map.insert("email".to_string(), e);
         });
 
         if !validator_err_map.is_empty() {
@@ -71,18 +78,31 @@ impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
         let user = self.user_gateway.get_user_by_email_not_sensitive(&data.email).await.ok_or(
             ApplicationError::InvalidData(
                 ErrorContent::Map(
-                    [("email".to_string(), "Пользователь таким email не существует".to_string())]
+                    [("email".to_string(), Message::new("email_not_registered"))]
                         .iter().cloned().collect()
                 )
             )
         )?;
 
+        let is_breached = self.breached_password_checker.is_breached(&data.new_password).await;
+        self.validator.validate_password(
+            &data.new_password,
+            &[&user.username, &user.email],
+            is_breached
+        ).map_err(|e| ApplicationError::InvalidData(
+            ErrorContent::Map(
+                [("new_password".to_string(), e)].into_iter().collect()
+            )
+        ))?;
+
+        self.password_reuse.ensure_not_reused(&user, &data.new_password).await?;
+        
         self.confirm_code.confirm(
             &data.email,
             data.code,
         ).await.map_err(
             |e| ApplicationError::InvalidData(
-                ErrorContent::Message(e.to_string())
+                ErrorContent::Message(e)
             )
         )?;
 
@@ -92,13 +112,13 @@ impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
             user.clone(),
             user.username.clone(),
             user.email.clone(),
-            user.state,
             user.first_name,
             user.last_name,
             hashed_password
         );
         
         self.user_gateway.save_user(&new_user).await;
+        self.password_history_gateway.save_password_history(&user.id, &user.hashed_password).await;
         
         self.session_remover.remove_user_sessions(&user.id).await;
         
@@ -106,10 +126,9 @@ impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
             let mut context = BTreeMap::new();
             context.insert("username".to_string(), Value::String(user.username.clone()));
             context.insert("ip".to_string(), Value::String(self.id_provider.ip().to_string()));
-            context.insert("change_time".to_string(), Value::String({
-                let now = chrono::Utc::now();
-                now.format("%d/%m/%Y %H:%M %Z").to_string()
-            }));
+            context.insert("change_time".to_string(), Value::String(
+                self.user_service.format_local_time(&new_user, chrono::Utc::now())
+            ));
             context.insert("email".to_string(), Value::String(user.email.clone()));
             context.insert("company".to_string(), Value::String(self.extra.company.clone()));
             context.insert("company_url".to_string(), Value::String(self.extra.company_url.clone()));
@@ -118,8 +137,8 @@ impl Interactor<ResetPasswordDTO, ()> for ResetPassword<'_> {
         };
         
         self.email_sender.send_template(
-            &user.email,
-            "Сброс пароля",
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_password_reset"),
             "successfully_reset_password.html",
             Some(context),
             13,
@@ -0,0 +1,86 @@
+use serde::Deserialize;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::one_time_token::OneTimeToken;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::Message;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+
+#[derive(Debug, Deserialize)]
+pub struct RevertEmailChangeDTO {
+    pub token: String,
+}
+
+pub struct RevertEmailChange<'a> {
+    pub one_time_token: &'a dyn OneTimeToken,
+    pub user_gateway: &'a dyn UserGateway,
+    pub session_remover: &'a dyn SessionRemover,
+    pub user_service: &'a UserService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<RevertEmailChangeDTO, ()> for RevertEmailChange<'_> {
+    async fn execute(&self, data: RevertEmailChangeDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_revert_email_change(
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(e) => return Err(
+                ApplicationError::Forbidden(
+                    ErrorContent::Message(e.into())
+                )
+            )
+        };
+
+        let invalid_link = || ApplicationError::InvalidData(
+            ErrorContent::Message(Message::new("link_expired"))
+        );
+
+        let payload = self.one_time_token.redeem(&data.token).await.ok_or_else(invalid_link)?;
+
+        let (user_id, old_email) = payload
+            .strip_prefix("email_revert:")
+            .and_then(|payload| payload.split_once(':'))
+            .and_then(|(user_id, email)| Some((user_id.parse::<UserId>().ok()?, email.to_string())))
+            .ok_or_else(invalid_link)?;
+
+        let user = self.user_gateway.get_user_by_id(&user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        if let Some(owner) = self.user_gateway.get_user_by_email_not_sensitive(&old_email).await {
+            if owner.id != user.id {
+                return Err(
+                    ApplicationError::Conflict(
+                        ErrorContent::Message(Message::new("previous_email_taken"))
+                    )
+                )
+            }
+        }
+
+        let new_user = self.user_service.update_user(
+            user.clone(),
+            user.username.clone(),
+            old_email,
+            user.first_name,
+            user.last_name,
+            user.hashed_password
+        );
+
+        self.user_gateway.save_user(&new_user).await;
+
+        // Смену почты мог выполнить злоумышленник, поэтому завершаем все сессии
+        self.session_remover.remove_user_sessions(&user.id).await;
+
+        Ok(())
+    }
+}
@@ -4,13 +4,15 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::application::common::confirm_code::ConfirmCode;
-use crate::application::common::email_sender::EmailSender;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserReader;
 use crate::config::Extra;
+use crate::domain::exceptions::Message;
 use crate::domain::services::access::AccessService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -24,12 +26,13 @@ pub struct SendConfirmCode<'a> {
     pub extra: &'a Extra,
     pub user_reader: &'a dyn UserReader,
     pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
     pub access_service: &'a AccessService,
     pub id_provider: Box<dyn IdProvider>,
 }
 
 impl Interactor<SendConfirmCodeDTO, ()> for SendConfirmCode<'_> {
-    async fn execute(&self, data: SendConfirmCodeDTO) -> Result<(), ApplicationError> {
+    async fn execute(&self, mut data: SendConfirmCodeDTO) -> Result<(), ApplicationError> {
         
         match self.access_service.ensure_can_send_confirm_code(
             &self.id_provider.is_auth(),
@@ -38,14 +41,16 @@ impl Interactor<SendConfirmCodeDTO, ()> for SendConfirmCode<'_> {
             Ok(_) => (),
             Err(e) => return Err(
                 ApplicationError::Forbidden(
-                    ErrorContent::Message(e.to_string())
+                    ErrorContent::Message(e.into())
                 )
             )
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.email = self.normalizer.normalize_email(&data.email);
+
         self.validator.validate_email(&data.email).unwrap_or_else(|e| {
-            validator_err_map.insert("email".to_string(), e.to_string());
+            validator_err_map.insert("email".to_string(), e);
         });
 
         if !validator_err_map.is_empty() {
@@ -58,13 +63,13 @@ impl Interactor<SendConfirmCodeDTO, ()> for SendConfirmCode<'_> {
         
         let user = self.user_reader.get_user_by_email_not_sensitive(&data.email).await.ok_or(
             ApplicationError::NotFound(
-                ErrorContent::Message("Пользователь не найден".to_string())
+                ErrorContent::Message(Message::new("user_not_found"))
             )
         )?;
         
         let code = self.confirm_code.generate(&user.email).await.map_err(
             |error| ApplicationError::InvalidData(
-                ErrorContent::Message(error.to_string())
+                ErrorContent::Message(error)
             )
         )?;
         
@@ -78,8 +83,8 @@ impl Interactor<SendConfirmCodeDTO, ()> for SendConfirmCode<'_> {
         };
         
         self.email_sender.send_template(
-            &user.email,
-            "Подтверждение операции смены данных",
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new("email_subject_confirm_code"),
             "confirm_code.html",
             Some(context),
             13,
@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::common::confirm_code::ConfirmCode;
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserReader;
+use crate::config::Extra;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::services::access::AccessService;
+use crate::domain::services::normalizer::NormalizerService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct SendEmailChangeCodeDTO {
+    pub email: String,
+}
+
+pub struct SendEmailChangeCode<'a> {
+    pub email_sender: &'a dyn EmailSender,
+    pub confirm_code: &'a dyn ConfirmCode,
+    pub extra: &'a Extra,
+    pub user_reader: &'a dyn UserReader,
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<SendEmailChangeCodeDTO, ()> for SendEmailChangeCode<'_> {
+    async fn execute(&self, mut data: SendEmailChangeCodeDTO) -> Result<(), ApplicationError> {
+
+        match self.access_service.ensure_can_change_email_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return match error {
+                DomainError::AccessDenied => Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.email = self.normalizer.normalize_email(&data.email);
+
+        self.validator.validate_email(&data.email).unwrap_or_else(|e| {
+            validator_err_map.insert("email".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user_id = self.id_provider.user_id().unwrap();
+
+        if let Some(user) = self.user_reader.get_user_by_email_not_sensitive(&data.email).await {
+            validator_err_map.insert(
+                "email".to_string(),
+                if &user.id == user_id {
+                    Message::new("email_unchanged")
+                } else {
+                    Message::new("email_taken")
+                }
+            );
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = self.user_reader.get_user_by_id(user_id).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let code = self.confirm_code.generate(
+            &format!("email_change:{}:{}", user.id, self.normalizer.canonical_email(&data.email))
+        ).await.map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Message(error)
+            )
+        )?;
+
+        let context: BTreeMap<String, Value> = {
+            let mut context = BTreeMap::new();
+            context.insert("code".to_string(), Value::String(code.to_string()));
+            context.insert("username".to_string(), Value::String(user.username));
+            context.insert("company".to_string(), Value::String(self.extra.company.clone()));
+            context.insert("company_url".to_string(), Value::String(self.extra.company_url.clone()));
+            context
+        };
+
+        self.email_sender.send_template(
+            &EmailRecipient { email: &data.email, locale: &user.locale },
+            &Message::new("email_subject_email_change_code"),
+            "email_change_code.html",
+            Some(context),
+            13,
+            900, // 15 minutes
+        ).await;
+
+        Ok(())
+    }
+}
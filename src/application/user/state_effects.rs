@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+use crate::application::common::password_history_gateway::PasswordHistoryRemover;
+use crate::application::common::session_gateway::SessionRemover;
+use crate::config::Extra;
+use crate::domain::exceptions::Message;
+use crate::domain::models::user::{User, UserState, UserStateEffect};
+
+/// **UserStateEffects** - выполнение побочных действий смены состояния пользователя
+///
+/// Действия для каждого перехода определяет `UserService::change_state`.
+pub struct UserStateEffects<'a> {
+    pub session_remover: &'a dyn SessionRemover,
+    pub password_history_remover: &'a dyn PasswordHistoryRemover,
+    pub email_sender: &'a dyn EmailSender,
+    pub extra: &'a Extra,
+}
+
+impl UserStateEffects<'_> {
+    pub async fn apply(&self, user: &User, effects: &[UserStateEffect]) {
+        for effect in effects {
+            match effect {
+                UserStateEffect::RevokeSessions => {
+                    self.session_remover.remove_user_sessions(&user.id).await
+                },
+                UserStateEffect::ClearPasswordHistory => {
+                    self.password_history_remover.remove_password_history(&user.id).await
+                },
+                UserStateEffect::Notify => self.notify(user).await,
+            }
+        }
+    }
+
+    async fn notify(&self, user: &User) {
+        let subject = match user.state {
+            UserState::Active => "email_subject_account_restored",
+            UserState::Banned => "email_subject_account_banned",
+            UserState::Deleted => "email_subject_account_deleted",
+            UserState::Inactive => return
+        };
+
+        let context: BTreeMap<String, Value> = {
+            let mut context = BTreeMap::new();
+            context.insert("username".to_string(), Value::String(user.username.clone()));
+            context.insert("state".to_string(), Value::String(user.state.to_string()));
+            context.insert("support_email".to_string(), Value::String(self.extra.support_email.to_string()));
+            context.insert("company".to_string(), Value::String(self.extra.company.to_string()));
+            context.insert("company_url".to_string(), Value::String(self.extra.company_url.to_string()));
+            context
+        };
+
+        self.email_sender.send_template(
+            &EmailRecipient { email: &user.email, locale: &user.locale },
+            &Message::new(subject),
+            "account_state.html",
+            Some(context),
+            13,
+            3600
+        ).await;
+    }
+}
@@ -6,10 +6,12 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserGateway;
-use crate::domain::exceptions::DomainError;
+use crate::application::user::state_effects::UserStateEffects;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::user::{UserId, UserState};
 use crate::domain::services::access::AccessService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -20,6 +22,8 @@ pub struct UpdateUserDTO {
     pub state: UserState,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
+    /// Потребовать смену пароля при следующем входе, `None` - не менять
+    pub must_change_password: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -30,19 +34,22 @@ pub struct UpdateUserResultDTO{
     state: UserState,
     first_name: Option<String>,
     last_name: Option<String>,
+    must_change_password: bool,
 }
 
 
 pub struct UpdateUser<'a> {
     pub user_gateway: &'a dyn UserGateway,
+    pub state_effects: UserStateEffects<'a>,
     pub user_service: &'a UserService,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
-    pub validator: &'a ValidatorService
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService
 }
 
 impl Interactor<UpdateUserDTO, UpdateUserResultDTO> for UpdateUser<'_> {
-    async fn execute(&self, data: UpdateUserDTO) -> Result<UpdateUserResultDTO, ApplicationError> {
+    async fn execute(&self, mut data: UpdateUserDTO) -> Result<UpdateUserResultDTO, ApplicationError> {
         
         match self.access_service.ensure_can_update_user(
             self.id_provider.is_auth(),
@@ -53,35 +60,38 @@ impl Interactor<UpdateUserDTO, UpdateUserResultDTO> for UpdateUser<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         }
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.username = self.normalizer.normalize_username(&data.username);
+        data.email = self.normalizer.normalize_email(&data.email);
+
         self.validator.validate_username(&data.username).unwrap_or_else(|e| {
-            validator_err_map.insert("username".to_string(), e.to_string());
+            validator_err_map.insert("username".to_string(), e);
         });
         
         self.validator.validate_email(&data.email).unwrap_or_else(|e| {
-            validator_err_map.insert("email".to_string(), e.to_string());
+            validator_err_map.insert("email".to_string(), e);
         });
 
         if let Some(first_name) = &data.first_name {
             self.validator.validate_first_name(first_name).unwrap_or_else(|e| {
-                validator_err_map.insert("first_name".to_string(), e.to_string());
+                validator_err_map.insert("first_name".to_string(), e);
             });
         }
 
         if let Some(last_name) = &data.last_name {
             self.validator.validate_last_name(last_name).unwrap_or_else(|e| {
-                validator_err_map.insert("last_name".to_string(), e.to_string());
+                validator_err_map.insert("last_name".to_string(), e);
             });
         }
 
@@ -105,36 +115,53 @@ impl Interactor<UpdateUserDTO, UpdateUserResultDTO> for UpdateUser<'_> {
         //     Err(e) => panic!("Error: {:?}", e)
         // };
         
+        // Поиск идет по каноническим формам, на которых стоят уникальные индексы
         if user_by_username.is_some() && user_by_username.unwrap().id != data.id {
-            validator_err_map.insert("username".to_string(), "Имя пользователя занято".to_string());
+            validator_err_map.insert("username".to_string(), Message::new("username_taken"));
         }
         
         if user_by_email.is_some() && user_by_email.unwrap().id != data.id{
-            validator_err_map.insert("email".to_string(), "Email занят".to_string());
+            validator_err_map.insert("email".to_string(), Message::new("email_taken"));
         }
         
-        
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
 
         let user = match self.user_gateway.get_user_by_id(&data.id).await {
             Some(user) => user,
             None => {
                 return Err(ApplicationError::NotFound(
-                    ErrorContent::Message("Пользователь не найден".to_string()))
+                    ErrorContent::Message(Message::new("user_not_found")))
                 );
             }
         };
         
-        let new_user = self.user_service.update_user(
+        let mut new_user = self.user_service.update_user(
             user.clone(),
-            data.email,
             data.username,
-            data.state,
+            data.email,
             data.first_name,
             data.last_name,
             user.hashed_password
         );
 
+        if let Some(must_change_password) = data.must_change_password {
+            new_user = self.user_service.set_must_change_password(new_user, must_change_password);
+        }
+
+        let (new_user, effects) = self.user_service.change_state(new_user, data.state).map_err(
+            |error| ApplicationError::InvalidData(
+                ErrorContent::Map(HashMap::from([("state".to_string(), error.into())]))
+            )
+        )?;
+
         self.user_gateway.save_user(&new_user).await;
+        self.state_effects.apply(&new_user, &effects).await;
 
         Ok(UpdateUserResultDTO {
             id: new_user.id,
@@ -143,6 +170,183 @@ impl Interactor<UpdateUserDTO, UpdateUserResultDTO> for UpdateUser<'_> {
             state: new_user.state,
             first_name: new_user.first_name,
             last_name: new_user.last_name,
+            must_change_password: new_user.must_change_password,
         })
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
+    use serde_json::Value;
+
+    use crate::application::common::email_sender::{EmailRecipient, EmailSender};
+    use crate::application::common::pagination::Cursor;
+    use crate::application::common::password_history_gateway::PasswordHistoryRemover;
+    use crate::application::common::session_gateway::SessionRemover;
+    use crate::application::common::user_gateway::{
+        SortOrder,
+        UserFilter,
+        UserReader,
+        UserSortField,
+        UserWriter
+    };
+    use crate::config::Extra;
+    use crate::domain::models::normalization::Normalization;
+    use crate::domain::models::password_policy::PasswordPolicy;
+    use crate::domain::models::permission::PermissionTextId;
+    use crate::domain::models::role::RoleId;
+    use crate::domain::models::service::ServiceTextId;
+    use crate::domain::models::session::SessionId;
+    use crate::domain::models::ums_permission::UMSPermission;
+    use crate::domain::models::user::User;
+
+    use super::*;
+
+    struct MemoryUserGateway {
+        users: Mutex<Vec<User>>,
+    }
+
+    #[async_trait]
+    impl UserReader for MemoryUserGateway {
+        async fn get_user_by_id(&self, user_id: &UserId) -> Option<User> {
+            self.users.lock().unwrap().iter().find(|user| &user.id == user_id).cloned()
+        }
+        async fn get_users_by_ids(&self, _: &Vec<UserId>) -> Option<Vec<User>> { unimplemented!() }
+        async fn get_users_by_filter(
+            &self,
+            _: &UserFilter,
+            _: &UserSortField,
+            _: &SortOrder,
+            _: Option<&Cursor>,
+            _: &u64
+        ) -> Vec<(User, Option<DateTime<Utc>>)> { unimplemented!() }
+        async fn count_users_by_filter(&self, _: &UserFilter) -> u64 { unimplemented!() }
+        async fn get_user_by_username_not_sensitive(&self, username: &String) -> Option<User> {
+            self.users.lock().unwrap().iter().find(|user| &user.username == username).cloned()
+        }
+        async fn get_user_by_email_not_sensitive(&self, email: &String) -> Option<User> {
+            self.users.lock().unwrap().iter().find(|user| &user.email == email).cloned()
+        }
+        async fn get_user_by_phone(&self, _: &str) -> Option<User> { unimplemented!() }
+        async fn get_users_by_usernames_or_emails_not_sensitive(
+            &self,
+            _: &[String],
+            _: &[String]
+        ) -> Vec<User> { unimplemented!() }
+    }
+
+    #[async_trait]
+    impl UserWriter for MemoryUserGateway {
+        async fn save_user(&self, data: &User) {
+            let mut users = self.users.lock().unwrap();
+            users.retain(|user| user.id != data.id);
+            users.push(data.clone());
+        }
+        async fn import_users(&self, _: &[User], _: &[(RoleId, UserId)]) -> Vec<UserId> { unimplemented!() }
+    }
+
+    impl UserGateway for MemoryUserGateway {}
+
+    /// Побочные эффекты смены состояния в тесте не наступают
+    struct NoEffects;
+
+    #[async_trait]
+    impl SessionRemover for NoEffects {
+        async fn remove_session(&self, _: &SessionId) { unimplemented!() }
+        async fn remove_user_sessions(&self, _: &UserId) { unimplemented!() }
+        async fn remove_user_sessions_from_cache(&self, _: &UserId) { unimplemented!() }
+    }
+
+    #[async_trait]
+    impl PasswordHistoryRemover for NoEffects {
+        async fn remove_password_history(&self, _: &UserId) { unimplemented!() }
+    }
+
+    #[async_trait]
+    impl EmailSender for NoEffects {
+        async fn send(&self, _: &str, _: &str, _: &str, _: &str, _: u8, _: u32) { unimplemented!() }
+        async fn send_template(
+            &self,
+            _: &EmailRecipient<'_>,
+            _: &Message,
+            _: &str,
+            _: Option<BTreeMap<String, Value>>,
+            _: u8,
+            _: u32
+        ) { unimplemented!() }
+    }
+
+    struct AdminIdProvider {
+        permissions: Vec<String>,
+    }
+
+    impl IdProvider for AdminIdProvider {
+        fn session_id(&self) -> Option<&SessionId> { None }
+        fn user_id(&self) -> Option<&UserId> { None }
+        fn user_state(&self) -> Option<&UserState> { Some(&UserState::Active) }
+        fn permissions(&self) -> &Vec<String> { &self.permissions }
+        fn service_permissions(&self, _: &ServiceTextId) -> Option<&Vec<PermissionTextId>> { None }
+        fn client(&self) -> &str { "test" }
+        fn os(&self) -> &str { "test" }
+        fn device(&self) -> &str { "test" }
+        fn ip(&self) -> &str { "127.0.0.1" }
+        fn is_auth(&self) -> &bool { &true }
+    }
+
+    #[tokio::test]
+    async fn test_update_user_stores_username_and_email() {
+        let user = UserService {}.create_user(
+            "old_name".to_string(),
+            "old@example.com".to_string(),
+            UserState::Active,
+            "hash".to_string(),
+            None,
+            None,
+        );
+        let user_gateway = MemoryUserGateway { users: Mutex::new(vec![user.clone()]) };
+        let extra = Extra {
+            company: "Company".to_string(),
+            company_url: "https://example.com".to_string(),
+            support_email: "support@example.com".to_string(),
+            reset_password_url: "https://example.com/reset".to_string(),
+            revert_email_url: None,
+            invitation_url: None,
+        };
+        let interactor = UpdateUser {
+            user_gateway: &user_gateway,
+            state_effects: UserStateEffects {
+                session_remover: &NoEffects,
+                password_history_remover: &NoEffects,
+                email_sender: &NoEffects,
+                extra: &extra,
+            },
+            user_service: &UserService {},
+            id_provider: Box::new(AdminIdProvider {
+                permissions: vec![UMSPermission::UpdateUser.to_string()]
+            }),
+            access_service: &AccessService {},
+            validator: &ValidatorService::new(PasswordPolicy::default()),
+            normalizer: &NormalizerService::new(Normalization::default()),
+        };
+
+        let result = interactor.execute(UpdateUserDTO {
+            id: user.id,
+            email: "new@example.com".to_string(),
+            username: "new_name".to_string(),
+            state: UserState::Active,
+            first_name: None,
+            last_name: None,
+            must_change_password: None,
+        }).await.unwrap();
+
+        assert_eq!(result.username, "new_name");
+        assert_eq!(result.email, "new@example.com");
+        let stored = user_gateway.get_user_by_id(&user.id).await.unwrap();
+        assert_eq!(stored.username, "new_name");
+        assert_eq!(stored.email, "new@example.com");
+    }
+}
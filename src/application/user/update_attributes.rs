@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::service_gateway::ServiceGateway;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserAttributesDTO {
+    pub user_id: Option<UserId>,
+    pub namespace: ServiceTextId,
+    pub attributes: Value,
+}
+
+pub struct UpdateUserAttributes<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub service_gateway: &'a dyn ServiceGateway,
+    pub user_service: &'a UserService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
+}
+
+impl Interactor<UpdateUserAttributesDTO, Value> for UpdateUserAttributes<'_> {
+    async fn execute(&self, data: UpdateUserAttributesDTO) -> Result<Value, ApplicationError> {
+
+        let user_id = match data.user_id.or(self.id_provider.user_id().cloned()) {
+            Some(user_id) => user_id,
+            None => return Err(
+                ApplicationError::Unauthorized(
+                    ErrorContent::Message(DomainError::AuthorizationRequired.into())
+                )
+            )
+        };
+
+        match self.access_service.ensure_can_update_user_attributes(
+            self.id_provider.is_auth(),
+            self.id_provider.user_id(),
+            &user_id,
+            self.id_provider.user_state(),
+            self.id_provider.service_permissions(&data.namespace)
+        ) {
+            Ok(_) => (),
+            Err(error) => match error {
+                DomainError::AccessDenied => return Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => return Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let schema = match self.service_gateway.get_services_by_text_id(&data.namespace).await {
+            Some(service) => match service.attribute_schema {
+                Some(schema) => schema,
+                None => return Err(
+                    ApplicationError::NotFound(
+                        ErrorContent::Message(Message::new("attribute_namespace_not_found"))
+                    )
+                )
+            },
+            None => return Err(
+                ApplicationError::NotFound(
+                    ErrorContent::Message(Message::new("attribute_namespace_not_found"))
+                )
+            )
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        self.validator.validate_attributes(&schema, &data.attributes).unwrap_or_else(|e| {
+            validator_err_map.insert("attributes".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = match self.user_gateway.get_user_by_id(&user_id).await {
+            Some(user) => user,
+            None => return Err(
+                ApplicationError::NotFound(
+                    ErrorContent::Message(Message::new("user_not_found"))
+                )
+            ),
+        };
+
+        let user = self.user_service.update_attributes(
+            user,
+            data.namespace.clone(),
+            data.attributes
+        );
+        self.user_gateway.save_user(&user).await;
+
+        Ok(self.user_service.get_attributes(&user, &data.namespace))
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::domain::exceptions::Message;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::user::UserService;
+use crate::domain::services::validator::ValidatorService;
+
+/// Не указанные поля остаются без изменений
+#[derive(Debug, Deserialize)]
+pub struct UpdatePreferencesSelfDTO {
+    pub locale: Option<String>,
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreferencesResultDTO {
+    locale: String,
+    timezone: String,
+}
+
+pub struct UpdatePreferencesSelf<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub user_service: &'a UserService,
+    pub validator: &'a ValidatorService,
+    pub access_service: &'a AccessService,
+    pub id_provider: Box<dyn IdProvider>,
+}
+
+impl Interactor<UpdatePreferencesSelfDTO, PreferencesResultDTO> for UpdatePreferencesSelf<'_> {
+    async fn execute(&self, data: UpdatePreferencesSelfDTO) -> Result<PreferencesResultDTO, ApplicationError> {
+
+        match self.access_service.ensure_can_update_user_self(
+            self.id_provider.is_auth(),
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => return Err(
+                ApplicationError::Forbidden(
+                    ErrorContent::Message(error.into())
+                )
+            )
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        if let Some(locale) = &data.locale {
+            self.validator.validate_locale(locale).unwrap_or_else(|e| {
+                validator_err_map.insert("locale".to_string(), e);
+            });
+        }
+
+        if let Some(timezone) = &data.timezone {
+            self.validator.validate_timezone(timezone).unwrap_or_else(|e| {
+                validator_err_map.insert("timezone".to_string(), e);
+            });
+        }
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = self.user_gateway.get_user_by_id(self.id_provider.user_id().unwrap()).await.ok_or(
+            ApplicationError::NotFound(
+                ErrorContent::Message(Message::new("user_not_found"))
+            )
+        )?;
+
+        let locale = data.locale.unwrap_or(user.locale.clone());
+        let timezone = data.timezone.unwrap_or(user.timezone.clone());
+        let new_user = self.user_service.set_preferences(user, locale, timezone);
+        self.user_gateway.save_user(&new_user).await;
+
+        Ok(PreferencesResultDTO {
+            locale: new_user.locale,
+            timezone: new_user.timezone,
+        })
+    }
+}
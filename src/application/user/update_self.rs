@@ -6,10 +6,11 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
 use crate::application::common::user_gateway::UserGateway;
-use crate::domain::exceptions::DomainError;
+use crate::domain::exceptions::{DomainError, Message};
 use crate::domain::models::user::{UserId, UserState};
 use crate::domain::services::access::AccessService;
 use crate::domain::services::user::UserService;
+use crate::domain::services::normalizer::NormalizerService;
 use crate::domain::services::validator::ValidatorService;
 
 #[derive(Debug, Deserialize)]
@@ -35,11 +36,12 @@ pub struct UpdateUserSelf<'a> {
     pub user_service: &'a UserService,
     pub id_provider: Box<dyn IdProvider>,
     pub access_service: &'a AccessService,
-    pub validator: &'a ValidatorService
+    pub validator: &'a ValidatorService,
+    pub normalizer: &'a NormalizerService
 }
 
 impl Interactor<UpdateSelfDTO, UpdateSelfResultDTO> for UpdateUserSelf<'_> {
-    async fn execute(&self, data: UpdateSelfDTO) -> Result<UpdateSelfResultDTO, ApplicationError> {
+    async fn execute(&self, mut data: UpdateSelfDTO) -> Result<UpdateSelfResultDTO, ApplicationError> {
         
         match self.access_service.ensure_can_update_user_self(
             self.id_provider.is_auth(),
@@ -50,31 +52,33 @@ impl Interactor<UpdateSelfDTO, UpdateSelfResultDTO> for UpdateUserSelf<'_> {
             Err(error) => return match error {
                 DomainError::AccessDenied => Err(
                     ApplicationError::Forbidden(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 ),
                 DomainError::AuthorizationRequired => Err(
                     ApplicationError::Unauthorized(
-                        ErrorContent::Message(error.to_string())
+                        ErrorContent::Message(error.into())
                     )
                 )
             }
         };
 
-        let mut validator_err_map: HashMap<String, String> = HashMap::new();
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        data.username = self.normalizer.normalize_username(&data.username);
+
         self.validator.validate_username(&data.username).unwrap_or_else(|e| {
-            validator_err_map.insert("username".to_string(), e.to_string());
+            validator_err_map.insert("username".to_string(), e);
         });
 
         if let Some(first_name) = &data.first_name {
             self.validator.validate_first_name(first_name).unwrap_or_else(|e| {
-                validator_err_map.insert("first_name".to_string(), e.to_string());
+                validator_err_map.insert("first_name".to_string(), e);
             });
         }
 
         if let Some(last_name) = &data.last_name {
             self.validator.validate_last_name(last_name).unwrap_or_else(|e| {
-                validator_err_map.insert("last_name".to_string(), e.to_string());
+                validator_err_map.insert("last_name".to_string(), e);
             });
         }
 
@@ -91,7 +95,7 @@ impl Interactor<UpdateSelfDTO, UpdateSelfResultDTO> for UpdateUserSelf<'_> {
             if &user.id != self.id_provider.user_id().unwrap() {
                 validator_err_map.insert(
                     "username".to_string(), 
-                    "Имя пользователя занято".to_string()
+                    Message::new("username_taken")
                 );
                 return Err(
                     ApplicationError::InvalidData(
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use strum::IntoEnumIterator;
+
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::file_storage::FileStorage;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::image_processor::ImageProcessor;
+use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::UserGateway;
+use crate::application::user::avatar::AvatarDTO;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::avatar::AvatarSize;
+use crate::domain::models::user::UserId;
+use crate::domain::services::access::AccessService;
+use crate::domain::services::avatar::AvatarService;
+use crate::domain::services::validator::ValidatorService;
+
+pub struct UploadAvatarDTO {
+    pub user_id: Option<UserId>,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+pub struct UploadAvatar<'a> {
+    pub user_gateway: &'a dyn UserGateway,
+    pub file_storage: &'a dyn FileStorage,
+    pub image_processor: &'a dyn ImageProcessor,
+    pub avatar_service: &'a AvatarService,
+    pub id_provider: Box<dyn IdProvider>,
+    pub access_service: &'a AccessService,
+    pub validator: &'a ValidatorService,
+}
+
+impl Interactor<UploadAvatarDTO, AvatarDTO> for UploadAvatar<'_> {
+    async fn execute(&self, data: UploadAvatarDTO) -> Result<AvatarDTO, ApplicationError> {
+
+        let user_id = match data.user_id.or(self.id_provider.user_id().cloned()) {
+            Some(user_id) => user_id,
+            None => return Err(
+                ApplicationError::Unauthorized(
+                    ErrorContent::Message(DomainError::AuthorizationRequired.into())
+                )
+            )
+        };
+
+        match self.access_service.ensure_can_update_user_avatar(
+            self.id_provider.is_auth(),
+            self.id_provider.user_id(),
+            &user_id,
+            self.id_provider.user_state(),
+            self.id_provider.permissions()
+        ) {
+            Ok(_) => (),
+            Err(error) => match error {
+                DomainError::AccessDenied => return Err(
+                    ApplicationError::Forbidden(
+                        ErrorContent::Message(error.into())
+                    )
+                ),
+                DomainError::AuthorizationRequired => return Err(
+                    ApplicationError::Unauthorized(
+                        ErrorContent::Message(error.into())
+                    )
+                )
+            }
+        };
+
+        let mut validator_err_map: HashMap<String, Message> = HashMap::new();
+        self.validator.validate_avatar(&data.content_type, data.data.len()).unwrap_or_else(|e| {
+            validator_err_map.insert("avatar".to_string(), e);
+        });
+
+        if !validator_err_map.is_empty() {
+            return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(validator_err_map)
+                )
+            )
+        }
+
+        let user = match self.user_gateway.get_user_by_id(&user_id).await {
+            Some(user) => user,
+            None => return Err(
+                ApplicationError::NotFound(
+                    ErrorContent::Message(Message::new("user_not_found"))
+                )
+            ),
+        };
+        
+        let sizes = AvatarSize::iter().collect::<Vec<AvatarSize>>();
+        let variants = match self.image_processor.square_variants(
+            data.data,
+            &sizes.iter().map(|size| size.pixels()).collect::<Vec<u32>>()
+        ).await {
+            Ok(variants) => variants,
+            Err(error) => return Err(
+                ApplicationError::InvalidData(
+                    ErrorContent::Map(HashMap::from([("avatar".to_string(), error)]))
+                )
+            )
+        };
+        
+        let avatar_id = self.avatar_service.create_avatar_id();
+        for (size, variant) in sizes.iter().zip(variants) {
+            self.file_storage.put(
+                &self.avatar_service.object_key(&user.id, &avatar_id, size),
+                variant,
+                self.avatar_service.content_type()
+            ).await;
+        }
+        
+        let old_avatar_id = user.avatar_id;
+        let user = self.avatar_service.set_avatar(user, Some(avatar_id));
+        self.user_gateway.save_user(&user).await;
+        
+        if let Some(old_avatar_id) = old_avatar_id {
+            for size in sizes.iter() {
+                self.file_storage.remove(
+                    &self.avatar_service.object_key(&user.id, &old_avatar_id, size)
+                ).await;
+            }
+        }
+        
+        Ok(AvatarDTO::from_user(&user, self.avatar_service, self.file_storage).unwrap())
+    }
+}
@@ -2,24 +2,73 @@ use consulrs::client::{ConsulClient, ConsulClientSettingsBuilder};
 use consulrs::kv;
 use serde::Deserialize;
 
+use crate::domain::models::normalization::Normalization;
+use crate::domain::models::password_policy::PasswordPolicy;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Extra {
     pub company: String,
     pub company_url: String,
     pub support_email: String,
     pub reset_password_url: String,
+    /// Ссылка для отмены смены почты, без нее в письме предлагается написать в поддержку
+    pub revert_email_url: Option<String>,
+    /// Ссылка на страницу принятия приглашения, без нее приглашения отключены
+    pub invitation_url: Option<String>,
 }
 
 
+/// Параметры Argon2id для хеширования паролей
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Argon2 {
+    pub memory_cost: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2 {
+    fn default() -> Self {
+        Self {
+            memory_cost: 2048,
+            time_cost: 64,
+            parallelism: 4,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Base {
     pub title: String,
     pub description: String,
     pub session_exp: u32,
     pub confirm_code_ttl: u32,
+    #[serde(default)]
+    pub password_policy: PasswordPolicy,
+    /// Правила канонизации имен пользователей и email
+    #[serde(default)]
+    pub normalization: Normalization,
+    /// Файл со скомпрометированными паролями, по одному в строке
+    pub breached_passwords_path: Option<String>,
+    #[serde(default)]
+    pub argon2: Argon2,
+    /// Интервал в секундах между проверками сроков действия назначений ролей
+    #[serde(default = "default_role_link_check_interval")]
+    pub role_link_check_interval: u64,
+    /// Интервал в секундах между проверками сроков банов
+    #[serde(default = "default_ban_check_interval")]
+    pub ban_check_interval: u64,
     pub extra: Extra,
 }
 
+fn default_role_link_check_interval() -> u64 {
+    60
+}
+
+fn default_ban_check_interval() -> u64 {
+    60
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Postgresql {
     pub host: String,
@@ -44,6 +93,7 @@ pub struct S3 {
     pub access_key: String,
     pub secret_key: String,
     pub bucket: String,
+    pub public_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -62,6 +112,12 @@ pub struct Email {
     pub rabbitmq: EmailRabbitMQ,
 }
 
+/// SMS пишутся в лог и, если задан `log_path`, в файл
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Sms {
+    pub log_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Database {
     pub postgresql: Postgresql,
@@ -74,6 +130,8 @@ pub struct Config {
     pub base: Base,
     pub database: Database,
     pub email: Email,
+    #[serde(default)]
+    pub sms: Sms,
 }
 
 
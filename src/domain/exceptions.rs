@@ -1,10 +1,74 @@
+use std::collections::BTreeMap;
+
 use derive_more::{Display, Error};
+use serde::Serialize;
+
+use crate::domain::models::user::UserState;
 
 #[derive(Debug, Display, Error)]
 pub enum DomainError {
-    #[display(fmt = "Необходима авторизация")]
+    #[display(fmt = "authorization_required")]
     AuthorizationRequired,
 
-    #[display(fmt = "У Вас нет доступа к этому ресурсу")]
+    #[display(fmt = "access_denied")]
     AccessDenied,
 }
+
+/// **StateTransitionError** - переход пользователя между состояниями запрещен
+#[derive(Debug, Display, Error)]
+#[display(fmt = "user_state_transition_forbidden")]
+pub struct StateTransitionError {
+    pub from: UserState,
+    pub to: UserState,
+}
+
+/// **Message** - сообщение об ошибке в виде стабильного кода
+///
+/// Текст не хранится: он подставляется из каталога переводов на уровне
+/// представления по коду и именованным параметрам `args`.
+/// `details` содержит вложенные сообщения, например все нарушения политики паролей.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Message {
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<&'static str, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<Message>,
+}
+
+impl Message {
+    pub fn new(code: &'static str) -> Self {
+        Self {
+            code,
+            args: BTreeMap::new(),
+            details: vec![],
+        }
+    }
+
+    pub fn arg(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.args.insert(name, value.to_string());
+        self
+    }
+
+    pub fn details(mut self, details: Vec<Message>) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+impl From<DomainError> for Message {
+    fn from(error: DomainError) -> Self {
+        match error {
+            DomainError::AuthorizationRequired => Message::new("authorization_required"),
+            DomainError::AccessDenied => Message::new("access_denied"),
+        }
+    }
+}
+
+impl From<StateTransitionError> for Message {
+    fn from(error: StateTransitionError) -> Self {
+        Message::new("user_state_transition_forbidden")
+            .arg("from", error.from)
+            .arg("to", error.to)
+    }
+}
@@ -0,0 +1,11 @@
+use strum_macros::{Display, EnumIter};
+
+/// Права доступа к атрибутам пользователя, которые создаются
+/// в сервисе-владельце пространства атрибутов при его синхронизации
+#[derive(Display, EnumIter)]
+pub enum AttributePermission {
+    GetUserAttributes,
+    GetUserAttributesSelf,
+    UpdateUserAttributes,
+    UpdateUserAttributesSelf,
+}
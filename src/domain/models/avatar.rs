@@ -0,0 +1,22 @@
+use strum_macros::{Display, EnumIter};
+use uuid::Uuid;
+
+pub type AvatarId = Uuid;
+
+#[derive(Debug, Clone, Copy, Display, EnumIter)]
+#[strum(serialize_all = "lowercase")]
+pub enum AvatarSize {
+    Small,
+    Medium,
+    Large,
+}
+
+impl AvatarSize {
+    pub fn pixels(&self) -> u32 {
+        match self {
+            AvatarSize::Small => 64,
+            AvatarSize::Medium => 128,
+            AvatarSize::Large => 256,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::user::{UserId, UserState};
+
+pub type BanId = Uuid;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Ban {
+    pub id: BanId,
+    pub user_id: UserId,
+    pub banned_by: Option<UserId>,
+    pub reason: String,
+
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// Состояние пользователя до бана, None - бан создан до появления поля
+    pub previous_state: Option<UserState>,
+}
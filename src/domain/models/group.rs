@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub type GroupId = Uuid;
+
+/// Группа пользователей: роли группы действуют для всех ее участников
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Group {
+    pub id: GroupId,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
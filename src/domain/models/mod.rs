@@ -4,4 +4,12 @@ pub mod permission;
 pub mod role;
 pub mod ums_permission;
 pub mod service;
-pub mod access_log;
\ No newline at end of file
+pub mod access_log;
+pub mod ban;
+pub mod attribute_permission;
+pub mod avatar;
+pub mod password_policy;
+pub mod normalization;
+pub mod resource_scope;
+pub mod organization;
+pub mod group;
\ No newline at end of file
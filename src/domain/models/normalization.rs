@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+/// **EmailProviderRule** - особенности адресов почтового провайдера
+///
+/// Например, для gmail.com точки в имени и часть после `+` не значимы.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailProviderRule {
+    /// Домены провайдера в ASCII-форме
+    pub domains: Vec<String>,
+    /// Точки в локальной части адреса не учитываются
+    #[serde(default)]
+    pub ignore_dots: bool,
+    /// Часть локальной части после `+` отбрасывается
+    #[serde(default)]
+    pub strip_tag: bool,
+    /// Домен, к которому приводятся все домены провайдера
+    pub canonical_domain: Option<String>,
+}
+
+/// **Normalization** - правила канонизации имен пользователей и email
+///
+/// Задается в конфигурации сервиса, по умолчанию правила провайдеров не применяются.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Normalization {
+    pub email_providers: Vec<EmailProviderRule>,
+}
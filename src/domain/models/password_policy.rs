@@ -0,0 +1,47 @@
+use serde::Deserialize;
+
+/// **PasswordPolicy** - политика паролей
+///
+/// Задается в конфигурации сервиса, отсутствующие поля 
+/// принимают значения по умолчанию.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    /// Хотя бы одна буква в любом регистре, чтобы не проходили пароли из одних цифр
+    pub require_letter: bool,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    pub allow_whitespace: bool,
+    /// Минимальная оценка стойкости zxcvbn от 0 до 4, 0 - проверка отключена
+    pub min_strength: u8,
+    /// Запрет на вхождение имени пользователя и email в пароль
+    pub forbid_personal_data: bool,
+    /// Количество последних паролей, включая текущий, которые нельзя использовать повторно,
+    /// 0 - без ограничения
+    pub history_size: usize,
+    /// Максимальный срок действия пароля в днях, 0 - без ограничения
+    pub max_age_days: u32,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            max_length: 128,
+            require_letter: true,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: true,
+            require_special: false,
+            allow_whitespace: false,
+            min_strength: 0,
+            forbid_personal_data: true,
+            history_size: 5,
+            max_age_days: 0,
+        }
+    }
+}
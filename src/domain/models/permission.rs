@@ -20,3 +20,12 @@ pub struct Permission {
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
+
+/// Действие личной записи разрешения пользователя: выдача или явный запрет,
+/// который перекрывает выдачу через любую роль
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionEffect {
+    Allow,
+    Deny,
+}
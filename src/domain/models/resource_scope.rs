@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::service::ServiceTextId;
+
+/// Область действия назначения роли: тип ресурса и его идентификатор
+/// или glob-шаблон идентификаторов (например, `project` и `42` или `42-*`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceScope {
+    pub resource_type: String,
+    pub resource_id: String,
+}
+
+/// Разрешения, действующие только в пределах областей:
+/// сервис -> разрешение -> области
+pub type PermissionScopes = HashMap<ServiceTextId, HashMap<PermissionTextId, Vec<ResourceScope>>>;
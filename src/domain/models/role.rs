@@ -2,6 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::models::resource_scope::ResourceScope;
+use crate::domain::models::user::UserId;
+
 pub type RoleId = Uuid;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -11,4 +14,17 @@ pub struct Role {
     pub description: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
-}
\ No newline at end of file
+    /// Родительская роль, все ее разрешения наследуются
+    pub parent_id: Option<RoleId>,
+}
+/// Назначение роли пользователю
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RoleUserLink {
+    pub role_id: RoleId,
+    pub user_id: UserId,
+    /// Пустой список - роль действует глобально
+    pub scopes: Vec<ResourceScope>,
+    /// Начало и конец действия назначения, None - без ограничения
+    pub starts_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
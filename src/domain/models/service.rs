@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use uuid::Uuid;
 
 pub type ServiceId = Uuid;
@@ -12,6 +13,7 @@ pub struct Service {
     pub text_id: ServiceTextId,
     pub title: String,
     pub description: Option<String>,
+    pub attribute_schema: Option<Value>,
     
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
@@ -22,4 +22,8 @@ pub struct Session {
     
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    
+    /// Сессия, в которой доступна только смена пароля
+    #[serde(default)]
+    pub restricted: bool,
 }
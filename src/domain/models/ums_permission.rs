@@ -5,11 +5,20 @@ pub enum UMSPermission {
     GetUserSelf,
     GetUser,
     CreateUser,
+    ImportUsers,
+    InviteUser,
     UpdateUser,
     UpdateUserSelf,
+    ChangePasswordSelf,
+    ExportUserSelf,
+    ChangeEmailSelf,
     DeleteUser,
+    BanUser,
+    GetUserBan,
     ConfirmUser,
     ResetUserPassword,
+    RevertEmailChange,
+    AcceptInvitation,
 
     SendConfirmCode,
 
@@ -42,4 +51,15 @@ pub enum UMSPermission {
     
     GetService,
     UpdateService,
+    
+    GetOrganization,
+    CreateOrganization,
+    ManageOrganizationUsers,
+    
+    GetGroup,
+    CreateGroup,
+    UpdateGroup,
+    DeleteGroup,
+    ManageGroupUsers,
+    LinkGroupRole,
 }
@@ -1,8 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use strum_macros::{Display, EnumString};
 use uuid::Uuid;
 
+use crate::domain::models::avatar::AvatarId;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, EnumString, Display)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
@@ -13,8 +16,31 @@ pub enum UserState {
     Deleted,
 }
 
+/// **UserStateEffect** - побочное действие перехода пользователя в другое состояние
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserStateEffect {
+    /// Завершение всех сессий пользователя
+    RevokeSessions,
+    /// Удаление истории паролей
+    ClearPasswordHistory,
+    /// Письмо пользователю о новом состоянии аккаунта
+    Notify,
+}
+
 pub type UserId = Uuid;
 
+/// Языки, для которых есть каталоги переводов и шаблоны писем
+pub const SUPPORTED_LOCALES: [&str; 2] = ["ru", "en"];
+pub const DEFAULT_LOCALE: &str = "ru";
+pub const DEFAULT_TIMEZONE: &str = "UTC";
+
+/// Хеш, с которым не совпадает ни один пароль. Ставится пользователям
+/// без пароля (импорт, приглашение), пароль они задают через сброс или приглашение
+pub const UNUSABLE_PASSWORD_HASH: &str = "!";
+
+/// Атрибуты пользователя, сгруппированные по text_id сервиса
+pub type UserAttributes = Value;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
     pub id: UserId,
@@ -26,4 +52,16 @@ pub struct User {
     pub hashed_password: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    pub attributes: UserAttributes,
+    pub avatar_id: Option<AvatarId>,
+    pub password_changed_at: DateTime<Utc>,
+    /// Пароль должен быть изменен при следующем входе
+    pub must_change_password: bool,
+    /// Номер телефона в формате E.164
+    pub phone: Option<String>,
+    pub phone_verified: bool,
+    /// Язык писем и сообщений
+    pub locale: String,
+    /// Часовой пояс IANA, например Europe/Moscow
+    pub timezone: String,
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
+use std::collections::HashMap;
+
 use uuid::Uuid;
 
 use crate::domain::exceptions::DomainError;
-use crate::domain::models::permission::PermissionTextId;
+use crate::domain::models::attribute_permission::AttributePermission;
+use crate::domain::models::permission::{PermissionId, PermissionTextId};
+use crate::domain::models::service::ServiceTextId;
 use crate::domain::models::session::SessionId;
 use crate::domain::models::ums_permission::UMSPermission;
 use crate::domain::models::user::UserState;
@@ -20,6 +24,48 @@ impl AccessService {
         Ok(())
     }
 
+    pub fn ensure_can_import_users(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::ImportUsers.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_invite_user(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::InviteUser.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
     pub fn ensure_can_get_user_self(
         &self,
         is_auth: &bool,
@@ -128,6 +174,48 @@ impl AccessService {
         Err(DomainError::AccessDenied)
     }
 
+    pub fn ensure_can_ban_user(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::BanUser.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_get_user_bans(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::GetUserBan.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
     pub fn ensure_can_update_user_self(
         &self,
         is_auth: &bool,
@@ -149,6 +237,104 @@ impl AccessService {
         Err(DomainError::AccessDenied)
     }
 
+    pub fn ensure_can_change_password_self(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if
+            user_state.unwrap() == &UserState::Active && (
+                permissions.contains(&UMSPermission::UpdateUserSelf.to_string()) ||
+                permissions.contains(&UMSPermission::ChangePasswordSelf.to_string())
+            )
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+
+    /// **restricted_session_permissions** - права сессии, ограниченной сменой пароля
+    ///
+    /// Права ролей пользователя не выдаются ни в одном сервисе до смены пароля.
+    pub fn restricted_session_permissions(
+        &self,
+        ums_service_text_id: &ServiceTextId
+    ) -> HashMap<ServiceTextId, Vec<PermissionTextId>> {
+        HashMap::from([(
+            ums_service_text_id.clone(),
+            vec![UMSPermission::ChangePasswordSelf.to_string()]
+        )])
+    }
+
+    pub fn ensure_can_export_user_self(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::ExportUserSelf.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_change_email_self(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::ChangeEmailSelf.to_string())
+        {
+            return Ok(())
+        }
+
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_revert_email_change(
+        &self,
+        permissions: &[PermissionTextId]
+    ) -> Result<(), DomainError> {
+        if !permissions.contains(&UMSPermission::RevertEmailChange.to_string()) {
+            return Err(DomainError::AccessDenied)
+        }
+        Ok(())
+    }
+
+    pub fn ensure_can_accept_invitation(
+        &self,
+        permissions: &[PermissionTextId]
+    ) -> Result<(), DomainError> {
+        if !permissions.contains(&UMSPermission::AcceptInvitation.to_string()) {
+            return Err(DomainError::AccessDenied)
+        }
+        Ok(())
+    }
+
     pub fn ensure_can_reset_password(
         &self,
         is_auth: &bool,
@@ -425,6 +611,265 @@ impl AccessService {
         Err(DomainError::AccessDenied)
     }
     
+    pub fn ensure_can_get_organization(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::GetOrganization.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_create_organization(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::CreateOrganization.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    /// Управлять пользователями организации может обладатель разрешения глобально
+    /// или администратор организации, получивший его через роль в этой организации
+    pub fn ensure_can_manage_organization_users(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String],
+        organization_permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        let permission = UMSPermission::ManageOrganizationUsers.to_string();
+        if 
+            user_state.unwrap() == &UserState::Active && (
+                permissions.contains(&permission) ||
+                organization_permissions.contains(&permission)
+            )
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    /// **ensure_can_link_organization_role** - назначение роли внутри организации
+    ///
+    /// Глобальное право назначать роли позволяет выдать любую роль. Администратор
+    /// организации может выдать только роль, все разрешения которой (с учетом
+    /// родительских ролей) он сам имеет в этой организации.
+    pub fn ensure_can_link_organization_role(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String],
+        actor_organization_permission_ids: &[PermissionId],
+        role_permission_ids: &[PermissionId]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if user_state.unwrap() != &UserState::Active {
+            return Err(DomainError::AccessDenied)
+        }
+        
+        if 
+            permissions.contains(&UMSPermission::LinkUserRole.to_string()) ||
+            role_permission_ids.iter().all(|id| actor_organization_permission_ids.contains(id))
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_get_group(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::GetGroup.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_create_group(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::CreateGroup.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_update_group(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::UpdateGroup.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_delete_group(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::DeleteGroup.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_manage_group_users(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::ManageGroupUsers.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    pub fn ensure_can_link_group_role(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if 
+            user_state.unwrap() == &UserState::Active &&
+            permissions.contains(&UMSPermission::LinkGroupRole.to_string())
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
+    /// **ensure_can_grant_group_roles** - выдача ролей группы
+    ///
+    /// Участник группы получает все ее роли, поэтому добавить участника или роль
+    /// может только тот, у кого есть глобальное право назначать роли или все
+    /// разрешения ролей группы (с учетом родительских ролей).
+    pub fn ensure_can_grant_group_roles(
+        &self,
+        is_auth: &bool,
+        user_state: Option<&UserState>,
+        permissions: &[String],
+        actor_permission_ids: &[PermissionId],
+        group_permission_ids: &[PermissionId]
+    ) -> Result<(), DomainError> {
+        
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+        
+        if user_state.unwrap() != &UserState::Active {
+            return Err(DomainError::AccessDenied)
+        }
+        
+        if 
+            permissions.contains(&UMSPermission::LinkUserRole.to_string()) ||
+            group_permission_ids.iter().all(|id| actor_permission_ids.contains(id))
+        {
+            return Ok(())
+        }
+        
+        Err(DomainError::AccessDenied)
+    }
+    
     pub fn ensure_can_link_role_user(
         &self,
         is_auth: &bool,
@@ -567,4 +1012,104 @@ impl AccessService {
 
         Err(DomainError::AccessDenied)
     }
+
+    pub fn ensure_can_get_user_attributes(
+        &self,
+        is_auth: &bool,
+        user_id: Option<&Uuid>,
+        get_user_id: &Uuid,
+        user_state: Option<&UserState>,
+        namespace_permissions: Option<&Vec<String>>
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        let permissions = match namespace_permissions {
+            Some(permissions) => permissions,
+            None => return Err(DomainError::AccessDenied)
+        };
+
+        if user_state.unwrap() != &UserState::Active {
+            return Err(DomainError::AccessDenied)
+        }
+
+        if permissions.contains(&AttributePermission::GetUserAttributes.to_string()) {
+            return Ok(())
+        }
+
+        if
+            permissions.contains(&AttributePermission::GetUserAttributesSelf.to_string()) &&
+            user_id.unwrap() == get_user_id
+        {
+            return Ok(())
+        }
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_update_user_attributes(
+        &self,
+        is_auth: &bool,
+        user_id: Option<&Uuid>,
+        update_user_id: &Uuid,
+        user_state: Option<&UserState>,
+        namespace_permissions: Option<&Vec<String>>
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        let permissions = match namespace_permissions {
+            Some(permissions) => permissions,
+            None => return Err(DomainError::AccessDenied)
+        };
+
+        if user_state.unwrap() != &UserState::Active {
+            return Err(DomainError::AccessDenied)
+        }
+
+        if permissions.contains(&AttributePermission::UpdateUserAttributes.to_string()) {
+            return Ok(())
+        }
+
+        if
+            permissions.contains(&AttributePermission::UpdateUserAttributesSelf.to_string()) &&
+            user_id.unwrap() == update_user_id
+        {
+            return Ok(())
+        }
+        Err(DomainError::AccessDenied)
+    }
+
+    pub fn ensure_can_update_user_avatar(
+        &self,
+        is_auth: &bool,
+        user_id: Option<&Uuid>,
+        update_user_id: &Uuid,
+        user_state: Option<&UserState>,
+        permissions: &[String]
+    ) -> Result<(), DomainError> {
+
+        if !is_auth {
+            return Err(DomainError::AuthorizationRequired)
+        }
+
+        if user_state.unwrap() != &UserState::Active {
+            return Err(DomainError::AccessDenied)
+        }
+
+        if permissions.contains(&UMSPermission::UpdateUser.to_string()) {
+            return Ok(())
+        }
+
+        if
+            permissions.contains(&UMSPermission::UpdateUserSelf.to_string()) &&
+            user_id.unwrap() == update_user_id
+        {
+            return Ok(())
+        }
+        Err(DomainError::AccessDenied)
+    }
 }
@@ -0,0 +1,39 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::avatar::{AvatarId, AvatarSize};
+use crate::domain::models::user::{User, UserId};
+
+pub struct AvatarService {}
+
+impl AvatarService {
+    
+    pub fn create_avatar_id(&self) -> AvatarId {
+        Uuid::new_v4()
+    }
+    
+    pub fn object_key(
+        &self,
+        user_id: &UserId,
+        avatar_id: &AvatarId,
+        size: &AvatarSize,
+    ) -> String {
+        format!("avatars/{}/{}/{}.webp", user_id, avatar_id, size)
+    }
+    
+    pub fn content_type(&self) -> &str {
+        "image/webp"
+    }
+
+    pub fn set_avatar(
+        &self,
+        user: User,
+        avatar_id: Option<AvatarId>,
+    ) -> User {
+        User {
+            avatar_id,
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::ban::{Ban, BanId};
+use crate::domain::models::user::{UserId, UserState};
+
+pub struct BanService {}
+
+impl BanService {
+    pub fn create_ban(
+        &self,
+        user_id: UserId,
+        banned_by: UserId,
+        reason: String,
+        expires_at: Option<DateTime<Utc>>,
+        previous_state: UserState,
+    ) -> Ban {
+        Ban {
+            id: BanId::new_v4(),
+            user_id,
+            banned_by: Some(banned_by),
+            reason,
+            expires_at,
+            revoked_at: None,
+            created_at: Utc::now(),
+            previous_state: Some(previous_state),
+        }
+    }
+
+    pub fn revoke_ban(
+        &self,
+        ban: Ban,
+    ) -> Ban {
+        Ban {
+            revoked_at: Some(Utc::now()),
+            ..ban
+        }
+    }
+
+    /// **restored_state** - состояние пользователя после снятия бана
+    ///
+    /// Пользователь возвращается в состояние до бана, так что неподтвержденный
+    /// аккаунт остается неподтвержденным. Для банов без сохраненного состояния - Active.
+    pub fn restored_state(
+        &self,
+        ban: Option<&Ban>,
+    ) -> UserState {
+        match ban.and_then(|ban| ban.previous_state.clone()) {
+            Some(UserState::Inactive) => UserState::Inactive,
+            _ => UserState::Active
+        }
+    }
+
+    /// Бан без даты окончания бессрочный
+    pub fn is_expired(
+        &self,
+        ban: &Ban,
+    ) -> bool {
+        match ban.expires_at {
+            Some(expires_at) => expires_at <= Utc::now(),
+            None => false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restored_state() {
+        let service = BanService {};
+        let user_id = UserId::new_v4();
+
+        let ban = service.create_ban(user_id, user_id, "spam".to_string(), None, UserState::Inactive);
+        assert_eq!(service.restored_state(Some(&ban)), UserState::Inactive);
+
+        let ban = service.create_ban(user_id, user_id, "spam".to_string(), None, UserState::Active);
+        assert_eq!(service.restored_state(Some(&ban)), UserState::Active);
+
+        let legacy_ban = Ban { previous_state: None, ..ban };
+        assert_eq!(service.restored_state(Some(&legacy_ban)), UserState::Active);
+        assert_eq!(service.restored_state(None), UserState::Active);
+    }
+}
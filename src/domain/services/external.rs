@@ -1,4 +1,5 @@
 use chrono::Utc;
+use serde_json::Value;
 use uuid::Uuid;
 
 use crate::domain::models::service::{Service, ServiceTextId};
@@ -17,6 +18,7 @@ impl ExternalService {
             text_id,
             title,
             description,
+            attribute_schema: None,
             created_at: Utc::now(),
             updated_at: None,
         }
@@ -35,4 +37,16 @@ impl ExternalService {
             ..service
         }
     }
+
+    pub fn update_service_attribute_schema(
+        &self,
+        service: Service,
+        new_attribute_schema: Option<Value>,
+    ) -> Service {
+        Service {
+            attribute_schema: new_attribute_schema,
+            updated_at: Some(Utc::now()),
+            ..service
+        }
+    }
 }
@@ -0,0 +1,37 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::group::Group;
+
+pub struct GroupService { }
+
+impl GroupService {
+
+    pub fn create_group(
+        &self,
+        title: String,
+        description: Option<String>,
+    ) -> Group {
+        Group {
+            id: Uuid::new_v4(),
+            title,
+            description,
+            created_at: Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    pub fn update_group(
+        &self,
+        group: Group,
+        new_title: String,
+        new_description: Option<String>,
+    ) -> Group {
+        Group {
+            title: new_title,
+            description: new_description,
+            updated_at: Some(Utc::now()),
+            ..group
+        }
+    }
+}
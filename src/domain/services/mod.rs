@@ -5,4 +5,9 @@ pub mod session;
 pub mod role;
 pub mod access_log;
 pub mod permission;
-pub mod external;
\ No newline at end of file
+pub mod external;
+pub mod ban;
+pub mod avatar;
+pub mod normalizer;
+pub mod organization;
+pub mod group;
\ No newline at end of file
@@ -0,0 +1,143 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::domain::models::normalization::Normalization;
+
+/// **NormalizerService** - приведение имен пользователей и email к единому виду
+///
+/// Нормализованная форма (NFKC, домен в punycode) сохраняется и показывается пользователю.
+/// Каноническая форма дополнительно не зависит от регистра и учитывает правила
+/// почтовых провайдеров, по ней проверяется уникальность и выполняется поиск.
+#[derive(Debug, Clone)]
+pub struct NormalizerService {
+    rules: Normalization,
+}
+
+impl NormalizerService {
+    pub fn new(rules: Normalization) -> Self {
+        Self { rules }
+    }
+
+    pub fn normalize_username(&self, username: &str) -> String {
+        username.trim().nfkc().collect()
+    }
+
+    pub fn normalize_email(&self, email: &str) -> String {
+        let email: String = email.trim().nfkc().collect();
+        match email.rsplit_once('@') {
+            Some((local, domain)) => format!("{}@{}", local, domain_to_ascii(domain)),
+            None => email
+        }
+    }
+
+    pub fn canonical_username(&self, username: &str) -> String {
+        fold_case(&self.normalize_username(username))
+    }
+
+    pub fn canonical_email(&self, email: &str) -> String {
+        let email = self.normalize_email(email);
+        let (local, domain) = match email.rsplit_once('@') {
+            Some(parts) => parts,
+            None => return fold_case(&email)
+        };
+
+        let mut local = fold_case(local);
+        let mut domain = domain.to_string();
+        let rule = self.rules.email_providers.iter().find(
+            |rule| rule.domains.iter().any(|provider| provider.eq_ignore_ascii_case(&domain))
+        );
+        if let Some(rule) = rule {
+            if rule.strip_tag {
+                if let Some((name, _)) = local.split_once('+') {
+                    local = name.to_string();
+                }
+            }
+            if rule.ignore_dots {
+                local = local.replace('.', "");
+            }
+            if let Some(canonical_domain) = &rule.canonical_domain {
+                domain = canonical_domain.to_lowercase();
+            }
+        }
+        format!("{}@{}", local, domain)
+    }
+}
+
+/// Домен в ASCII-форме (punycode) в нижнем регистре, некорректный домен
+/// только приводится к нижнему регистру и будет отклонен валидатором
+fn domain_to_ascii(domain: &str) -> String {
+    idna::domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+}
+
+/// Сравнение без учета регистра: нижний регистр и повторная NFKC, аналог NFKC_Casefold
+fn fold_case(value: &str) -> String {
+    value.to_lowercase().nfkc().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::normalization::EmailProviderRule;
+
+    use super::*;
+
+    fn gmail_rules() -> Normalization {
+        Normalization {
+            email_providers: vec![EmailProviderRule {
+                domains: vec!["gmail.com".to_string(), "googlemail.com".to_string()],
+                ignore_dots: true,
+                strip_tag: true,
+                canonical_domain: Some("gmail.com".to_string()),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_normalize_username() {
+        let normalizer = NormalizerService::new(Normalization::default());
+
+        assert_eq!(normalizer.normalize_username("  Ivan  "), "Ivan");
+        // NFKC: полноширинные символы и лигатуры
+        assert_eq!(normalizer.normalize_username("Ｉｖａｎ"), "Ivan");
+        assert_eq!(normalizer.normalize_username("ﬁlm"), "film");
+        // Составной и предкомпонованный символ дают одну форму
+        assert_eq!(normalizer.normalize_username("e\u{301}"), "\u{e9}");
+    }
+
+    #[test]
+    fn test_canonical_username() {
+        let normalizer = NormalizerService::new(Normalization::default());
+
+        assert_eq!(normalizer.canonical_username("Ivan"), "ivan");
+        assert_eq!(normalizer.canonical_username("ＩＶＡＮ"), "ivan");
+        assert_eq!(normalizer.canonical_username("ИВАН"), "иван");
+        assert_eq!(normalizer.canonical_username("Ivan"), normalizer.canonical_username("iVAN"));
+    }
+
+    #[test]
+    fn test_normalize_email() {
+        let normalizer = NormalizerService::new(Normalization::default());
+
+        // Регистр локальной части сохраняется, домен переводится в punycode
+        assert_eq!(normalizer.normalize_email(" Ivan@Example.COM "), "Ivan@example.com");
+        assert_eq!(normalizer.normalize_email("ivan@пример.рф"), "ivan@xn--e1afmkfd.xn--p1ai");
+        assert_eq!(normalizer.normalize_email("ivan@ПРИМЕР.РФ"), "ivan@xn--e1afmkfd.xn--p1ai");
+        assert_eq!(normalizer.normalize_email("not-an-email"), "not-an-email");
+    }
+
+    #[test]
+    fn test_canonical_email() {
+        let normalizer = NormalizerService::new(Normalization::default());
+
+        assert_eq!(normalizer.canonical_email("Ivan.Petrov+news@Gmail.com"), "ivan.petrov+news@gmail.com");
+        assert_eq!(normalizer.canonical_email("NOT-AN-EMAIL"), "not-an-email");
+    }
+
+    #[test]
+    fn test_canonical_email_provider_rules() {
+        let normalizer = NormalizerService::new(gmail_rules());
+
+        assert_eq!(normalizer.canonical_email("Ivan.Petrov+news@Gmail.com"), "ivanpetrov@gmail.com");
+        assert_eq!(normalizer.canonical_email("ivan.petrov@googlemail.com"), "ivanpetrov@gmail.com");
+        // Правила других провайдеров не затрагивают
+        assert_eq!(normalizer.canonical_email("ivan.petrov+news@example.com"), "ivan.petrov+news@example.com");
+    }
+}
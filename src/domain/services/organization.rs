@@ -0,0 +1,23 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::models::organization::Organization;
+
+pub struct OrganizationService { }
+
+impl OrganizationService {
+
+    pub fn create_organization(
+        &self,
+        title: String,
+        description: Option<String>,
+    ) -> Organization {
+        Organization {
+            id: Uuid::new_v4(),
+            title,
+            description,
+            created_at: Utc::now(),
+            updated_at: None,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use uuid::Uuid;
 
-use crate::domain::exceptions::DomainError;
-use crate::domain::models::role::Role;
+use crate::domain::exceptions::{DomainError, Message};
+use crate::domain::models::role::{Role, RoleId};
 
 pub struct RoleService { }
 
@@ -11,6 +11,7 @@ impl RoleService {
         &self,
         title: String,
         description: Option<String>,
+        parent_id: Option<RoleId>,
     ) -> Role {
         Role {
             id: Uuid::new_v4(),
@@ -18,6 +19,7 @@ impl RoleService {
             description,
             created_at: Default::default(),
             updated_at: None,
+            parent_id,
         }
     }
 
@@ -26,12 +28,27 @@ impl RoleService {
         role: Role,
         new_title: String,
         new_description: Option<String>,
+        new_parent_id: Option<RoleId>,
     ) -> Result<Role, DomainError> {
         Ok(Role {
             title: new_title,
             description: new_description,
+            parent_id: new_parent_id,
             updated_at: Some(chrono::Utc::now()),
             ..role
         })
     }
+    /// **ensure_no_parent_cycle** - проверка, что роль не окажется своим же предком
+    ///
+    /// `parent_chain` - новая родительская роль и все ее предки.
+    pub fn ensure_no_parent_cycle(
+        &self,
+        role_id: &RoleId,
+        parent_chain: &[RoleId],
+    ) -> Result<(), Message> {
+        if parent_chain.contains(role_id) {
+            return Err(Message::new("role_parent_cycle"))
+        }
+        Ok(())
+    }
 }
@@ -43,6 +43,7 @@ impl SessionService {
             device,
             created_at: chrono::Utc::now(),
             updated_at: None,
+            restricted: false,
         }
     }
 
@@ -56,6 +57,20 @@ impl SessionService {
         session.client == client && session.os == os && session.device == device
     }
 
+    pub fn restrict(&self, session: Session) -> Session {
+        Session {
+            restricted: true,
+            ..session
+        }
+    }
+
+    pub fn lift_restriction(&self, session: Session) -> Session {
+        Session {
+            restricted: false,
+            ..session
+        }
+    }
+
     pub fn update_session(
         &self,
         session: Session,
@@ -1,8 +1,15 @@
-use chrono::Utc;
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use crate::application::common::exceptions::ApplicationError;
-use crate::domain::models::user::{User, UserState};
+use crate::domain::exceptions::StateTransitionError;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::user::{DEFAULT_LOCALE, DEFAULT_TIMEZONE, User, UserState, UserStateEffect};
 
 pub struct UserService { }
 
@@ -27,6 +34,14 @@ impl UserService {
             hashed_password,
             created_at: Utc::now(),
             updated_at: None,
+            attributes: Value::Object(Map::new()),
+            avatar_id: None,
+            password_changed_at: Utc::now(),
+            must_change_password: false,
+            phone: None,
+            phone_verified: false,
+            locale: DEFAULT_LOCALE.to_string(),
+            timezone: DEFAULT_TIMEZONE.to_string(),
         }
     }
 
@@ -35,15 +50,19 @@ impl UserService {
         user: User,
         new_username: String,
         new_email: String,
-        new_state: UserState,
         new_first_name: Option<String>,
         new_last_name: Option<String>,
         new_hashed_password: String,
     ) -> User {
+        let is_password_changed = new_hashed_password != user.hashed_password;
         User {
+            password_changed_at: match is_password_changed {
+                true => Utc::now(),
+                false => user.password_changed_at
+            },
+            must_change_password: user.must_change_password && !is_password_changed,
             username: new_username,
             email: new_email,
-            state: new_state,
             first_name: new_first_name,
             last_name: new_last_name,
             updated_at: Some(Utc::now()),
@@ -52,6 +71,137 @@ impl UserService {
         }
     }
 
+    /// **change_state** - переход пользователя в другое состояние
+    ///
+    /// Повтор текущего состояния ничего не меняет. Побочные действия перехода
+    /// возвращаются вызывающему и выполняются после сохранения пользователя.
+    pub fn change_state(
+        &self,
+        user: User,
+        new_state: UserState,
+    ) -> Result<(User, Vec<UserStateEffect>), StateTransitionError> {
+        if user.state == new_state {
+            return Ok((user, vec![]))
+        }
+        
+        let effects = state_transition_effects(&user.state, &new_state).ok_or_else(
+            || StateTransitionError { from: user.state.clone(), to: new_state.clone() }
+        )?;
+        
+        Ok((
+            User {
+                state: new_state,
+                updated_at: Some(Utc::now()),
+                ..user
+            },
+            effects
+        ))
+    }
+
+    /// **rehash_password** - замена хеша того же пароля, дата смены пароля не меняется
+    pub fn rehash_password(
+        &self,
+        user: User,
+        new_hashed_password: String,
+    ) -> User {
+        User {
+            hashed_password: new_hashed_password,
+            ..user
+        }
+    }
+
+    pub fn set_must_change_password(
+        &self,
+        user: User,
+        must_change_password: bool,
+    ) -> User {
+        User {
+            must_change_password,
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+
+    /// **generate_password** - случайный пароль длиной `length` символов
+    ///
+    /// Содержит хотя бы две цифры и две заглавные буквы, чтобы проходить политику паролей.
+    pub fn generate_password(&self, length: usize) -> String {
+        let mut rng = rand::thread_rng();
+        let mut password: Vec<char> = (0..2)
+            .flat_map(|_| [rng.gen_range('0'..='9'), rng.gen_range('A'..='Z')])
+            .collect();
+        password.extend(
+            (&mut rng).sample_iter(&Alphanumeric)
+                .take(length.saturating_sub(password.len()))
+                .map(char::from)
+        );
+        password.shuffle(&mut rng);
+        password.into_iter().collect()
+    }
+
+    /// **is_password_change_required** - нужно ли сменить пароль перед работой
+    ///
+    /// `max_password_age_days` равный 0 отключает проверку срока действия пароля.
+    pub fn is_password_change_required(
+        &self,
+        user: &User,
+        max_password_age_days: u32,
+    ) -> bool {
+        user.must_change_password || (
+            max_password_age_days > 0 &&
+            Utc::now() - user.password_changed_at > Duration::days(max_password_age_days as i64)
+        )
+    }
+
+    /// **set_phone** - смена номера телефона, новый номер требует подтверждения
+    pub fn set_phone(
+        &self,
+        user: User,
+        phone: Option<String>,
+    ) -> User {
+        User {
+            phone,
+            phone_verified: false,
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+
+    pub fn verify_phone(
+        &self,
+        user: User,
+    ) -> User {
+        User {
+            phone_verified: true,
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+
+    pub fn set_preferences(
+        &self,
+        user: User,
+        locale: String,
+        timezone: String,
+    ) -> User {
+        User {
+            locale,
+            timezone,
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+
+    /// **format_local_time** - время в часовом поясе пользователя для писем
+    pub fn format_local_time(
+        &self,
+        user: &User,
+        time: DateTime<Utc>,
+    ) -> String {
+        let timezone = user.timezone.parse::<Tz>().unwrap_or(Tz::UTC);
+        time.with_timezone(&timezone).format("%d/%m/%Y %H:%M %Z").to_string()
+    }
+
     pub fn update_user_self(
         &self,
         user: User,
@@ -67,5 +217,142 @@ impl UserService {
             ..user
         })
     }
-    
+
+    pub fn get_attributes(
+        &self,
+        user: &User,
+        namespace: &ServiceTextId,
+    ) -> Value {
+        user.attributes.get(namespace).cloned().unwrap_or(Value::Object(Map::new()))
+    }
+
+    pub fn update_attributes(
+        &self,
+        user: User,
+        namespace: ServiceTextId,
+        new_attributes: Value,
+    ) -> User {
+        let mut attributes = match user.attributes {
+            Value::Object(ref attributes) => attributes.clone(),
+            _ => Map::new()
+        };
+        attributes.insert(namespace, new_attributes);
+        
+        User {
+            attributes: Value::Object(attributes),
+            updated_at: Some(Utc::now()),
+            ..user
+        }
+    }
+}
+
+/// Жизненный цикл пользователя: разрешенные переходы и их побочные действия
+///
+/// Inactive → Active - подтверждение почты или приглашения,
+/// Active → Inactive - приостановка аккаунта,
+/// Active, Inactive → Banned и Banned → Active, Inactive - бан и возврат
+/// в состояние до бана,
+/// любое состояние → Deleted, из Deleted выхода нет.
+fn state_transition_effects(from: &UserState, to: &UserState) -> Option<Vec<UserStateEffect>> {
+    match (from, to) {
+        (UserState::Inactive, UserState::Active) => Some(vec![]),
+        (UserState::Active, UserState::Inactive) => Some(vec![UserStateEffect::RevokeSessions]),
+        (UserState::Active | UserState::Inactive, UserState::Banned) => Some(vec![
+            UserStateEffect::RevokeSessions,
+            UserStateEffect::Notify
+        ]),
+        (UserState::Banned, UserState::Active) => Some(vec![UserStateEffect::Notify]),
+        (UserState::Banned, UserState::Inactive) => Some(vec![]),
+        (_, UserState::Deleted) => Some(vec![
+            UserStateEffect::RevokeSessions,
+            UserStateEffect::ClearPasswordHistory,
+            UserStateEffect::Notify
+        ]),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_in_state(state: UserState) -> User {
+        UserService {}.create_user(
+            "test".to_string(),
+            "test@example.com".to_string(),
+            state,
+            "hash".to_string(),
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_allowed_transitions() {
+        assert_eq!(state_transition_effects(&UserState::Inactive, &UserState::Active), Some(vec![]));
+        assert_eq!(
+            state_transition_effects(&UserState::Active, &UserState::Inactive),
+            Some(vec![UserStateEffect::RevokeSessions])
+        );
+        for from in [UserState::Active, UserState::Inactive] {
+            assert_eq!(
+                state_transition_effects(&from, &UserState::Banned),
+                Some(vec![UserStateEffect::RevokeSessions, UserStateEffect::Notify])
+            );
+        }
+        assert_eq!(
+            state_transition_effects(&UserState::Banned, &UserState::Active),
+            Some(vec![UserStateEffect::Notify])
+        );
+        assert_eq!(state_transition_effects(&UserState::Banned, &UserState::Inactive), Some(vec![]));
+        for from in [UserState::Active, UserState::Inactive, UserState::Banned] {
+            assert_eq!(
+                state_transition_effects(&from, &UserState::Deleted),
+                Some(vec![
+                    UserStateEffect::RevokeSessions,
+                    UserStateEffect::ClearPasswordHistory,
+                    UserStateEffect::Notify
+                ])
+            );
+        }
+    }
+
+    #[test]
+    fn test_forbidden_transitions() {
+        for to in [UserState::Active, UserState::Inactive, UserState::Banned] {
+            assert_eq!(state_transition_effects(&UserState::Deleted, &to), None);
+        }
+    }
+
+    #[test]
+    fn test_change_state() {
+        let service = UserService {};
+
+        let (user, effects) = service.change_state(user_in_state(UserState::Active), UserState::Active).unwrap();
+        assert_eq!(user.state, UserState::Active);
+        assert!(effects.is_empty());
+        assert!(user.updated_at.is_none());
+
+        let (user, effects) = service.change_state(user, UserState::Banned).unwrap();
+        assert_eq!(user.state, UserState::Banned);
+        assert_eq!(effects, vec![UserStateEffect::RevokeSessions, UserStateEffect::Notify]);
+        assert!(user.updated_at.is_some());
+
+        let error = service.change_state(user_in_state(UserState::Deleted), UserState::Active).unwrap_err();
+        assert_eq!(error.from, UserState::Deleted);
+        assert_eq!(error.to, UserState::Active);
+    }
+
+    #[test]
+    fn test_generate_password() {
+        let service = UserService {};
+
+        for length in [4, 12, 32] {
+            let password = service.generate_password(length);
+            assert_eq!(password.chars().count(), length);
+            assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+            assert!(password.chars().filter(char::is_ascii_digit).count() >= 2);
+            assert!(password.chars().filter(char::is_ascii_uppercase).count() >= 2);
+        }
+    }
 }
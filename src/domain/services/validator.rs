@@ -1,4 +1,13 @@
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use jsonschema::JSONSchema;
+use serde_json::Value;
+
+use crate::domain::exceptions::Message;
+use crate::domain::models::password_policy::PasswordPolicy;
+use crate::domain::models::resource_scope::ResourceScope;
 use crate::domain::models::session::SessionToken;
+use crate::domain::models::user::SUPPORTED_LOCALES;
 
 pub struct ValidatorService {
     firstname_max_length: usize,
@@ -10,14 +19,27 @@ pub struct ValidatorService {
     username_max_length: usize,
     username_min_length: usize,
     username_regex: regex::Regex,
-    password_max_length: usize,
-    password_min_length: usize,
+    password_policy: PasswordPolicy,
+    password_hash_regex: regex::Regex,
     email_max_length: usize,
     email_regex: regex::Regex,
+    phone_regex: regex::Regex,
     role_title_max_length: usize,
     role_title_min_length: usize,
     role_description_max_length: usize,
     role_description_min_length: usize,
+    organization_title_max_length: usize,
+    organization_title_min_length: usize,
+    organization_description_max_length: usize,
+    organization_description_min_length: usize,
+    group_title_max_length: usize,
+    group_title_min_length: usize,
+    group_description_max_length: usize,
+    group_description_min_length: usize,
+    resource_type_max_length: usize,
+    resource_type_regex: regex::Regex,
+    resource_id_max_length: usize,
+    resource_id_regex: regex::Regex,
     session_token_length: usize,
     permission_title_min_length: usize,
     permission_title_max_length: usize,
@@ -27,11 +49,16 @@ pub struct ValidatorService {
     service_title_max_length: usize,
     service_description_min_length: usize,
     service_description_max_length: usize,
+    ban_reason_min_length: usize,
+    ban_reason_max_length: usize,
+    attribute_errors_limit: usize,
+    avatar_max_size: usize,
+    avatar_content_types: Vec<String>,
 }
 
 impl ValidatorService {
 
-    pub fn new() -> Self {
+    pub fn new(password_policy: PasswordPolicy) -> Self {
         
         // User - - - - - - - - - - - - - - - - - - - - - - - - - - -
         
@@ -50,13 +77,20 @@ impl ValidatorService {
         let username_min_length = 4;
         let username_regex = regex::Regex::new(r"^[a-zA-Z0-9._]*$").unwrap();
 
-        // Password
-        let password_max_length = 32;
-        let password_min_length = 8;
+        // Password hash PHC
+        // PHC Argon2, bcrypt (modular crypt) или PBKDF2-SHA256 в формате Django
+        let password_hash_regex = regex::Regex::new(concat!(
+            r"^(\$argon2(id|i|d)\$v=\d+\$m=\d+,t=\d+,p=\d+\$[A-Za-z0-9+/]+\$[A-Za-z0-9+/]+",
+            r"|\$2[aby]\$\d{2}\$[./A-Za-z0-9]{53}",
+            r"|pbkdf2_sha256\$\d+\$[^$]+\$[A-Za-z0-9+/]+={0,2})$"
+        )).unwrap();
 
         // Email RFC2822
         let email_max_length = 255;
         let email_regex = regex::Regex::new(r"^([^\x00-\x20\x22\x28\x29\x2c\x2e\x3a-\x3c\x3e\x40\x5b-\x5d\x7f-\xff]+|\x22([^\x0d\x22\x5c\x80-\xff]|\x5c[\x00-\x7f])*\x22)(\x2e([^\x00-\x20\x22\x28\x29\x2c\x2e\x3a-\x3c\x3e\x40\x5b-\x5d\x7f-\xff]+|\x22([^\x0d\x22\x5c\x80-\xff]|\x5c[\x00-\x7f])*\x22))*\x40([^\x00-\x20\x22\x28\x29\x2c\x2e\x3a-\x3c\x3e\x40\x5b-\x5d\x7f-\xff]+|\x5b([^\x0d\x5b-\x5d\x80-\xff]|\x5c[\x00-\x7f])*\x5d)(\x2e([^\x00-\x20\x22\x28\x29\x2c\x2e\x3a-\x3c\x3e\x40\x5b-\x5d\x7f-\xff]+|\x5b([^\x0d\x5b-\x5d\x80-\xff]|\x5c[\x00-\x7f])*\x5d))*$").unwrap();
+
+        // Phone E.164
+        let phone_regex = regex::Regex::new(r"^\+[1-9]\d{7,14}$").unwrap();
         
         // Role - - - - - - - - - - - - - - - - - - - - - - - - - - -
         
@@ -66,6 +100,31 @@ impl ValidatorService {
         let role_description_max_length = 255;
         let role_description_min_length = 4;
         
+        // Organization
+        
+        let organization_title_max_length = 64;
+        let organization_title_min_length = 2;
+        
+        let organization_description_max_length = 255;
+        let organization_description_min_length = 4;
+        
+        // Group
+        
+        let group_title_max_length = 64;
+        let group_title_min_length = 2;
+        
+        let group_description_max_length = 255;
+        let group_description_min_length = 4;
+        
+        // Resource scope
+        
+        let resource_type_max_length = 64;
+        let resource_type_regex = regex::Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
+        
+        // Идентификатор ресурса или glob-шаблон
+        let resource_id_max_length = 255;
+        let resource_id_regex = regex::Regex::new(r"^[a-zA-Z0-9_\-.:/*?]+$").unwrap();
+        
         // Permission
         
         let permission_title_max_length = 64;
@@ -82,6 +141,24 @@ impl ValidatorService {
         let service_description_max_length = 255;
         let service_description_min_length = 4;
         
+        // Ban
+
+        let ban_reason_max_length = 512;
+        let ban_reason_min_length = 4;
+
+        // Attributes
+
+        let attribute_errors_limit = 8;
+        
+        // Avatar
+        
+        let avatar_max_size = 5 * 1024 * 1024;
+        let avatar_content_types = vec![
+            "image/png".to_string(),
+            "image/jpeg".to_string(),
+            "image/webp".to_string(),
+        ];
+
         // Session 
         
         let session_token_length = 128;
@@ -96,14 +173,27 @@ impl ValidatorService {
             username_max_length,
             username_min_length,
             username_regex,
-            password_max_length,
-            password_min_length,
+            password_policy,
+            password_hash_regex,
             email_max_length,
             email_regex,
+            phone_regex,
             role_title_max_length,
             role_title_min_length,
             role_description_max_length,
             role_description_min_length,
+            organization_title_max_length,
+            organization_title_min_length,
+            organization_description_max_length,
+            organization_description_min_length,
+            group_title_max_length,
+            group_title_min_length,
+            group_description_max_length,
+            group_description_min_length,
+            resource_type_max_length,
+            resource_type_regex,
+            resource_id_max_length,
+            resource_id_regex,
             session_token_length,
             permission_title_max_length,
             permission_title_min_length,
@@ -113,191 +203,555 @@ impl ValidatorService {
             service_title_min_length,
             service_description_max_length,
             service_description_min_length,
+            ban_reason_min_length,
+            ban_reason_max_length,
+            attribute_errors_limit,
+            avatar_max_size,
+            avatar_content_types,
         }
     }
 
 
-    pub fn validate_username(&self, username: &str) -> Result<(), String> {
+    pub fn validate_username(&self, username: &str) -> Result<(), Message> {
 
         if username.len() < self.username_min_length || username.len() > self.username_max_length {
             return Err(
-                format!(
-                    "Имя пользователя должно содержать от {} до {} символов", 
-                    self.username_min_length, 
-                    self.username_max_length
-                )
+                Message::new("username_length")
+                    .arg("min", self.username_min_length)
+                    .arg("max", self.username_max_length)
             );
         }
 
         if !self.username_regex.is_match(username) {
             return Err(
-                "Имя пользователя может содержать только буквы, \
-                цифры, точки и символы подчеркивания".to_string()
+                Message::new("username_format")
             );
         }
         Ok(())
     }
 
-    pub fn validate_email(&self, email: &str) -> Result<(), String> {
+    pub fn validate_email(&self, email: &str) -> Result<(), Message> {
 
         if email.len() > self.email_max_length {
             return Err(
-                format!("Email должен содержать максимум {} символов", self.email_max_length)
+                Message::new("email_length").arg("max", self.email_max_length)
             );
         }
 
         if !self.email_regex.is_match(email) {
-            return Err("Неверный формат email".to_string());
+            return Err(Message::new("email_format"));
         }
         Ok(())
     }
 
-    pub fn validate_password(&self, password: &str) -> Result<(), String> {
+    /// **normalize_phone** - приведение номера телефона к формату E.164
+    ///
+    /// Номер указывается с кодом страны, пробелы, дефисы, точки и скобки отбрасываются.
+    pub fn normalize_phone(&self, phone: &str) -> Result<String, Message> {
+        let phone: String = phone.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+            .collect();
 
-        if password.len() < self.password_min_length || password.len() > self.password_max_length {
-            return Err(
-                format!(
-                    "Пароль должен содержать от {} до {} символов", 
-                    self.password_min_length, 
-                    self.password_max_length
-                )
+        if !self.phone_regex.is_match(&phone) {
+            return Err(Message::new("phone_format"));
+        }
+        Ok(phone)
+    }
+
+    /// **validate_password** - проверка пароля по политике паролей
+    ///
+    /// `personal_data` - имя пользователя и email, которые не должны входить в пароль,
+    /// `is_breached` - результат проверки по списку утечек.
+    /// Несколько нарушений возвращаются вложенными сообщениями одной ошибки.
+    pub fn validate_password(
+        &self,
+        password: &str,
+        personal_data: &[&str],
+        is_breached: bool
+    ) -> Result<(), Message> {
+        let policy = &self.password_policy;
+        let mut errors: Vec<Message> = Vec::new();
+
+        let length = password.chars().count();
+        if length < policy.min_length || length > policy.max_length {
+            errors.push(
+                Message::new("password_length")
+                    .arg("min", policy.min_length)
+                    .arg("max", policy.max_length)
             );
         }
 
-        if !password.chars().any(char::is_numeric) {
-            return Err("Пароль должен содержать хотя бы одну цифру".to_string());
+        if policy.require_letter && !password.chars().any(char::is_alphabetic) {
+            errors.push(Message::new("password_letter"));
         }
 
-        if !password.chars().any(char::is_alphabetic) {
-            return Err("Пароль должен содержать хотя бы одну букву".to_string());
+        if policy.require_lowercase && !password.chars().any(char::is_lowercase) {
+            errors.push(Message::new("password_lowercase"));
         }
 
-        if password.chars().any(char::is_whitespace) {
-            return Err("Пароль не должен содержать пробелов".to_string());
+        if policy.require_uppercase && !password.chars().any(char::is_uppercase) {
+            errors.push(Message::new("password_uppercase"));
+        }
+
+        if policy.require_digit && !password.chars().any(char::is_numeric) {
+            errors.push(Message::new("password_digit"));
+        }
+
+        if policy.require_special && !password.chars().any(|c| !c.is_alphanumeric() && !c.is_whitespace()) {
+            errors.push(Message::new("password_special"));
+        }
+
+        if !policy.allow_whitespace && password.chars().any(char::is_whitespace) {
+            errors.push(Message::new("password_whitespace"));
+        }
+
+        // Из email проверяется только локальная часть
+        let personal_data: Vec<String> = personal_data.iter()
+            .map(|value| value.split('@').next().unwrap_or_default().to_lowercase())
+            .filter(|value| !value.is_empty())
+            .collect();
+
+        if policy.forbid_personal_data {
+            let lowercase_password = password.to_lowercase();
+            if personal_data.iter().any(|value| lowercase_password.contains(value.as_str())) {
+                errors.push(Message::new("password_personal_data"));
+            }
+        }
+
+        if policy.min_strength > 0 && length > 0 {
+            let user_inputs: Vec<&str> = personal_data.iter().map(String::as_str).collect();
+            let score = zxcvbn::zxcvbn(password, &user_inputs).map(|entropy| entropy.score()).unwrap_or(0);
+            if score < policy.min_strength {
+                errors.push(Message::new("password_weak"));
+            }
+        }
+
+        if is_breached {
+            errors.push(Message::new("password_breached"));
+        }
+
+        match errors.len() {
+            0 => (),
+            1 => return Err(errors.remove(0)),
+            _ => return Err(Message::new("password_policy").details(errors))
         }
 
         Ok(())
     }
 
-    pub fn validate_last_name(&self, last_name: &str) -> Result<(), String> {
+    /// **validate_login_password** - проверка пароля при входе
+    ///
+    /// Политика паролей при входе не применяется: пароль, заданный 
+    /// до ее изменения, должен оставаться рабочим.
+    pub fn validate_login_password(&self, password: &str) -> Result<(), Message> {
+        let length = password.chars().count();
+        if length == 0 || length > self.password_policy.max_length {
+            return Err(
+                Message::new("password_length")
+                    .arg("min", 1)
+                    .arg("max", self.password_policy.max_length)
+            );
+        }
+        Ok(())
+    }
+
+    pub fn validate_locale(&self, locale: &str) -> Result<(), Message> {
+        if !SUPPORTED_LOCALES.contains(&locale) {
+            return Err(
+                Message::new("locale_unsupported").arg("locales", SUPPORTED_LOCALES.join(", "))
+            );
+        }
+        Ok(())
+    }
+
+    pub fn validate_timezone(&self, timezone: &str) -> Result<(), Message> {
+        if timezone.parse::<Tz>().is_err() {
+            return Err(Message::new("timezone_invalid"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_password_hash(&self, password_hash: &str) -> Result<(), Message> {
+        if !self.password_hash_regex.is_match(password_hash) {
+            return Err(Message::new("password_hash_format"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_last_name(&self, last_name: &str) -> Result<(), Message> {
         if last_name.len() > self.lastname_max_length  || last_name.len() < self.lastname_min_length {
-            return Err(format!(
-                "Фамилия должна содержать от {} до {} символов", 
-                self.lastname_min_length, 
-                self.lastname_max_length
-            ));
+            return Err(
+                Message::new("last_name_length")
+                    .arg("min", self.lastname_min_length)
+                    .arg("max", self.lastname_max_length)
+            );
         }
 
         if !self.lastname_regex.is_match(last_name) {
-            return Err("Фамилия должна состоять из латинских или кириллических букв".to_string());
+            return Err(Message::new("last_name_format"));
         }
 
         Ok(())
     }
 
-    pub fn validate_first_name(&self, first_name: &str) -> Result<(), String> {
+    pub fn validate_first_name(&self, first_name: &str) -> Result<(), Message> {
         if first_name.len() > self.firstname_max_length || first_name.len() < self.firstname_min_length {
-            return Err(format!(
-                "Имя должно содержать от {} до {} символов", 
-                self.firstname_min_length, 
-                self.firstname_max_length
-            ));
+            return Err(
+                Message::new("first_name_length")
+                    .arg("min", self.firstname_min_length)
+                    .arg("max", self.firstname_max_length)
+            );
         }
 
         if !self.firstname_regex.is_match(first_name) {
-            return Err("Имя должно состоять из латинских или кириллических букв".to_string());
+            return Err(Message::new("first_name_format"));
         }
 
         Ok(())
     }
     
-    pub fn validate_role_title(&self, title: &str) -> Result<(), String> {
+    pub fn validate_role_title(&self, title: &str) -> Result<(), Message> {
         if title.len() < self.role_title_min_length || title.len() > self.role_title_max_length {
-            return Err(format!(
-                "Название роли должно содержать от {} до {} символов",
-                self.role_title_min_length,
-                self.role_title_max_length
-            ));
+            return Err(
+                Message::new("role_title_length")
+                    .arg("min", self.role_title_min_length)
+                    .arg("max", self.role_title_max_length)
+            );
         }
         Ok(())
     }
     
-    pub fn validate_role_description(&self, description: &str) -> Result<(), String> {
+    pub fn validate_role_description(&self, description: &str) -> Result<(), Message> {
         if description.len() < self.role_description_min_length || description.len() > self.role_description_max_length {
-            return Err(format!(
-                "Описание роли должно содержать от {} до {} символов",
-                self.role_description_min_length,
-                self.role_description_max_length
-            ));
+            return Err(
+                Message::new("role_description_length")
+                    .arg("min", self.role_description_min_length)
+                    .arg("max", self.role_description_max_length)
+            );
         }
         Ok(())
     }
     
-    pub fn validate_session_token(&self, session_token: &SessionToken) -> Result<(), String> {
-        if session_token.len() != self.session_token_length {
-            return Err("Неверный формат токена сессии".to_string());
+    pub fn validate_organization_title(&self, title: &str) -> Result<(), Message> {
+        if title.len() < self.organization_title_min_length || title.len() > self.organization_title_max_length {
+            return Err(
+                Message::new("organization_title_length")
+                    .arg("min", self.organization_title_min_length)
+                    .arg("max", self.organization_title_max_length)
+            );
         }
         Ok(())
     }
-
-    pub fn validate_page(&self, page: &u64) -> Result<(), String> {
-        if *page == 0 {
-            return Err("Номер страницы должен быть больше 0".to_string());
+    
+    pub fn validate_organization_description(&self, description: &str) -> Result<(), Message> {
+        if description.len() < self.organization_description_min_length
+            || description.len() > self.organization_description_max_length {
+            return Err(
+                Message::new("organization_description_length")
+                    .arg("min", self.organization_description_min_length)
+                    .arg("max", self.organization_description_max_length)
+            );
         }
         Ok(())
     }
     
-    pub fn validate_per_page(&self, per_page: &u64) -> Result<(), String> {
+    pub fn validate_group_title(&self, title: &str) -> Result<(), Message> {
+        if title.len() < self.group_title_min_length || title.len() > self.group_title_max_length {
+            return Err(
+                Message::new("group_title_length")
+                    .arg("min", self.group_title_min_length)
+                    .arg("max", self.group_title_max_length)
+            );
+        }
+        Ok(())
+    }
+    
+    pub fn validate_group_description(&self, description: &str) -> Result<(), Message> {
+        if description.len() < self.group_description_min_length
+            || description.len() > self.group_description_max_length {
+            return Err(
+                Message::new("group_description_length")
+                    .arg("min", self.group_description_min_length)
+                    .arg("max", self.group_description_max_length)
+            );
+        }
+        Ok(())
+    }
+    
+    pub fn validate_resource_scope(&self, scope: &ResourceScope) -> Result<(), Message> {
+        if scope.resource_type.is_empty()
+            || scope.resource_type.len() > self.resource_type_max_length
+            || !self.resource_type_regex.is_match(&scope.resource_type) {
+            return Err(
+                Message::new("resource_scope_type_invalid").arg("max", self.resource_type_max_length)
+            );
+        }
+        if scope.resource_id.len() > self.resource_id_max_length
+            || !self.resource_id_regex.is_match(&scope.resource_id) {
+            return Err(
+                Message::new("resource_scope_id_invalid").arg("max", self.resource_id_max_length)
+            );
+        }
+        Ok(())
+    }
+    
+    pub fn validate_role_link_period(
+        &self,
+        starts_at: Option<&DateTime<Utc>>,
+        expires_at: Option<&DateTime<Utc>>
+    ) -> Result<(), Message> {
+        if let Some(expires_at) = expires_at {
+            if *expires_at <= Utc::now() || starts_at.is_some_and(|starts_at| expires_at <= starts_at) {
+                return Err(Message::new("role_link_period_invalid"));
+            }
+        }
+        Ok(())
+    }
+    
+    pub fn validate_session_token(&self, session_token: &SessionToken) -> Result<(), Message> {
+        if session_token.len() != self.session_token_length {
+            return Err(Message::new("session_token_format"));
+        }
+        Ok(())
+    }
+
+    pub fn validate_per_page(&self, per_page: &u64) -> Result<(), Message> {
         if *per_page == 0 {
-            return Err("Количество элементов на странице должно быть больше 0".to_string());
+            return Err(Message::new("per_page_too_small"));
         } else if *per_page > 100 {
-            return Err("Количество элементов на странице должно быть не больше 100".to_string());
+            return Err(Message::new("per_page_too_large").arg("max", 100));
         }
         Ok(())
     }
 
-    pub fn validate_permission_title(&self, title: &str) -> Result<(), String> {
+    pub fn validate_permission_title(&self, title: &str) -> Result<(), Message> {
         if title.len() < self.permission_title_min_length || title.len() > self.permission_title_max_length {
-            return Err(format!(
-                "Название разрешения должно содержать от {} до {} символов",
-                self.permission_title_min_length,
-                self.permission_title_max_length
-            ));
+            return Err(
+                Message::new("permission_title_length")
+                    .arg("min", self.permission_title_min_length)
+                    .arg("max", self.permission_title_max_length)
+            );
         }
         Ok(())
     }
 
-    pub fn validate_permission_description(&self, description: &str) -> Result<(), String> {
+    pub fn validate_permission_description(&self, description: &str) -> Result<(), Message> {
         if description.len() < self.permission_description_min_length || description.len() > self.permission_description_max_length {
-            return Err(format!(
-                "Описание разрешения должно содержать от {} до {} символов",
-                self.permission_description_min_length,
-                self.permission_description_max_length
-            ));
+            return Err(
+                Message::new("permission_description_length")
+                    .arg("min", self.permission_description_min_length)
+                    .arg("max", self.permission_description_max_length)
+            );
         }
         Ok(())
     }
 
-    pub fn validate_service_title(&self, title: &str) -> Result<(), String> {
+    pub fn validate_service_title(&self, title: &str) -> Result<(), Message> {
         if title.len() < self.service_title_min_length || title.len() > self.service_title_max_length {
-            return Err(format!(
-                "Название сервиса должно содержать от {} до {} символов",
-                self.service_title_min_length,
-                self.service_title_max_length
-            ));
+            return Err(
+                Message::new("service_title_length")
+                    .arg("min", self.service_title_min_length)
+                    .arg("max", self.service_title_max_length)
+            );
         }
         Ok(())
     }
 
-    pub fn validate_service_description(&self, description: &str) -> Result<(), String> {
+    pub fn validate_service_description(&self, description: &str) -> Result<(), Message> {
         if description.len() < self.service_description_min_length || description.len() > self.service_description_max_length {
-            return Err(format!(
-                "Описание сервиса должно содержать от {} до {} символов",
-                self.service_description_min_length,
-                self.service_description_max_length
-            ));
+            return Err(
+                Message::new("service_description_length")
+                    .arg("min", self.service_description_min_length)
+                    .arg("max", self.service_description_max_length)
+            );
         }
         Ok(())
     }
     
+
+    pub fn validate_ban_reason(&self, reason: &str) -> Result<(), Message> {
+        if reason.len() < self.ban_reason_min_length || reason.len() > self.ban_reason_max_length {
+            return Err(
+                Message::new("ban_reason_length")
+                    .arg("min", self.ban_reason_min_length)
+                    .arg("max", self.ban_reason_max_length)
+            );
+        }
+        Ok(())
+    }
+
+    pub fn validate_attribute_schema(&self, schema: &Value) -> Result<(), Message> {
+        if !schema.is_object() {
+            return Err(Message::new("attribute_schema_not_object"))
+        }
+        match JSONSchema::compile(schema) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(Message::new("attribute_schema_invalid").arg("error", error))
+        }
+    }
+
+    pub fn validate_attributes(&self, schema: &Value, attributes: &Value) -> Result<(), Message> {
+        let compiled = match JSONSchema::compile(schema) {
+            Ok(compiled) => compiled,
+            Err(error) => return Err(Message::new("attribute_schema_invalid").arg("error", error))
+        };
+        
+        if let Err(errors) = compiled.validate(attributes) {
+            let errors = errors.take(self.attribute_errors_limit).map(|error| {
+                let path = error.instance_path.to_string();
+                if path.is_empty() {
+                    error.to_string()
+                } else {
+                    format!("{}: {}", path, error)
+                }
+            }).collect::<Vec<String>>();
+            return Err(Message::new("attributes_invalid").arg("errors", errors.join("; ")))
+        }
+        Ok(())
+    }
+
+    pub fn validate_avatar(&self, content_type: &str, size: usize) -> Result<(), Message> {
+        if !self.avatar_content_types.iter().any(|allowed| allowed == content_type) {
+            return Err(
+                Message::new("avatar_content_type").arg("types", self.avatar_content_types.join(", "))
+            );
+        }
+        if size == 0 || size > self.avatar_max_size {
+            return Err(
+                Message::new("avatar_size").arg("max", self.avatar_max_size / 1024 / 1024)
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_phone() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+
+        assert_eq!(validator.normalize_phone("+79991234567").unwrap(), "+79991234567");
+        assert_eq!(validator.normalize_phone("+7 (999) 123-45-67").unwrap(), "+79991234567");
+        assert_eq!(validator.normalize_phone("+1.202.555.0143").unwrap(), "+12025550143");
+    }
+
+    #[test]
+    fn test_normalize_phone_invalid() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+
+        for phone in ["", "89991234567", "+0991234567", "+7999", "+7999123456789012", "+7999abc4567"] {
+            assert_eq!(validator.normalize_phone(phone).unwrap_err().code, "phone_format", "{}", phone);
+        }
+    }
+
+    fn error_codes(result: Result<(), Message>) -> Vec<&'static str> {
+        match result {
+            Ok(_) => vec![],
+            Err(error) if error.details.is_empty() => vec![error.code],
+            Err(error) => error.details.iter().map(|detail| detail.code).collect()
+        }
+    }
+
+    #[test]
+    fn test_password_default_policy() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+
+        assert!(validator.validate_password("correct1horse", &[], false).is_ok());
+        assert_eq!(error_codes(validator.validate_password("12345678", &[], false)), vec!["password_letter"]);
+    }
+
+    #[test]
+    fn test_password_length() {
+        let validator = ValidatorService::new(PasswordPolicy { min_length: 4, max_length: 6, ..PasswordPolicy::default() });
+
+        assert!(validator.validate_password("abc12", &[], false).is_ok());
+        assert_eq!(error_codes(validator.validate_password("ab1", &[], false)), vec!["password_length"]);
+        assert_eq!(error_codes(validator.validate_password("abcdef1", &[], false)), vec!["password_length"]);
+        // Длина считается в символах, а не в байтах
+        assert!(validator.validate_password("парол1", &[], false).is_ok());
+    }
+
+    #[test]
+    fn test_password_character_classes() {
+        let validator = ValidatorService::new(PasswordPolicy {
+            require_letter: false,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_special: true,
+            ..PasswordPolicy::default()
+        });
+
+        assert!(validator.validate_password("Abcdef1!", &[], false).is_ok());
+        assert_eq!(error_codes(validator.validate_password("ABCDEF1!", &[], false)), vec!["password_lowercase"]);
+        assert_eq!(error_codes(validator.validate_password("abcdef1!", &[], false)), vec!["password_uppercase"]);
+        assert_eq!(error_codes(validator.validate_password("Abcdefg!", &[], false)), vec!["password_digit"]);
+        assert_eq!(error_codes(validator.validate_password("Abcdefg1", &[], false)), vec!["password_special"]);
+    }
+
+    #[test]
+    fn test_password_letter() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+        assert_eq!(error_codes(validator.validate_password("1234567!", &[], false)), vec!["password_letter"]);
+        assert!(validator.validate_password("пароль12", &[], false).is_ok());
+
+        let validator = ValidatorService::new(PasswordPolicy { require_letter: false, ..PasswordPolicy::default() });
+        assert!(validator.validate_password("12345678", &[], false).is_ok());
+    }
+
+    #[test]
+    fn test_password_whitespace() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+        assert_eq!(error_codes(validator.validate_password("pass word1", &[], false)), vec!["password_whitespace"]);
+
+        let validator = ValidatorService::new(PasswordPolicy { allow_whitespace: true, ..PasswordPolicy::default() });
+        assert!(validator.validate_password("pass word1", &[], false).is_ok());
+    }
+
+    #[test]
+    fn test_password_personal_data() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+        let personal_data = ["ivanov", "petr@example.com"];
+
+        assert!(validator.validate_password("secret123", &personal_data, false).is_ok());
+        assert_eq!(
+            error_codes(validator.validate_password("IVANOV2024", &personal_data, false)),
+            vec!["password_personal_data"]
+        );
+        // Из email учитывается только локальная часть
+        assert_eq!(
+            error_codes(validator.validate_password("xpetr2024", &personal_data, false)),
+            vec!["password_personal_data"]
+        );
+        assert!(validator.validate_password("example123", &personal_data, false).is_ok());
+
+        let validator = ValidatorService::new(PasswordPolicy { forbid_personal_data: false, ..PasswordPolicy::default() });
+        assert!(validator.validate_password("ivanov2024", &personal_data, false).is_ok());
+    }
+
+    #[test]
+    fn test_password_strength() {
+        let validator = ValidatorService::new(PasswordPolicy { min_strength: 3, ..PasswordPolicy::default() });
+
+        assert_eq!(error_codes(validator.validate_password("password1", &[], false)), vec!["password_weak"]);
+        assert!(validator.validate_password("wL4#qz9!Tr2vXn", &[], false).is_ok());
+    }
+
+    #[test]
+    fn test_password_breached() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+        assert_eq!(error_codes(validator.validate_password("secret123", &[], true)), vec!["password_breached"]);
+    }
+
+    #[test]
+    fn test_password_multiple_violations() {
+        let validator = ValidatorService::new(PasswordPolicy::default());
+
+        let error = validator.validate_password("abc", &[], false).unwrap_err();
+        assert_eq!(error.code, "password_policy");
+        assert_eq!(error_codes(Err(error)), vec!["password_length", "password_digit"]);
+    }
 }
@@ -3,23 +3,55 @@ use sea_orm::DbConn;
 
 use crate::adapters::argon2_password_hasher::Argon2PasswordHasher;
 use crate::adapters::database::access_log_db::AccessLogGateway;
+use crate::adapters::database::ban_db::BanGateway;
+use crate::adapters::database::password_history_db::PasswordHistoryGateway;
+use crate::adapters::database::group_db::GroupGateway;
+use crate::adapters::database::organization_db::OrganizationGateway;
 use crate::adapters::database::permission_db::PermissionGateway;
 use crate::adapters::database::role_db::RoleGateway;
 use crate::adapters::database::service_db::ServiceGateway;
 use crate::adapters::database::session_db::SessionGateway;
 use crate::adapters::database::user_db::UserGateway;
+use crate::adapters::file_breached_password_checker::FileBreachedPasswordChecker;
+use crate::adapters::log_sms_sender::LogSmsSender;
 use crate::adapters::redis_confirm_code::RedisConfirmCode;
+use crate::adapters::redis_one_time_token::RedisOneTimeToken;
+use crate::adapters::redis_rate_limiter::RedisRateLimiter;
 use crate::adapters::rmq_email_sender::RMQEmailSender;
+use crate::adapters::s3_file_storage::S3FileStorage;
 use crate::adapters::sha256_session_hasher::Sha256SessionHasher;
+use crate::adapters::webp_image_processor::WebpImageProcessor;
+use crate::application::ban::create::BanUser;
+use crate::application::ban::expire::ExpireBans;
+use crate::application::ban::get_by_user::GetUserBans;
+use crate::application::ban::revoke::RevokeBan;
 use crate::application::common::id_provider::IdProvider;
+use crate::application::group::create::CreateGroup;
+use crate::application::group::delete::DeleteGroup;
+use crate::application::group::get_by_id::GetGroupById;
+use crate::application::group::get_range::GetGroupRange;
+use crate::application::group::link_role::LinkGroupRole;
+use crate::application::group::link_user::LinkGroupUser;
+use crate::application::group::unlink_role::UnlinkGroupRole;
+use crate::application::group::unlink_user::UnlinkGroupUser;
+use crate::application::group::update::UpdateGroup;
+use crate::application::organization::create::CreateOrganization;
+use crate::application::organization::get_range::GetOrganizationRange;
+use crate::application::organization::link_role::LinkOrganizationRole;
+use crate::application::organization::link_user::LinkOrganizationUser;
+use crate::application::organization::unlink_role::UnlinkOrganizationRole;
+use crate::application::organization::unlink_user::UnlinkOrganizationUser;
 use crate::application::permission::get_by_role::GetRolePermissions;
 use crate::application::permission::get_by_user::GetUserPermissions;
 use crate::application::permission::get_range::GetPermissionRange;
 use crate::application::permission::link::LinkRolePermission;
+use crate::application::permission::link_user::LinkUserPermission;
 use crate::application::permission::unlink::UnlinkRolePermission;
+use crate::application::permission::unlink_user::UnlinkUserPermission;
 use crate::application::permission::update::UpdatePermission;
 use crate::application::role::create::CreateRole;
 use crate::application::role::delete::DeleteRole;
+use crate::application::role::expire_links::ExpireRoleLinks;
 use crate::application::role::get_by_id::GetRoleById;
 use crate::application::role::get_by_ids::GetRolesByIds;
 use crate::application::role::get_by_user::GetUserRoles;
@@ -40,62 +72,153 @@ use crate::application::session::get_access_log_self::GetAccessLogSelf;
 use crate::application::session::get_by_id::GetSessionById;
 use crate::application::session::get_by_user_id::GetSessionsByUserId;
 use crate::application::session::get_self::GetSessionSelf;
+use crate::application::user::accept_invitation::AcceptInvitation;
+use crate::application::user::change_email_self::ChangeEmailSelf;
+use crate::application::user::change_phone_self::ChangePhoneSelf;
+use crate::application::user::confirm_phone::ConfirmPhone;
 use crate::application::user::change_password::ChangePassword;
 use crate::application::user::confirm::ConfirmUser;
 use crate::application::user::create::CreateUser;
+use crate::application::user::export_self::ExportUserSelf;
 use crate::application::user::get_by_id::GetUserById;
 use crate::application::user::get_by_ids::GetUsersByIds;
 use crate::application::user::get_range::GetUserRange;
 use crate::application::user::get_self::GetUserSelf;
+use crate::application::user::import::ImportUsers;
+use crate::application::user::invite::InviteUser;
 use crate::application::user::reset_password::ResetPassword;
+use crate::application::user::get_attributes::GetUserAttributes;
+use crate::application::user::revert_email_change::RevertEmailChange;
 use crate::application::user::send_confirm_code::SendConfirmCode;
+use crate::application::user::send_email_change_code::SendEmailChangeCode;
+use crate::application::user::password_reuse::PasswordReuseCheck;
+use crate::application::user::state_effects::UserStateEffects;
 use crate::application::user::update::UpdateUser;
+use crate::application::user::update_attributes::UpdateUserAttributes;
+use crate::application::user::upload_avatar::UploadAvatar;
 use crate::application::user::update_self::UpdateUserSelf;
+use crate::application::user::update_preferences_self::UpdatePreferencesSelf;
 use crate::config::Extra;
 use crate::domain::services::access::AccessService;
 use crate::domain::services::access_log::AccessLogService;
+use crate::domain::services::avatar::AvatarService;
+use crate::domain::services::ban::BanService;
 use crate::domain::services::external::ExternalService;
+use crate::domain::services::normalizer::NormalizerService;
+use crate::domain::services::group::GroupService;
+use crate::domain::services::organization::OrganizationService;
 use crate::domain::services::permission::PermissionService;
 use crate::domain::services::role::RoleService;
 use crate::domain::services::session::SessionService;
 use crate::domain::services::user::UserService;
+use crate::domain::models::normalization::Normalization;
+use crate::domain::models::password_policy::PasswordPolicy;
+use crate::domain::models::service::ServiceTextId;
 use crate::domain::services::validator::ValidatorService;
 use crate::presentation::interactor_factory::InteractorFactory;
 
+/// Адаптеры, которые создаются снаружи и передаются в **IoC**
+pub struct IoCAdapters {
+    pub email_sender: RMQEmailSender,
+    pub sms_sender: LogSmsSender,
+    pub file_storage: S3FileStorage,
+    pub password_hasher: Argon2PasswordHasher,
+    pub breached_password_checker: FileBreachedPasswordChecker,
+}
+
+/// Параметры из конфигурации, нужные при сборке **IoC**
+pub struct IoCConfig {
+    pub session_exp: u32,
+    pub confirm_code_ttl: u32,
+    pub password_policy: PasswordPolicy,
+    pub normalization: Normalization,
+    pub extra: Extra,
+    pub service_name: ServiceTextId,
+}
+
 pub struct IoC {
     user_gateway: UserGateway,
     session_gateway: SessionGateway,
     access_log_gateway: AccessLogGateway,
     access_log_service: AccessLogService,
+    ban_gateway: BanGateway,
+    ban_service: BanService,
     role_gateway: RoleGateway,
     service_gateway: ServiceGateway,
     permission_gateway: PermissionGateway,
+    organization_gateway: OrganizationGateway,
+    group_gateway: GroupGateway,
+    password_history_gateway: PasswordHistoryGateway,
     user_service: UserService,
     session_service: SessionService,
     password_hasher: Argon2PasswordHasher,
     session_hasher: Sha256SessionHasher,
     validator: ValidatorService,
+    normalizer: NormalizerService,
+    password_policy: PasswordPolicy,
+    breached_password_checker: FileBreachedPasswordChecker,
     access_service: AccessService,
     confirm_code: RedisConfirmCode,
+    one_time_token: RedisOneTimeToken,
+    rate_limiter: RedisRateLimiter,
     email_sender: RMQEmailSender,
+    sms_sender: LogSmsSender,
+    file_storage: S3FileStorage,
+    image_processor: WebpImageProcessor,
+    avatar_service: AvatarService,
     role_service: RoleService,
     extra: Extra,
+    service_name: ServiceTextId,
     permission_service: PermissionService,
-    external_service: ExternalService
+    external_service: ExternalService,
+    organization_service: OrganizationService,
+    group_service: GroupService
 }
 
 impl IoC {
+    fn user_state_effects(&self) -> UserStateEffects<'_> {
+        UserStateEffects {
+            session_remover: &self.session_gateway,
+            password_history_remover: &self.password_history_gateway,
+            email_sender: &self.email_sender,
+            extra: &self.extra,
+        }
+    }
+
+    fn password_reuse_check(&self) -> PasswordReuseCheck<'_> {
+        PasswordReuseCheck {
+            password_history_reader: &self.password_history_gateway,
+            password_hasher: &self.password_hasher,
+            history_size: self.password_policy.history_size,
+        }
+    }
+
     pub fn new(
         db_pool: Box<DbConn>,
         session_redis_pool: Pool,
-        session_exp: u32,
-        email_sender: RMQEmailSender,
         confirm_redis_pool: Pool,
-        confirm_code_ttl: u32,
-        extra: Extra
+        rate_limit_redis_pool: Pool,
+        adapters: IoCAdapters,
+        config: IoCConfig,
     ) -> IoC {
+        let IoCAdapters {
+            email_sender,
+            sms_sender,
+            file_storage,
+            password_hasher,
+            breached_password_checker,
+        } = adapters;
+        let IoCConfig {
+            session_exp,
+            confirm_code_ttl,
+            password_policy,
+            normalization,
+            extra,
+            service_name,
+        } = config;
+        let normalizer = NormalizerService::new(normalization);
         IoC {
-            user_gateway: UserGateway::new(db_pool.clone()),
+            user_gateway: UserGateway::new(db_pool.clone(), normalizer.clone()),
             session_gateway: SessionGateway::new(
                 Box::new(session_redis_pool),
                 session_exp,
@@ -103,24 +226,49 @@ impl IoC {
             ),
             access_log_gateway: AccessLogGateway::new(db_pool.clone()),
             access_log_service: AccessLogService {},
+            ban_gateway: BanGateway::new(db_pool.clone()),
+            ban_service: BanService {},
             role_gateway: RoleGateway::new(db_pool.clone()),
             service_gateway: ServiceGateway::new(db_pool.clone()),
             permission_gateway: PermissionGateway::new(db_pool.clone()),
+            organization_gateway: OrganizationGateway::new(db_pool.clone()),
+            group_gateway: GroupGateway::new(db_pool.clone()),
+            // Текущий пароль хранится у пользователя и входит в `history_size`
+            password_history_gateway: PasswordHistoryGateway::new(
+                db_pool.clone(),
+                password_policy.history_size.saturating_sub(1),
+            ),
             user_service: UserService{},
             session_service: SessionService::new(session_exp),
-            password_hasher: Argon2PasswordHasher::new(),
+            password_hasher,
             session_hasher: Sha256SessionHasher {},
-            validator: ValidatorService::new(),
+            validator: ValidatorService::new(password_policy.clone()),
+            normalizer,
+            password_policy,
+            breached_password_checker,
             access_service: AccessService{},
             confirm_code: RedisConfirmCode::new(
-                Box::new(confirm_redis_pool),
+                Box::new(confirm_redis_pool.clone()),
                 confirm_code_ttl,
             ),
+            one_time_token: RedisOneTimeToken::new(
+                Box::new(confirm_redis_pool),
+            ),
+            rate_limiter: RedisRateLimiter::new(
+                Box::new(rate_limit_redis_pool),
+            ),
             email_sender,
+            sms_sender,
+            file_storage,
+            image_processor: WebpImageProcessor::new(),
+            avatar_service: AvatarService {},
             role_service: RoleService{},
             extra,
+            service_name,
             permission_service: PermissionService {},
             external_service: ExternalService {},
+            organization_service: OrganizationService {},
+            group_service: GroupService {},
         }
     }
 }
@@ -131,6 +279,8 @@ impl InteractorFactory for IoC {
             user_reader: &self.user_gateway,
             id_provider,
             access_service: &self.access_service,
+            avatar_service: &self.avatar_service,
+            file_storage: &self.file_storage,
         }
     }
 
@@ -139,6 +289,8 @@ impl InteractorFactory for IoC {
             id_provider,
             user_reader: &self.user_gateway,
             access_service: &self.access_service,
+            avatar_service: &self.avatar_service,
+            file_storage: &self.file_storage,
         }
     }
 
@@ -147,6 +299,8 @@ impl InteractorFactory for IoC {
             user_reader: &self.user_gateway,
             id_provider,
             access_service: &self.access_service,
+            avatar_service: &self.avatar_service,
+            file_storage: &self.file_storage,
             validator: &self.validator,
         }
     }
@@ -156,6 +310,8 @@ impl InteractorFactory for IoC {
             user_reader: &self.user_gateway,
             id_provider,
             access_service: &self.access_service,
+            avatar_service: &self.avatar_service,
+            file_storage: &self.file_storage,
         }
     }
 
@@ -166,18 +322,97 @@ impl InteractorFactory for IoC {
             email_sender: &self.email_sender,
             user_service: &self.user_service,
             password_hasher: &self.password_hasher,
+            breached_password_checker: &self.breached_password_checker,
             validator: &self.validator,
+            normalizer: &self.normalizer,
             access_service: &self.access_service,
             id_provider,
             extra: &self.extra,
         }
     }
+
+    fn import_users(&self, id_provider: Box<dyn IdProvider>) -> ImportUsers<'_> {
+        ImportUsers {
+            user_gateway: &self.user_gateway,
+            role_gateway: &self.role_gateway,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            normalizer: &self.normalizer,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
     
+    fn invite_user(&self, id_provider: Box<dyn IdProvider>) -> InviteUser<'_> {
+        InviteUser {
+            user_gateway: &self.user_gateway,
+            role_gateway: &self.role_gateway,
+            email_sender: &self.email_sender,
+            one_time_token: &self.one_time_token,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            normalizer: &self.normalizer,
+            access_service: &self.access_service,
+            id_provider,
+            extra: &self.extra,
+        }
+    }
+
+    fn accept_invitation(&self, id_provider: Box<dyn IdProvider>) -> AcceptInvitation<'_> {
+        AcceptInvitation {
+            one_time_token: &self.one_time_token,
+            user_gateway: &self.user_gateway,
+            user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
+            password_hasher: &self.password_hasher,
+            breached_password_checker: &self.breached_password_checker,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+    
+    fn change_phone_self(&self, id_provider: Box<dyn IdProvider>) -> ChangePhoneSelf<'_> {
+        ChangePhoneSelf {
+            user_gateway: &self.user_gateway,
+            confirm_code: &self.confirm_code,
+            sms_sender: &self.sms_sender,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+            extra: &self.extra,
+        }
+    }
+
+    fn confirm_phone(&self, id_provider: Box<dyn IdProvider>) -> ConfirmPhone<'_> {
+        ConfirmPhone {
+            user_gateway: &self.user_gateway,
+            confirm_code: &self.confirm_code,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
+    fn update_preferences_self(&self, id_provider: Box<dyn IdProvider>) -> UpdatePreferencesSelf<'_> {
+        UpdatePreferencesSelf {
+            user_gateway: &self.user_gateway,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
     fn update_user(&self, id_provider: Box<dyn IdProvider>) -> UpdateUser {
         UpdateUser {
             user_gateway: &self.user_gateway,
             user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
             validator: &self.validator,
+            normalizer: &self.normalizer,
             access_service: &self.access_service,
             id_provider,
         }
@@ -188,24 +423,86 @@ impl InteractorFactory for IoC {
             user_gateway: &self.user_gateway,
             user_service: &self.user_service,
             validator: &self.validator,
+            normalizer: &self.normalizer,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
+    fn export_user_self(&self, id_provider: Box<dyn IdProvider>) -> ExportUserSelf<'_> {
+        ExportUserSelf {
+            user_reader: &self.user_gateway,
+            role_reader: &self.role_gateway,
+            permission_reader: &self.permission_gateway,
+            session_reader: &self.session_gateway,
+            access_log_reader: &self.access_log_gateway,
+            rate_limiter: &self.rate_limiter,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn ban_user(&self, id_provider: Box<dyn IdProvider>) -> BanUser<'_> {
+        BanUser {
+            ban_gateway: &self.ban_gateway,
+            user_gateway: &self.user_gateway,
+            ban_service: &self.ban_service,
+            user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
+    fn revoke_ban(&self, id_provider: Box<dyn IdProvider>) -> RevokeBan<'_> {
+        RevokeBan {
+            ban_gateway: &self.ban_gateway,
+            user_gateway: &self.user_gateway,
+            ban_service: &self.ban_service,
+            user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
             access_service: &self.access_service,
             id_provider,
         }
     }
 
+    fn expire_bans(&self) -> ExpireBans<'_> {
+        ExpireBans {
+            ban_reader: &self.ban_gateway,
+            user_gateway: &self.user_gateway,
+            ban_service: &self.ban_service,
+            user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
+        }
+    }
+
+    fn get_user_bans(&self, id_provider: Box<dyn IdProvider>) -> GetUserBans<'_> {
+        GetUserBans {
+            ban_reader: &self.ban_gateway,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
 
     fn create_session(&self, id_provider: Box<dyn IdProvider>) -> CreateSession {
         CreateSession {
             id_provider,
             session_gateway: &self.session_gateway,
             user_gateway: &self.user_gateway,
+            ban_reader: &self.ban_gateway,
             access_log_writer: &self.access_log_gateway,
             access_log_service: &self.access_log_service,
             session_service: &self.session_service,
+            ban_service: &self.ban_service,
+            user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
             password_hasher: &self.password_hasher,
             session_hasher: &self.session_hasher,
             validator: &self.validator,
+            normalizer: &self.normalizer,
             access_service: &self.access_service,
+            password_policy: &self.password_policy,
         }
     }
 
@@ -237,6 +534,7 @@ impl InteractorFactory for IoC {
         GetSessionsByUserId {
             session_reader: &self.session_gateway,
             access_service: &self.access_service,
+            validator: &self.validator,
             id_provider,
         }
     }
@@ -256,6 +554,9 @@ impl InteractorFactory for IoC {
             session_hasher: &self.session_hasher,
             id_provider,
             validator_service: &self.validator,
+            access_service: &self.access_service,
+            ums_service_text_id: &self.service_name,
+            organization_reader: &self.organization_gateway,
         }
     }
 
@@ -265,6 +566,14 @@ impl InteractorFactory for IoC {
             permission_gateway: &self.permission_gateway,
             permission_service: &self.permission_service,
             external_service: &self.external_service,
+            validator: &self.validator,
+        }
+    }
+
+    fn expire_role_links(&self) -> ExpireRoleLinks<'_> {
+        ExpireRoleLinks {
+            role_gateway: &self.role_gateway,
+            session_remover: &self.session_gateway,
         }
     }
 
@@ -277,6 +586,7 @@ impl InteractorFactory for IoC {
             user_reader: &self.user_gateway,
             access_service: &self.access_service,
             validator: &self.validator,
+            normalizer: &self.normalizer,
         }
     }
 
@@ -288,7 +598,9 @@ impl InteractorFactory for IoC {
             extra: &self.extra,
             confirm_code: &self.confirm_code,
             user_service: &self.user_service,
+            state_effects: self.user_state_effects(),
             validator: &self.validator,
+            normalizer: &self.normalizer,
             access_service: &self.access_service,
         }
     }
@@ -297,9 +609,14 @@ impl InteractorFactory for IoC {
         ChangePassword {
             email_sender: &self.email_sender,
             user_gateway: &self.user_gateway,
+            password_history_gateway: &self.password_history_gateway,
+            session_gateway: &self.session_gateway,
+            session_service: &self.session_service,
             user_service: &self.user_service,
             validator: &self.validator,
             password_hasher: &self.password_hasher,
+            password_reuse: self.password_reuse_check(),
+            breached_password_checker: &self.breached_password_checker,
             access_service: &self.access_service,
             id_provider,
             extra: &self.extra,
@@ -311,9 +628,13 @@ impl InteractorFactory for IoC {
             email_sender: &self.email_sender,
             confirm_code: &self.confirm_code,
             user_gateway: &self.user_gateway,
+            password_history_gateway: &self.password_history_gateway,
             user_service: &self.user_service,
             validator: &self.validator,
+            normalizer: &self.normalizer,
             password_hasher: &self.password_hasher,
+            password_reuse: self.password_reuse_check(),
+            breached_password_checker: &self.breached_password_checker,
             access_service: &self.access_service,
             session_remover: &self.session_gateway,
             id_provider,
@@ -321,6 +642,78 @@ impl InteractorFactory for IoC {
         }
     }
     
+    fn send_email_change_code(&self, id_provider: Box<dyn IdProvider>) -> SendEmailChangeCode<'_> {
+        SendEmailChangeCode {
+            id_provider,
+            email_sender: &self.email_sender,
+            confirm_code: &self.confirm_code,
+            extra: &self.extra,
+            user_reader: &self.user_gateway,
+            access_service: &self.access_service,
+            validator: &self.validator,
+            normalizer: &self.normalizer,
+        }
+    }
+
+    fn change_email_self(&self, id_provider: Box<dyn IdProvider>) -> ChangeEmailSelf<'_> {
+        ChangeEmailSelf {
+            id_provider,
+            confirm_code: &self.confirm_code,
+            one_time_token: &self.one_time_token,
+            user_gateway: &self.user_gateway,
+            email_sender: &self.email_sender,
+            extra: &self.extra,
+            user_service: &self.user_service,
+            validator: &self.validator,
+            normalizer: &self.normalizer,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn revert_email_change(&self, id_provider: Box<dyn IdProvider>) -> RevertEmailChange<'_> {
+        RevertEmailChange {
+            id_provider,
+            one_time_token: &self.one_time_token,
+            user_gateway: &self.user_gateway,
+            session_remover: &self.session_gateway,
+            user_service: &self.user_service,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn get_user_attributes(&self, id_provider: Box<dyn IdProvider>) -> GetUserAttributes<'_> {
+        GetUserAttributes {
+            user_gateway: &self.user_gateway,
+            service_gateway: &self.service_gateway,
+            user_service: &self.user_service,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn update_user_attributes(&self, id_provider: Box<dyn IdProvider>) -> UpdateUserAttributes<'_> {
+        UpdateUserAttributes {
+            user_gateway: &self.user_gateway,
+            service_gateway: &self.service_gateway,
+            user_service: &self.user_service,
+            id_provider,
+            access_service: &self.access_service,
+            validator: &self.validator,
+        }
+    }
+    
+    fn upload_avatar(&self, id_provider: Box<dyn IdProvider>) -> UploadAvatar<'_> {
+        UploadAvatar {
+            user_gateway: &self.user_gateway,
+            file_storage: &self.file_storage,
+            image_processor: &self.image_processor,
+            avatar_service: &self.avatar_service,
+            id_provider,
+            access_service: &self.access_service,
+            validator: &self.validator,
+        }
+    }
+    
     fn get_access_log_self(&self, id_provider: Box<dyn IdProvider>) -> GetAccessLogSelf {
         GetAccessLogSelf {
             access_log_reader: &self.access_log_gateway,
@@ -388,8 +781,10 @@ impl InteractorFactory for IoC {
         LinkRoleUser {
             role_gateway: &self.role_gateway,
             user_reader: &self.user_gateway,
+            session_remover: &self.session_gateway,
             id_provider,
             access_service: &self.access_service,
+            validator: &self.validator,
         }
     }
 
@@ -397,6 +792,7 @@ impl InteractorFactory for IoC {
         UnlinkRoleUser {
             role_gateway: &self.role_gateway,
             user_reader: &self.user_gateway,
+            session_remover: &self.session_gateway,
             id_provider,
             access_service: &self.access_service,
         }
@@ -475,6 +871,24 @@ impl InteractorFactory for IoC {
         }
     }
 
+    fn link_user_permission(&self, id_provider: Box<dyn IdProvider>) -> LinkUserPermission<'_> {
+        LinkUserPermission {
+            user_reader: &self.user_gateway,
+            permission_gateway: &self.permission_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn unlink_user_permission(&self, id_provider: Box<dyn IdProvider>) -> UnlinkUserPermission<'_> {
+        UnlinkUserPermission {
+            user_reader: &self.user_gateway,
+            permission_gateway: &self.permission_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
     fn get_service(&self, id_provider: Box<dyn IdProvider>) -> GetService {
         GetService {
             service_reader: &self.service_gateway,
@@ -502,4 +916,151 @@ impl InteractorFactory for IoC {
         }
     }
 
+    fn create_organization(&self, id_provider: Box<dyn IdProvider>) -> CreateOrganization<'_> {
+        CreateOrganization {
+            organization_gateway: &self.organization_gateway,
+            organization_service: &self.organization_service,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
+    fn get_organization_range(&self, id_provider: Box<dyn IdProvider>) -> GetOrganizationRange<'_> {
+        GetOrganizationRange {
+            organization_reader: &self.organization_gateway,
+            id_provider,
+            access_service: &self.access_service,
+            validator: &self.validator,
+        }
+    }
+
+    fn link_organization_user(&self, id_provider: Box<dyn IdProvider>) -> LinkOrganizationUser<'_> {
+        LinkOrganizationUser {
+            organization_gateway: &self.organization_gateway,
+            user_reader: &self.user_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn unlink_organization_user(&self, id_provider: Box<dyn IdProvider>) -> UnlinkOrganizationUser<'_> {
+        UnlinkOrganizationUser {
+            organization_gateway: &self.organization_gateway,
+            user_reader: &self.user_gateway,
+            id_provider,
+            access_service: &self.access_service,
+            ums_service_text_id: &self.service_name,
+        }
+    }
+
+    fn link_organization_role(&self, id_provider: Box<dyn IdProvider>) -> LinkOrganizationRole<'_> {
+        LinkOrganizationRole {
+            organization_gateway: &self.organization_gateway,
+            role_reader: &self.role_gateway,
+            permission_reader: &self.permission_gateway,
+            id_provider,
+            access_service: &self.access_service,
+            ums_service_text_id: &self.service_name,
+        }
+    }
+
+    fn unlink_organization_role(&self, id_provider: Box<dyn IdProvider>) -> UnlinkOrganizationRole<'_> {
+        UnlinkOrganizationRole {
+            organization_gateway: &self.organization_gateway,
+            role_reader: &self.role_gateway,
+            id_provider,
+            access_service: &self.access_service,
+            ums_service_text_id: &self.service_name,
+        }
+    }
+
+    fn create_group(&self, id_provider: Box<dyn IdProvider>) -> CreateGroup<'_> {
+        CreateGroup {
+            group_gateway: &self.group_gateway,
+            group_service: &self.group_service,
+            validator: &self.validator,
+            access_service: &self.access_service,
+            id_provider,
+        }
+    }
+
+    fn get_group_by_id(&self, id_provider: Box<dyn IdProvider>) -> GetGroupById<'_> {
+        GetGroupById {
+            group_reader: &self.group_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn get_group_range(&self, id_provider: Box<dyn IdProvider>) -> GetGroupRange<'_> {
+        GetGroupRange {
+            group_reader: &self.group_gateway,
+            id_provider,
+            access_service: &self.access_service,
+            validator: &self.validator,
+        }
+    }
+
+    fn update_group(&self, id_provider: Box<dyn IdProvider>) -> UpdateGroup<'_> {
+        UpdateGroup {
+            group_gateway: &self.group_gateway,
+            group_service: &self.group_service,
+            id_provider,
+            access_service: &self.access_service,
+            validator: &self.validator,
+        }
+    }
+
+    fn delete_group(&self, id_provider: Box<dyn IdProvider>) -> DeleteGroup<'_> {
+        DeleteGroup {
+            group_gateway: &self.group_gateway,
+            session_remover: &self.session_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn link_group_user(&self, id_provider: Box<dyn IdProvider>) -> LinkGroupUser<'_> {
+        LinkGroupUser {
+            group_gateway: &self.group_gateway,
+            user_reader: &self.user_gateway,
+            permission_reader: &self.permission_gateway,
+            session_remover: &self.session_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn unlink_group_user(&self, id_provider: Box<dyn IdProvider>) -> UnlinkGroupUser<'_> {
+        UnlinkGroupUser {
+            group_gateway: &self.group_gateway,
+            user_reader: &self.user_gateway,
+            session_remover: &self.session_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn link_group_role(&self, id_provider: Box<dyn IdProvider>) -> LinkGroupRole<'_> {
+        LinkGroupRole {
+            group_gateway: &self.group_gateway,
+            role_reader: &self.role_gateway,
+            permission_reader: &self.permission_gateway,
+            session_remover: &self.session_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
+    fn unlink_group_role(&self, id_provider: Box<dyn IdProvider>) -> UnlinkGroupRole<'_> {
+        UnlinkGroupRole {
+            group_gateway: &self.group_gateway,
+            role_reader: &self.role_gateway,
+            session_remover: &self.session_gateway,
+            id_provider,
+            access_service: &self.access_service,
+        }
+    }
+
 }
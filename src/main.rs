@@ -1,4 +1,5 @@
 use std::thread;
+use std::time::Duration;
 
 use deadpool_redis::{Config, Runtime};
 use dotenv::dotenv;
@@ -8,7 +9,7 @@ use tera::Tera;
 
 use crate::application::common::server::Server;
 use crate::domain::models::service::ServiceTextId;
-use crate::ioc::IoC;
+use crate::ioc::{IoC, IoCAdapters, IoCConfig};
 use crate::presentation::grpc::server::GrpcServer;
 use crate::presentation::web::server::{AppConfigProvider, HttpServer};
 
@@ -115,59 +116,122 @@ fn main() -> std::io::Result<()> {
     
     let session_redis_pool = redis_factory(0).create_pool(Some(Runtime::Tokio1)).unwrap();
     let confirm_code_redis_pool = redis_factory(1).create_pool(Some(Runtime::Tokio1)).unwrap();
+    let rate_limit_redis_pool = redis_factory(2).create_pool(Some(Runtime::Tokio1)).unwrap();
 
+    // Перец хранится отдельно от конфигурации в consul
+    let password_pepper = std::env::var("PASSWORD_PEPPER").ok().filter(|pepper| !pepper.is_empty());
+    let password_hasher_factory = || adapters::argon2_password_hasher::Argon2PasswordHasher::new(
+        config.base.argon2.memory_cost,
+        config.base.argon2.time_cost,
+        config.base.argon2.parallelism,
+        password_pepper.as_deref()
+    );
+    
     rt.block_on(application::initial::service_permissions(
         &adapters::database::service_db::ServiceGateway::new(db.clone()),
         &adapters::database::permission_db::PermissionGateway::new(db.clone()),
         &domain::services::permission::PermissionService{},
         service_name.clone(),
         &domain::services::external::ExternalService{},
+        &domain::services::validator::ValidatorService::new(config.base.password_policy.clone()),
     ));
 
     rt.block_on(application::initial::control_account(
         &adapters::database::role_db::RoleGateway::new(db.clone()),
         &domain::services::role::RoleService{},
         &adapters::database::permission_db::PermissionGateway::new(db.clone()),
-        &adapters::database::user_db::UserGateway::new(db.clone()),
+        &adapters::database::user_db::UserGateway::new(
+            db.clone(),
+            domain::services::normalizer::NormalizerService::new(config.base.normalization.clone())
+        ),
         &domain::services::user::UserService{},
-        &adapters::argon2_password_hasher::Argon2PasswordHasher::new(),
+        &password_hasher_factory(),
         &adapters::database::init_state_db::InitStateGateway::new(db.clone()),
     ));
     
+    let breached_password_checker = match &config.base.breached_passwords_path {
+        Some(path) => match adapters::file_breached_password_checker::FileBreachedPasswordChecker::from_file(path) {
+            Ok(checker) => checker,
+            Err(e) => {
+                log::error!("Failed to load breached passwords from {}: {}", path, e);
+                std::process::exit(1);
+            }
+        },
+        None => adapters::file_breached_password_checker::FileBreachedPasswordChecker::default(),
+    };
+    
     let ioc_factory = || {
         IoC::new(
             db.clone(),
             session_redis_pool.clone(),
-            config.base.session_exp,
-            rt.block_on(async {
-                let rmq_conn = RabbitConnection::connect(
-                    &format!("amqp://{username}:{password}@{host}:{port}/{vhost}",
-                             username = config.email.rabbitmq.username,
-                             password = config.email.rabbitmq.password,
-                             host = config.email.rabbitmq.host,
-                             port = config.email.rabbitmq.port,
-                             vhost = config.email.rabbitmq.vhost,
-                    ),
-                    RMQConnProps::default(),
-                ).await.map_err(|error| {
-                    log::error!("Failed to connect to RabbitMQ: {}", error);
-                    std::process::exit(1);
-                }).unwrap();
-
-                adapters::rmq_email_sender::RMQEmailSender::new(
-                    Box::new(rmq_conn),
-                    config.email.rabbitmq.exchange.clone(),
-                    config.email.sender_id.clone(),
-                    service_name.clone(),
-                    tera.clone(),
-                )
-            }),
             confirm_code_redis_pool.clone(),
-            config.base.confirm_code_ttl,
-            config.base.extra.clone()
+            rate_limit_redis_pool.clone(),
+            IoCAdapters {
+                email_sender: rt.block_on(async {
+                    let rmq_conn = RabbitConnection::connect(
+                        &format!("amqp://{username}:{password}@{host}:{port}/{vhost}",
+                                 username = config.email.rabbitmq.username,
+                                 password = config.email.rabbitmq.password,
+                                 host = config.email.rabbitmq.host,
+                                 port = config.email.rabbitmq.port,
+                                 vhost = config.email.rabbitmq.vhost,
+                        ),
+                        RMQConnProps::default(),
+                    ).await.map_err(|error| {
+                        log::error!("Failed to connect to RabbitMQ: {}", error);
+                        std::process::exit(1);
+                    }).unwrap();
+
+                    adapters::rmq_email_sender::RMQEmailSender::new(
+                        Box::new(rmq_conn),
+                        config.email.rabbitmq.exchange.clone(),
+                        config.email.sender_id.clone(),
+                        service_name.clone(),
+                        tera.clone(),
+                    )
+                }),
+                sms_sender: adapters::log_sms_sender::LogSmsSender::new(config.sms.log_path.as_deref()),
+                file_storage: adapters::s3_file_storage::S3FileStorage::new(
+                    &config.database.s3.endpoint_url,
+                    &config.database.s3.region,
+                    &config.database.s3.access_key,
+                    &config.database.s3.secret_key,
+                    &config.database.s3.bucket,
+                    config.database.s3.public_url.as_deref(),
+                ),
+                password_hasher: password_hasher_factory(),
+                breached_password_checker: breached_password_checker.clone(),
+            },
+            IoCConfig {
+                session_exp: config.base.session_exp,
+                confirm_code_ttl: config.base.confirm_code_ttl,
+                password_policy: config.base.password_policy.clone(),
+                normalization: config.base.normalization.clone(),
+                extra: config.base.extra.clone(),
+                service_name: service_name.clone(),
+            },
         )
     };
     
+    let args = std::env::args().collect::<Vec<String>>();
+    if args.get(1).map(String::as_str) == Some("import") {
+        let code = rt.block_on(presentation::cli::import_users(
+            &ioc_factory(),
+            &service_name,
+            &args[2..]
+        ));
+        std::process::exit(code);
+    }
+    if args.get(1).map(String::as_str) == Some("normalize-users") {
+        let code = rt.block_on(presentation::cli::normalize_users(
+            &adapters::database::user_db::UserGateway::new(
+                db.clone(),
+                domain::services::normalizer::NormalizerService::new(config.base.normalization.clone())
+            )
+        ));
+        std::process::exit(code);
+    }
+    
     let app_config_provider = AppConfigProvider {
         branch,
         build,
@@ -232,6 +296,22 @@ fn main() -> std::io::Result<()> {
         .spawn(move || {
             grpc_server.run().unwrap();
         }).unwrap();
+    
+    let role_link_checker_ioc = ioc_factory();
+    let role_link_check_interval = Duration::from_secs(config.base.role_link_check_interval);
+    thread::Builder::new()
+        .name("Role link checker".into())
+        .spawn(move || {
+            presentation::scheduler::run_role_link_checker(role_link_checker_ioc, role_link_check_interval);
+        }).unwrap();
+
+    let ban_checker_ioc = ioc_factory();
+    let ban_check_interval = Duration::from_secs(config.base.ban_check_interval);
+    thread::Builder::new()
+        .name("Ban expiry checker".into())
+        .spawn(move || {
+            presentation::scheduler::run_ban_expiry_checker(ban_checker_ioc, ban_check_interval);
+        }).unwrap();
 
     http_server.run().unwrap();
     Ok(())
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::adapters::auth::header::HeaderPayload;
+use crate::adapters::database::user_db::UserGateway;
+use crate::adapters::i18n::DEFAULT_LOCALE;
+use crate::application::common::id_provider::IdProvider;
+use crate::application::common::interactor::Interactor;
+use crate::application::user::import::ImportUsersDTO;
+use crate::domain::models::service::ServiceTextId;
+use crate::domain::models::ums_permission::UMSPermission;
+use crate::domain::models::user::UserState;
+use crate::presentation::id_provider::make_id_provider;
+use crate::presentation::import::{ImportFormat, parse_users, report_json};
+use crate::presentation::interactor_factory::InteractorFactory;
+
+/// Провайдер идентификации оператора, запустившего команду на сервере
+fn make_operator_id_provider(
+    service_name: &ServiceTextId,
+    permissions: Vec<UMSPermission>
+) -> Box<dyn IdProvider> {
+    make_id_provider(
+        service_name,
+        Some(HeaderPayload {
+            session_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            user_state: UserState::Active,
+            permissions: HashMap::from([(
+                service_name.clone(),
+                permissions.iter().map(|permission| permission.to_string()).collect()
+            )])
+        }),
+        Some("cli".to_string()),
+        "127.0.0.1"
+    )
+}
+
+/// **import** `<file> [--format csv|json] [--dry-run]`
+///
+/// Импорт пользователей из файла. Отчет выводится в stdout,
+/// код возврата 0 - без ошибок, 1 - есть отклоненные строки, 2 - импорт не выполнен.
+pub async fn import_users(
+    ioc: &dyn InteractorFactory,
+    service_name: &ServiceTextId,
+    args: &[String]
+) -> i32 {
+    let mut path: Option<&String> = None;
+    let mut format: Option<ImportFormat> = None;
+    let mut dry_run = false;
+    
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--format" => format = match args.next().map(String::as_str) {
+                Some("csv") => Some(ImportFormat::Csv),
+                Some("json") => Some(ImportFormat::Json),
+                _ => {
+                    log::error!("Import: --format must be csv or json");
+                    return 2
+                }
+            },
+            _ => path = Some(arg)
+        }
+    }
+    
+    let path = match path {
+        Some(path) => path,
+        None => {
+            log::error!("Usage: import <file> [--format csv|json] [--dry-run]");
+            return 2
+        }
+    };
+    
+    let format = match format.or(ImportFormat::from_path(path)) {
+        Some(format) => format,
+        None => {
+            log::error!("Import: unable to detect file format, use --format");
+            return 2
+        }
+    };
+    
+    let data = match std::fs::read(path) {
+        Ok(data) => data,
+        Err(error) => {
+            log::error!("Import: failed to read {}: {}", path, error);
+            return 2
+        }
+    };
+    
+    let result = match parse_users(format, &data) {
+        Ok(users) => ioc.import_users(
+            // Оператор на сервере доверенный и назначает роли из файла
+            make_operator_id_provider(
+                service_name,
+                vec![UMSPermission::ImportUsers, UMSPermission::LinkUserRole]
+            )
+        ).execute(ImportUsersDTO { users, dry_run }).await,
+        Err(error) => Err(error)
+    };
+    
+    match result {
+        Ok(report) => {
+            println!("{}", serde_json::to_string_pretty(&report_json(&report, DEFAULT_LOCALE)).unwrap());
+            if report.has_errors() { 1 } else { 0 }
+        },
+        Err(error) => {
+            log::error!("Import: {}", serde_json::to_string(&error.as_json(DEFAULT_LOCALE)).unwrap());
+            2
+        }
+    }
+}
+
+/// **normalize-users**
+///
+/// Пересчет канонических имен пользователей и email после миграции или изменения
+/// правил нормализации. Отчет выводится в stdout, код возврата 0 - совпадений нет,
+/// 1 - есть учетные записи с одинаковой канонической формой, их нужно объединить вручную.
+pub async fn normalize_users(user_gateway: &UserGateway) -> i32 {
+    let result = user_gateway.refresh_canonical().await;
+    
+    println!("{}", serde_json::to_string_pretty(&serde_json::json!({
+        "updated": result.updated,
+        "duplicate_usernames": result.duplicate_usernames,
+        "duplicate_emails": result.duplicate_emails,
+    })).unwrap());
+    
+    if result.duplicate_usernames.is_empty() && result.duplicate_emails.is_empty() { 0 } else { 1 }
+}
@@ -6,10 +6,28 @@ use tonic::codegen::http::HeaderMap;
 use proto::{EpRequest, EpResponse};
 use proto::ums_control_server::UmsControl;
 
+use crate::adapters::auth::header::HeaderPayload;
+use crate::adapters::i18n::negotiate_locale;
+use crate::application::common::exceptions::ApplicationError;
+use crate::application::common::id_provider::IdProvider;
 use crate::application::common::interactor::Interactor;
+use crate::application::session::extract_payload::EPSessionDTO;
 use crate::application::service::sync::ServiceSyncDTO;
+use crate::application::user::get_attributes::GetUserAttributesDTO;
+use crate::application::user::update_attributes::UpdateUserAttributesDTO;
+use crate::domain::models::organization::OrganizationId;
 use crate::domain::models::service::ServiceTextId;
-use crate::presentation::grpc::greeter::proto::{PermissionsList, SsRequest};
+use crate::domain::models::user::UserId;
+use crate::presentation::grpc::greeter::proto::{
+    GuaRequest,
+    PermissionsList,
+    ResourceScope,
+    ResourceScopeList,
+    ServiceScopes,
+    SsRequest,
+    UaResponse,
+    UuaRequest
+};
 use crate::presentation::id_provider::make_id_provider;
 use crate::presentation::interactor_factory::InteractorFactory;
 
@@ -29,6 +47,78 @@ impl UMSGreeter {
             service_text_id,
         }
     }
+    
+    /// Провайдер идентификации владельца сессии, от имени которого действует сервис
+    async fn session_id_provider(
+        &self,
+        session_token: String,
+        user_agent: String,
+        user_ip: String,
+        locale: &str
+    ) -> Result<Box<dyn IdProvider>, Status> {
+        let id_provider = make_id_provider(
+            &self.service_text_id,
+            None,
+            Some(user_agent.clone()),
+            &user_ip
+        );
+        
+        let data = self.ioc.extract_payload(id_provider)
+            .execute(EPSessionDTO { session_token, organization_id: None }).await
+            .map_err(|error| error_status(&error, locale))?;
+        
+        Ok(make_id_provider(
+            &self.service_text_id,
+            Some(HeaderPayload {
+                session_id: data.session_id,
+                user_id: data.user_id,
+                user_state: data.user_state,
+                permissions: data.permissions,
+            }),
+            Some(user_agent),
+            &user_ip
+        ))
+    }
+}
+
+/// Язык клиента из метаданных accept-language
+fn request_locale<T>(request: &Request<T>) -> &'static str {
+    negotiate_locale(
+        request.metadata().get("accept-language").and_then(|value| value.to_str().ok())
+    )
+}
+
+fn error_status(error: &ApplicationError, locale: &str) -> Status {
+    let message = serde_json::to_string(&error.as_json(locale)).unwrap();
+    let status = match error {
+        ApplicationError::InvalidData(_) => Status::invalid_argument(message),
+        ApplicationError::NotFound(_) => Status::not_found(message),
+        ApplicationError::Conflict(_) => Status::already_exists(message),
+        ApplicationError::Unauthorized(_) => Status::unauthenticated(message),
+        ApplicationError::Forbidden(_) => Status::permission_denied(message),
+        ApplicationError::TooManyRequests(_) => Status::resource_exhausted(message),
+        ApplicationError::ServiceUnavailable(_) => Status::unavailable(message),
+    };
+    
+    let mut header = HeaderMap::new();
+    header.insert("Content-Type", "application/json".parse().unwrap());
+    status.add_header(&mut header).unwrap();
+    
+    status
+}
+
+fn parse_user_id(user_id: &str) -> Result<Option<UserId>, uuid::Error> {
+    if user_id.is_empty() {
+        return Ok(None)
+    }
+    UserId::parse_str(user_id).map(Some)
+}
+
+fn parse_organization_id(organization_id: &str) -> Result<Option<OrganizationId>, uuid::Error> {
+    if organization_id.is_empty() {
+        return Ok(None)
+    }
+    OrganizationId::parse_str(organization_id).map(Some)
 }
 
 
@@ -40,6 +130,9 @@ impl UmsControl for UMSGreeter {
         let user_agent = payload.user_agent.clone();
         let user_ip = payload.user_ip.clone();
         let session_token = payload.session_token.clone();
+        let organization_id = parse_organization_id(&payload.organization_id).map_err(|_| {
+            Status::invalid_argument("Invalid organization_id")
+        })?;
         
         let id_provider = make_id_provider(
             &self.service_text_id,
@@ -49,7 +142,7 @@ impl UmsControl for UMSGreeter {
         );
 
         let resp = self.ioc.extract_payload(id_provider)
-            .execute(session_token).await;
+            .execute(EPSessionDTO { session_token, organization_id }).await;
         
         match resp {
             Ok(data) => {
@@ -59,7 +152,25 @@ impl UmsControl for UMSGreeter {
                     user_state: data.user_state.to_string(),
                     permissions: data.permissions.iter().map(|(k, v)| {
                         (String::from(k), PermissionsList { permission_text_ids: v.clone() })
-                    }).collect()
+                    }).collect(),
+                    organization_permissions: data.organization_permissions.iter().map(|(k, v)| {
+                        (String::from(k), PermissionsList { permission_text_ids: v.clone() })
+                    }).collect(),
+                    scopes: data.scopes.into_iter().map(|(service, permissions)| {
+                        (service, ServiceScopes {
+                            permissions: permissions.into_iter().map(|(permission, scopes)| {
+                                (permission, ResourceScopeList {
+                                    scopes: scopes.into_iter().map(|scope| ResourceScope {
+                                        resource_type: scope.resource_type,
+                                        resource_id: scope.resource_id,
+                                    }).collect()
+                                })
+                            }).collect()
+                        })
+                    }).collect(),
+                    organization_id: data.organization_id
+                        .map(|id| id.to_string())
+                        .unwrap_or_default()
                 }))
             },
             Err(error) => {
@@ -67,7 +178,7 @@ impl UmsControl for UMSGreeter {
                 header.insert("Content-Type", "application/json".parse().unwrap());
 
                 let status = Status::unauthenticated(
-                    serde_json::to_string(&error.as_json()).unwrap()
+                    serde_json::to_string(&error.as_json(request_locale(&request))).unwrap()
                 );
                 status.add_header(&mut header).unwrap();
 
@@ -81,13 +192,20 @@ impl UmsControl for UMSGreeter {
         
         let service_text_id = payload.text_id.clone();
         let permission_text_ids = payload.permission_text_ids.clone();
+        let attribute_schema = match payload.attribute_schema.is_empty() {
+            true => None,
+            false => Some(serde_json::from_str(&payload.attribute_schema).map_err(|_| {
+                Status::invalid_argument("Invalid attribute_schema")
+            })?)
+        };
 
         let executor = self.ioc.sync_service();
         
         let resp = executor.execute(
             ServiceSyncDTO {
                 service_text_id,
-                permission_text_ids
+                permission_text_ids,
+                attribute_schema
             }
         ).await;
 
@@ -96,4 +214,60 @@ impl UmsControl for UMSGreeter {
             Err(error) => Err(Status::internal(error.to_string()))
         }
     }
+
+    async fn get_user_attributes(&self, request: Request<GuaRequest>) -> Result<Response<UaResponse>, Status> {
+        let payload = request.get_ref();
+        let locale = request_locale(&request);
+        
+        let user_id = parse_user_id(&payload.user_id).map_err(|_| {
+            Status::invalid_argument("Invalid user_id")
+        })?;
+        let id_provider = self.session_id_provider(
+            payload.session_token.clone(),
+            payload.user_agent.clone(),
+            payload.user_ip.clone(),
+            locale
+        ).await?;
+        
+        let attributes = self.ioc.get_user_attributes(id_provider).execute(
+            GetUserAttributesDTO {
+                user_id,
+                namespace: payload.namespace.clone()
+            }
+        ).await.map_err(|error| error_status(&error, locale))?;
+        
+        Ok(Response::new(UaResponse {
+            attributes: attributes.to_string()
+        }))
+    }
+
+    async fn update_user_attributes(&self, request: Request<UuaRequest>) -> Result<Response<UaResponse>, Status> {
+        let payload = request.get_ref();
+        let locale = request_locale(&request);
+
+        let user_id = parse_user_id(&payload.user_id).map_err(|_| {
+            Status::invalid_argument("Invalid user_id")
+        })?;
+        let attributes = serde_json::from_str(&payload.attributes).map_err(|_| {
+            Status::invalid_argument("Invalid attributes")
+        })?;
+        let id_provider = self.session_id_provider(
+            payload.session_token.clone(),
+            payload.user_agent.clone(),
+            payload.user_ip.clone(),
+            locale
+        ).await?;
+
+        let attributes = self.ioc.update_user_attributes(id_provider).execute(
+            UpdateUserAttributesDTO {
+                user_id,
+                namespace: payload.namespace.clone(),
+                attributes
+            }
+        ).await.map_err(|error| error_status(&error, locale))?;
+
+        Ok(Response::new(UaResponse {
+            attributes: attributes.to_string()
+        }))
+    }
 }
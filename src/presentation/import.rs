@@ -0,0 +1,120 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::adapters::i18n::translate;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::user::import::{ImportUserDTO, ImportUsersResultDTO};
+use crate::domain::exceptions::Message;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Csv,
+    Json,
+}
+
+impl ImportFormat {
+    pub fn from_path(path: &str) -> Option<Self> {
+        match path.rsplit('.').next().map(|ext| ext.to_lowercase()).as_deref() {
+            Some("csv") => Some(ImportFormat::Csv),
+            Some("json") => Some(ImportFormat::Json),
+            _ => None
+        }
+    }
+}
+
+/// Строка CSV-файла, роли перечисляются через `;`
+#[derive(Debug, Deserialize)]
+struct CsvUserRow {
+    username: String,
+    email: String,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    password_hash: Option<String>,
+    roles: Option<String>,
+}
+
+pub fn parse_users(format: ImportFormat, data: &[u8]) -> Result<Vec<ImportUserDTO>, ApplicationError> {
+    match format {
+        ImportFormat::Json => serde_json::from_slice(data).map_err(|error| {
+            ApplicationError::InvalidData(
+                ErrorContent::Message(Message::new("import_json_invalid").arg("error", error))
+            )
+        }),
+        ImportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(data);
+            
+            reader.deserialize::<CsvUserRow>().enumerate().map(|(index, row)| {
+                let row = row.map_err(|error| {
+                    ApplicationError::InvalidData(
+                        ErrorContent::Message(
+                            Message::new("import_csv_row_invalid")
+                                .arg("row", index + 1)
+                                .arg("error", error)
+                        )
+                    )
+                })?;
+                let non_empty = |value: Option<String>| value.filter(|value| !value.is_empty());
+                
+                Ok(ImportUserDTO {
+                    username: row.username,
+                    email: row.email,
+                    first_name: non_empty(row.first_name),
+                    last_name: non_empty(row.last_name),
+                    password_hash: non_empty(row.password_hash),
+                    roles: row.roles.map(|roles| {
+                        roles.split(';')
+                            .map(|role| role.trim().to_string())
+                            .filter(|role| !role.is_empty())
+                            .collect()
+                    }).unwrap_or_default(),
+                })
+            }).collect()
+        }
+    }
+}
+
+/// Отчет об импорте, ошибки строк переводятся на язык `locale`
+pub fn report_json(report: &ImportUsersResultDTO, locale: &str) -> Value {
+    let mut value = serde_json::to_value(report).unwrap();
+    for (index, row) in report.errors.iter().enumerate() {
+        for (field, message) in row.errors.iter() {
+            value["errors"][index]["errors"][field] = json!({
+                "code": message.code,
+                "message": translate(message, locale)
+            });
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let data = "username,email,first_name,last_name,password_hash,roles\n\
+            alice,alice@example.com,Alice,,,Admin; Support\n\
+            bob,bob@example.com,,,,\n";
+        let users = parse_users(ImportFormat::Csv, data.as_bytes()).unwrap();
+        
+        assert_eq!(users.len(), 2);
+        assert_eq!(users[0].first_name.as_deref(), Some("Alice"));
+        assert_eq!(users[0].last_name, None);
+        assert_eq!(users[0].roles, vec!["Admin", "Support"]);
+        assert!(users[1].roles.is_empty());
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let data = r#"[{"username": "alice", "email": "alice@example.com", "roles": ["Admin"]}]"#;
+        let users = parse_users(ImportFormat::Json, data.as_bytes()).unwrap();
+        
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].password_hash, None);
+        assert_eq!(users[0].roles, vec!["Admin"]);
+    }
+}
@@ -1,12 +1,34 @@
+use crate::application::ban::create::BanUser;
+use crate::application::ban::expire::ExpireBans;
+use crate::application::ban::get_by_user::GetUserBans;
+use crate::application::ban::revoke::RevokeBan;
 use crate::application::common::id_provider::IdProvider;
+use crate::application::group::create::CreateGroup;
+use crate::application::group::delete::DeleteGroup;
+use crate::application::group::get_by_id::GetGroupById;
+use crate::application::group::get_range::GetGroupRange;
+use crate::application::group::link_role::LinkGroupRole;
+use crate::application::group::link_user::LinkGroupUser;
+use crate::application::group::unlink_role::UnlinkGroupRole;
+use crate::application::group::unlink_user::UnlinkGroupUser;
+use crate::application::group::update::UpdateGroup;
+use crate::application::organization::create::CreateOrganization;
+use crate::application::organization::get_range::GetOrganizationRange;
+use crate::application::organization::link_role::LinkOrganizationRole;
+use crate::application::organization::link_user::LinkOrganizationUser;
+use crate::application::organization::unlink_role::UnlinkOrganizationRole;
+use crate::application::organization::unlink_user::UnlinkOrganizationUser;
 use crate::application::permission::get_by_role::GetRolePermissions;
 use crate::application::permission::get_by_user::GetUserPermissions;
 use crate::application::permission::get_range::GetPermissionRange;
 use crate::application::permission::link::LinkRolePermission;
+use crate::application::permission::link_user::LinkUserPermission;
 use crate::application::permission::unlink::UnlinkRolePermission;
+use crate::application::permission::unlink_user::UnlinkUserPermission;
 use crate::application::permission::update::UpdatePermission;
 use crate::application::role::create::CreateRole;
 use crate::application::role::delete::DeleteRole;
+use crate::application::role::expire_links::ExpireRoleLinks;
 use crate::application::role::get_by_id::GetRoleById;
 use crate::application::role::get_by_ids::GetRolesByIds;
 use crate::application::role::get_by_user::GetUserRoles;
@@ -27,16 +49,29 @@ use crate::application::session::get_access_log_self::GetAccessLogSelf;
 use crate::application::session::get_by_id::GetSessionById;
 use crate::application::session::get_by_user_id::GetSessionsByUserId;
 use crate::application::session::get_self::GetSessionSelf;
+use crate::application::user::accept_invitation::AcceptInvitation;
+use crate::application::user::change_email_self::ChangeEmailSelf;
+use crate::application::user::change_phone_self::ChangePhoneSelf;
+use crate::application::user::confirm_phone::ConfirmPhone;
+use crate::application::user::update_preferences_self::UpdatePreferencesSelf;
 use crate::application::user::change_password::ChangePassword;
 use crate::application::user::confirm::ConfirmUser;
 use crate::application::user::create::CreateUser;
+use crate::application::user::export_self::ExportUserSelf;
 use crate::application::user::get_by_id::GetUserById;
 use crate::application::user::get_by_ids::GetUsersByIds;
 use crate::application::user::get_range::GetUserRange;
 use crate::application::user::get_self::GetUserSelf;
+use crate::application::user::import::ImportUsers;
+use crate::application::user::invite::InviteUser;
 use crate::application::user::reset_password::ResetPassword;
+use crate::application::user::get_attributes::GetUserAttributes;
+use crate::application::user::revert_email_change::RevertEmailChange;
 use crate::application::user::send_confirm_code::SendConfirmCode;
+use crate::application::user::send_email_change_code::SendEmailChangeCode;
 use crate::application::user::update::UpdateUser;
+use crate::application::user::update_attributes::UpdateUserAttributes;
+use crate::application::user::upload_avatar::UploadAvatar;
 use crate::application::user::update_self::UpdateUserSelf;
 
 pub trait InteractorFactory: Send + Sync {
@@ -45,8 +80,19 @@ pub trait InteractorFactory: Send + Sync {
     fn get_user_range(&self, id_provider: Box<dyn IdProvider>) -> GetUserRange;
     fn get_user_self(&self, id_provider: Box<dyn IdProvider>) -> GetUserSelf;
     fn create_user(&self, id_provider: Box<dyn IdProvider>) -> CreateUser;
+    fn import_users(&self, id_provider: Box<dyn IdProvider>) -> ImportUsers<'_>;
+    fn invite_user(&self, id_provider: Box<dyn IdProvider>) -> InviteUser<'_>;
+    fn change_phone_self(&self, id_provider: Box<dyn IdProvider>) -> ChangePhoneSelf<'_>;
+    fn confirm_phone(&self, id_provider: Box<dyn IdProvider>) -> ConfirmPhone<'_>;
+    fn update_preferences_self(&self, id_provider: Box<dyn IdProvider>) -> UpdatePreferencesSelf<'_>;
+    fn accept_invitation(&self, id_provider: Box<dyn IdProvider>) -> AcceptInvitation<'_>;
     fn update_user(&self, id_provider: Box<dyn IdProvider>) -> UpdateUser;
     fn update_user_self(&self, id_provider: Box<dyn IdProvider>) -> UpdateUserSelf;
+    fn export_user_self(&self, id_provider: Box<dyn IdProvider>) -> ExportUserSelf<'_>;
+    fn ban_user(&self, id_provider: Box<dyn IdProvider>) -> BanUser<'_>;
+    fn revoke_ban(&self, id_provider: Box<dyn IdProvider>) -> RevokeBan<'_>;
+    fn get_user_bans(&self, id_provider: Box<dyn IdProvider>) -> GetUserBans<'_>;
+    fn expire_bans(&self) -> ExpireBans<'_>;
     fn create_session(&self, id_provider: Box<dyn IdProvider>) -> CreateSession;
     fn delete_session(&self, id_provider: Box<dyn IdProvider>) -> DeleteSession;
     fn delete_self_session(&self, id_provider: Box<dyn IdProvider>) -> DeleteSessionSelf;
@@ -55,10 +101,17 @@ pub trait InteractorFactory: Send + Sync {
     fn get_sessions_self(&self, id_provider: Box<dyn IdProvider>) -> GetSessionSelf;
     fn extract_payload(&self, id_provider: Box<dyn IdProvider>) -> EPSession;
     fn sync_service(&self) -> ServiceSync;
+    fn expire_role_links(&self) -> ExpireRoleLinks<'_>;
     fn send_confirm_code(&self, id_provider: Box<dyn IdProvider>) -> SendConfirmCode;
     fn confirm_user(&self, id_provider: Box<dyn IdProvider>) -> ConfirmUser;
     fn change_password(&self, id_provider: Box<dyn IdProvider>) -> ChangePassword;
     fn reset_password(&self, id_provider: Box<dyn IdProvider>) -> ResetPassword;
+    fn send_email_change_code(&self, id_provider: Box<dyn IdProvider>) -> SendEmailChangeCode<'_>;
+    fn change_email_self(&self, id_provider: Box<dyn IdProvider>) -> ChangeEmailSelf<'_>;
+    fn revert_email_change(&self, id_provider: Box<dyn IdProvider>) -> RevertEmailChange<'_>;
+    fn get_user_attributes(&self, id_provider: Box<dyn IdProvider>) -> GetUserAttributes<'_>;
+    fn update_user_attributes(&self, id_provider: Box<dyn IdProvider>) -> UpdateUserAttributes<'_>;
+    fn upload_avatar(&self, id_provider: Box<dyn IdProvider>) -> UploadAvatar<'_>;
     fn get_access_log_self(&self, id_provider: Box<dyn IdProvider>) -> GetAccessLogSelf;
     fn get_access_log(&self, id_provider: Box<dyn IdProvider>) -> GetAccessLog;
     fn create_role(&self, id_provider: Box<dyn IdProvider>) -> CreateRole;
@@ -76,7 +129,24 @@ pub trait InteractorFactory: Send + Sync {
     fn update_permission(&self, id_provider: Box<dyn IdProvider>) -> UpdatePermission;
     fn link_role_permission(&self, id_provider: Box<dyn IdProvider>) -> LinkRolePermission;
     fn unlink_role_permission(&self, id_provider: Box<dyn IdProvider>) -> UnlinkRolePermission;
+    fn link_user_permission(&self, id_provider: Box<dyn IdProvider>) -> LinkUserPermission<'_>;
+    fn unlink_user_permission(&self, id_provider: Box<dyn IdProvider>) -> UnlinkUserPermission<'_>;
     fn get_service(&self, id_provider: Box<dyn IdProvider>) -> GetService;
     fn get_service_range(&self, id_provider: Box<dyn IdProvider>) -> GetServiceRange;
     fn update_service(&self, id_provider: Box<dyn IdProvider>) -> UpdateService;
+    fn create_organization(&self, id_provider: Box<dyn IdProvider>) -> CreateOrganization<'_>;
+    fn get_organization_range(&self, id_provider: Box<dyn IdProvider>) -> GetOrganizationRange<'_>;
+    fn link_organization_user(&self, id_provider: Box<dyn IdProvider>) -> LinkOrganizationUser<'_>;
+    fn unlink_organization_user(&self, id_provider: Box<dyn IdProvider>) -> UnlinkOrganizationUser<'_>;
+    fn link_organization_role(&self, id_provider: Box<dyn IdProvider>) -> LinkOrganizationRole<'_>;
+    fn unlink_organization_role(&self, id_provider: Box<dyn IdProvider>) -> UnlinkOrganizationRole<'_>;
+    fn create_group(&self, id_provider: Box<dyn IdProvider>) -> CreateGroup<'_>;
+    fn get_group_by_id(&self, id_provider: Box<dyn IdProvider>) -> GetGroupById<'_>;
+    fn get_group_range(&self, id_provider: Box<dyn IdProvider>) -> GetGroupRange<'_>;
+    fn update_group(&self, id_provider: Box<dyn IdProvider>) -> UpdateGroup<'_>;
+    fn delete_group(&self, id_provider: Box<dyn IdProvider>) -> DeleteGroup<'_>;
+    fn link_group_user(&self, id_provider: Box<dyn IdProvider>) -> LinkGroupUser<'_>;
+    fn unlink_group_user(&self, id_provider: Box<dyn IdProvider>) -> UnlinkGroupUser<'_>;
+    fn link_group_role(&self, id_provider: Box<dyn IdProvider>) -> LinkGroupRole<'_>;
+    fn unlink_group_role(&self, id_provider: Box<dyn IdProvider>) -> UnlinkGroupRole<'_>;
 }
\ No newline at end of file
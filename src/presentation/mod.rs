@@ -2,3 +2,7 @@ pub mod web;
 pub mod interactor_factory;
 pub mod id_provider;
 pub mod grpc;
+
+pub mod import;
+pub mod cli;
+pub mod scheduler;
\ No newline at end of file
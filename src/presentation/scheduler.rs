@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::application::ban::expire::ExpireBansDTO;
+use crate::application::common::interactor::Interactor;
+use crate::application::role::expire_links::ExpireRoleLinksDTO;
+use crate::ioc::IoC;
+use crate::presentation::interactor_factory::InteractorFactory;
+
+/// Периодически удаляет истекшие назначения ролей и сбрасывает кеш сессий
+/// пользователей, у которых назначения истекли или вступили в силу
+pub fn run_role_link_checker(ioc: IoC, interval: Duration) {
+    let ioc: Arc<dyn InteractorFactory> = Arc::new(ioc);
+    
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    
+    rt.block_on(async {
+        let mut since = Utc::now();
+        loop {
+            tokio::time::sleep(interval).await;
+            
+            let until = Utc::now();
+            if let Err(error) = ioc.expire_role_links().execute(
+                ExpireRoleLinksDTO { since, until }
+            ).await {
+                log::error!("Не удалось проверить сроки назначений ролей: {}", error);
+                continue
+            }
+            since = until;
+        }
+    });
+}
+
+/// Периодически снимает истекшие баны, возвращая пользователей в состояние до бана
+pub fn run_ban_expiry_checker(ioc: IoC, interval: Duration) {
+    let ioc: Arc<dyn InteractorFactory> = Arc::new(ioc);
+    
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    
+    rt.block_on(async {
+        loop {
+            tokio::time::sleep(interval).await;
+            
+            if let Err(error) = ioc.expire_bans().execute(
+                ExpireBansDTO { until: Utc::now() }
+            ).await {
+                log::error!("Не удалось проверить сроки банов: {}", error);
+            }
+        }
+    });
+}
@@ -1,11 +1,15 @@
 use std::fmt::{Display, Formatter};
 
 use actix_web::{error, HttpResponse, Result};
-use actix_web::http::header::ContentType;
+use actix_web::body::{BoxBody, MessageBody};
+use actix_web::dev::ServiceResponse;
+use actix_web::http::header::{ACCEPT_LANGUAGE, ContentType, HeaderMap};
 use actix_web::http::StatusCode;
 use serde_json::{json, Value};
 
+use crate::adapters::i18n::{DEFAULT_LOCALE, negotiate_locale, translate};
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::domain::exceptions::Message;
 
 impl Display for ApplicationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -21,24 +25,52 @@ impl ApplicationError {
             ApplicationError::Conflict(ref content) => (StatusCode::CONFLICT, content.clone()),
             ApplicationError::Forbidden(ref content) => (StatusCode::FORBIDDEN, content.clone()),
             ApplicationError::Unauthorized(ref content) => (StatusCode::UNAUTHORIZED, content.clone()),
+            ApplicationError::TooManyRequests(ref content) => (StatusCode::TOO_MANY_REQUESTS, content.clone()),
+            ApplicationError::ServiceUnavailable(ref content) => (StatusCode::SERVICE_UNAVAILABLE, content.clone()),
         }
     }
     
-    pub fn as_json(&self) -> Value {
+    /// Тело ошибки: стабильный код и текст на языке `locale`
+    pub fn as_json(&self, locale: &str) -> Value {
         match self.error_rest_content().1 {
-            ErrorContent::Message(msg) => json!({
-                "error": msg
-            }),
+            ErrorContent::Message(message) => {
+                let mut body = json!({
+                    "error": translate(&message, locale),
+                    "code": message.code
+                });
+                if !message.details.is_empty() {
+                    body["details"] = details_json(&message, locale);
+                }
+                body
+            },
             ErrorContent::Map(map) => json!({
                 "errors": map.iter().map(|(field, message)| {
-                    json!({
+                    let mut error = json!({
                         "field": field,
-                        "message": message
-                    })
+                        "code": message.code,
+                        "message": translate(message, locale)
+                    });
+                    if !message.details.is_empty() {
+                        error["details"] = details_json(message, locale);
+                    }
+                    error
                 }).collect::<Vec<_>>()
             }),
         }
     }
+    
+    fn localized_response(&self, locale: &str) -> HttpResponse {
+        HttpResponse::build(self.error_rest_content().0)
+            .insert_header(ContentType::json())
+            .body(serde_json::to_string(&self.as_json(locale)).unwrap())
+    }
+}
+
+fn details_json(message: &Message, locale: &str) -> Value {
+    json!(message.details.iter().map(|detail| json!({
+        "code": detail.code,
+        "message": translate(detail, locale)
+    })).collect::<Vec<_>>())
 }
 
 impl error::ResponseError for ApplicationError {
@@ -47,17 +79,36 @@ impl error::ResponseError for ApplicationError {
     }
 
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::build(self.status_code())
-            .insert_header(ContentType::json())
-            .body(serde_json::to_string(&self.as_json()).unwrap())
+        self.localized_response(DEFAULT_LOCALE)
+    }
+}
+
+/// Язык клиента из заголовка Accept-Language
+pub fn request_locale(headers: &HeaderMap) -> &'static str {
+    negotiate_locale(headers.get(ACCEPT_LANGUAGE).and_then(|value| value.to_str().ok()))
+}
+
+/// **localize_error** - перевод ошибки приложения на язык клиента
+///
+/// `error_response` не имеет доступа к запросу и отдает текст на языке по умолчанию,
+/// поэтому ответ с `ApplicationError` пересобирается с языком из Accept-Language.
+pub fn localize_error<B: MessageBody + 'static>(
+    response: ServiceResponse<B>,
+    locale: &str
+) -> ServiceResponse<BoxBody> {
+    let localized = response.response().error()
+        .and_then(|error| error.as_error::<ApplicationError>())
+        .map(|error| error.localized_response(locale));
+    
+    match localized {
+        Some(localized) => response.into_response(localized),
+        None => response.map_into_boxed_body()
     }
 }
 
 
 pub async fn not_found() -> Result<HttpResponse> {
-    Ok(HttpResponse::build(StatusCode::NOT_FOUND)
-        .insert_header(ContentType::json())
-        .body(serde_json::to_string(&json!({
-            "error": "Запрашиваемый ресурс не найден"
-        })).unwrap()))
-}
\ No newline at end of file
+    Err(ApplicationError::NotFound(
+        ErrorContent::Message(Message::new("resource_not_found"))
+    ).into())
+}
@@ -1,4 +1,5 @@
 pub mod rest;
 pub mod exception;
 mod deserializers;
+mod pagination;
 pub mod server;
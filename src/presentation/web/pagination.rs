@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+/// **PageQuery** - общие параметры постраничной выдачи списков
+///
+/// Извлекается отдельно от параметров фильтрации конкретного эндпоинта.
+/// `cursor` берется из `next_cursor` предыдущей страницы,
+/// `total` считается только по запросу `with_total=true`.
+/// Размер страницы для списков, где `per_page` необязателен
+pub const DEFAULT_PER_PAGE: u64 = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct PageQuery {
+    pub cursor: Option<String>,
+    pub per_page: Option<u64>,
+    pub with_total: Option<bool>,
+}
+
+impl PageQuery {
+    pub fn with_total(&self) -> bool {
+        self.with_total.unwrap_or(false)
+    }
+}
@@ -2,13 +2,15 @@ use actix_web::{get, HttpRequest, HttpResponse, Result, web};
 use serde::Deserialize;
 
 use crate::AppConfigProvider;
-use crate::application::common::exceptions::ApplicationError;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
 use crate::application::session::get_access_log::GetAccessLogDTO;
 use crate::application::session::get_access_log_self::GetAccessLogSelfDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::user::UserId;
 use crate::presentation::id_provider::make_id_provider_from_request;
 use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::PageQuery;
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -20,14 +22,13 @@ pub fn router(cfg: &mut web::ServiceConfig) {
 
 #[derive(Debug, Deserialize)]
 struct QueryParams {
-    page: u64,
-    per_page: u64,
     user_id: Option<UserId>
 }
 
 #[get("")]
 async fn access_log(
     query: web::Query<QueryParams>,
+    page: web::Query<PageQuery>,
     app_config_provider: web::Data<AppConfigProvider>,
     ioc: web::Data<dyn InteractorFactory>,
     req: HttpRequest
@@ -38,13 +39,18 @@ async fn access_log(
         &req
     );
     
+    let per_page = page.per_page.ok_or(
+        ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid")))
+    )?;
+    
     match query.user_id {
         Some(user_id) => {
             let data = ioc.get_access_log(id_provider).execute(
                 GetAccessLogDTO {
                     user_id,
-                    page: query.page,
-                    per_page: query.per_page
+                    cursor: page.cursor.clone(),
+                    per_page,
+                    with_total: page.with_total()
                 }
             ).await?;
             Ok(HttpResponse::Ok().json(data))
@@ -52,8 +58,9 @@ async fn access_log(
         None => {
             let data = ioc.get_access_log_self(id_provider).execute(
                 GetAccessLogSelfDTO {
-                    page: query.page,
-                    per_page: query.per_page
+                    cursor: page.cursor.clone(),
+                    per_page,
+                    with_total: page.with_total()
                 }
             ).await?;
             
@@ -0,0 +1,184 @@
+use actix_web::{delete, get, HttpRequest, HttpResponse, post, put, Result, web};
+use serde::Deserialize;
+
+use crate::AppConfigProvider;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::interactor::Interactor;
+use crate::application::group::create::CreateGroupDTO;
+use crate::application::group::delete::DeleteGroupDTO;
+use crate::application::group::get_by_id::GetGroupByIdDTO;
+use crate::application::group::get_range::GroupRangeDTO;
+use crate::application::group::link_role::LinkGroupRoleDTO;
+use crate::application::group::link_user::LinkGroupUserDTO;
+use crate::application::group::unlink_role::UnlinkGroupRoleDTO;
+use crate::application::group::unlink_user::UnlinkGroupUserDTO;
+use crate::application::group::update::UpdateGroupDTO;
+use crate::domain::exceptions::Message;
+use crate::domain::models::group::GroupId;
+use crate::presentation::id_provider::make_id_provider_from_request;
+use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::PageQuery;
+
+pub fn router(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/groups")
+            .service(create_group)
+            .service(get_groups)
+            .service(update_group)
+            .service(delete_group)
+            .service(link_group_user)
+            .service(unlink_group_user)
+            .service(link_group_role)
+            .service(unlink_group_role)
+    );
+}
+
+#[post("")]
+async fn create_group(
+    data: web::Json<CreateGroupDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.create_group(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[derive(Debug, Deserialize)]
+struct GroupsQuery {
+    id: Option<GroupId>,
+}
+
+#[get("")]
+async fn get_groups(
+    data: web::Query<GroupsQuery>,
+    page: web::Query<PageQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+
+    if let Some(id) = &data.id {
+        let data = ioc.get_group_by_id(id_provider).execute(
+            GetGroupByIdDTO { id: *id }
+        ).await?;
+        return Ok(HttpResponse::Ok().json(data))
+    } else if let Some(per_page) = page.per_page {
+        let data = ioc.get_group_range(id_provider).execute(
+            GroupRangeDTO {
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total()
+            }
+        ).await?;
+        return Ok(HttpResponse::Ok().json(data))
+    }
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
+}
+
+#[put("")]
+async fn update_group(
+    data: web::Json<UpdateGroupDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.update_group(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[delete("")]
+async fn delete_group(
+    data: web::Json<DeleteGroupDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.delete_group(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("users/link")]
+async fn link_group_user(
+    data: web::Json<LinkGroupUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.link_group_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("users/unlink")]
+async fn unlink_group_user(
+    data: web::Json<UnlinkGroupUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.unlink_group_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("roles/link")]
+async fn link_group_role(
+    data: web::Json<LinkGroupRoleDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.link_group_role(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("roles/unlink")]
+async fn unlink_group_role(
+    data: web::Json<UnlinkGroupRoleDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.unlink_group_role(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
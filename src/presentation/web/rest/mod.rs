@@ -4,4 +4,6 @@ pub mod stats;
 pub mod user;
 pub mod access_log;
 pub mod permission;
-pub mod service;
\ No newline at end of file
+pub mod service;
+pub mod organization;
+pub mod group;
\ No newline at end of file
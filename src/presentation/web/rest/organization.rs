@@ -0,0 +1,134 @@
+use actix_web::{get, HttpRequest, HttpResponse, post, Result, web};
+
+use crate::AppConfigProvider;
+use crate::application::common::exceptions::{ApplicationError, ErrorContent};
+use crate::application::common::interactor::Interactor;
+use crate::application::organization::create::CreateOrganizationDTO;
+use crate::application::organization::get_range::OrganizationRangeDTO;
+use crate::application::organization::link_role::LinkOrganizationRoleDTO;
+use crate::application::organization::link_user::LinkOrganizationUserDTO;
+use crate::application::organization::unlink_role::UnlinkOrganizationRoleDTO;
+use crate::application::organization::unlink_user::UnlinkOrganizationUserDTO;
+use crate::domain::exceptions::Message;
+use crate::presentation::id_provider::make_id_provider_from_request;
+use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::PageQuery;
+
+pub fn router(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/organizations")
+            .service(create_organization)
+            .service(get_organizations)
+            .service(link_organization_user)
+            .service(unlink_organization_user)
+            .service(link_organization_role)
+            .service(unlink_organization_role)
+    );
+}
+
+#[post("")]
+async fn create_organization(
+    data: web::Json<CreateOrganizationDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.create_organization(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[get("")]
+async fn get_organizations(
+    page: web::Query<PageQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+
+    if let Some(per_page) = page.per_page {
+        let data = ioc.get_organization_range(id_provider).execute(
+            OrganizationRangeDTO {
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total()
+            }
+        ).await?;
+        return Ok(HttpResponse::Ok().json(data))
+    }
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
+}
+
+#[post("users/link")]
+async fn link_organization_user(
+    data: web::Json<LinkOrganizationUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.link_organization_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("users/unlink")]
+async fn unlink_organization_user(
+    data: web::Json<UnlinkOrganizationUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.unlink_organization_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("roles/link")]
+async fn link_organization_role(
+    data: web::Json<LinkOrganizationRoleDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.link_organization_role(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("roles/unlink")]
+async fn unlink_organization_role(
+    data: web::Json<UnlinkOrganizationRoleDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.unlink_organization_role(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
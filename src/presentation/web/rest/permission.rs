@@ -6,18 +6,26 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
 use crate::application::permission::get_range::GetPermissionRangeDTO;
 use crate::application::permission::link::LinkRolePermissionDTO;
+use crate::application::permission::link_user::LinkUserPermissionDTO;
 use crate::application::permission::unlink::UnlinkRolePermissionDTO;
+use crate::application::permission::unlink_user::UnlinkUserPermissionDTO;
 use crate::application::permission::update::UpdatePermissionDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::role::RoleId;
 use crate::domain::models::user::UserId;
 use crate::presentation::id_provider::make_id_provider_from_request;
 use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::PageQuery;
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/permissions")
             .service(get_permissions)
             .service(update_permission)
+            .service(link_role_permission)
+            .service(unlink_role_permission)
+            .service(link_user_permission)
+            .service(unlink_user_permission)
     );
 }
 
@@ -25,13 +33,12 @@ pub fn router(cfg: &mut web::ServiceConfig) {
 struct PermissionsQuery {
     role_id: Option<RoleId>,
     user_id: Option<UserId>,
-    page: Option<u64>,
-    per_page: Option<u64>
 }
 
 #[get("")]
 async fn get_permissions(
     data: web::Query<PermissionsQuery>,
+    page: web::Query<PageQuery>,
     ioc: web::Data<dyn InteractorFactory>,
     app_config_provider: web::Data<AppConfigProvider>,
     req: HttpRequest
@@ -51,16 +58,17 @@ async fn get_permissions(
         return Ok(HttpResponse::Ok().json(
             ioc.get_user_permissions(id_provider).execute(user_id.clone()).await?
         ))
-    } else if let (Some(page), Some(per_page)) = (&data.page, &data.per_page) {
+    } else if let Some(per_page) = page.per_page {
         let data = ioc.get_permission_range(id_provider).execute(
             GetPermissionRangeDTO {
-                page: page.clone(),
-                per_page: per_page.clone()
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total()
             }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
     }
-    Err(ApplicationError::InvalidData(ErrorContent::Message("Invalid query".to_string())))
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
 }
 
 #[put("")]
@@ -110,3 +118,35 @@ async fn unlink_role_permission(
     ioc.unlink_role_permission(id_provider).execute(data.into_inner()).await?;
     Ok(HttpResponse::NoContent().finish())
 }
+
+#[post("users/link")]
+async fn link_user_permission(
+    data: web::Json<LinkUserPermissionDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.link_user_permission(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("users/unlink")]
+async fn unlink_user_permission(
+    data: web::Json<UnlinkUserPermissionDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.unlink_user_permission(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -13,11 +13,13 @@ use crate::application::role::get_range::RoleRangeDTO;
 use crate::application::role::link::LinkRoleUserDTO;
 use crate::application::role::unlink::UnlinkRoleUserDTO;
 use crate::application::role::update::UpdateRoleDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::role::RoleId;
 use crate::domain::models::user::UserId;
 use crate::presentation::id_provider::make_id_provider_from_request;
 use crate::presentation::interactor_factory::InteractorFactory;
 use crate::presentation::web::deserializers::deserialize_uuid_list;
+use crate::presentation::web::pagination::PageQuery;
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -26,6 +28,8 @@ pub fn router(cfg: &mut web::ServiceConfig) {
             .service(get_roles)
             .service(update_role)
             .service(delete_role)
+            .service(link_role_user)
+            .service(unlink_role_user)
     );
 }
 
@@ -52,13 +56,12 @@ struct RolesQuery {
     #[serde(deserialize_with = "deserialize_uuid_list", default)]
     ids: Option<Vec<RoleId>>,
     user_id: Option<UserId>,
-    page: Option<u64>,
-    per_page: Option<u64>
 }
 
 #[get("")]
 async fn get_roles(
     data: web::Query<RolesQuery>,
+    page: web::Query<PageQuery>,
     ioc: web::Data<dyn InteractorFactory>,
     app_config_provider: web::Data<AppConfigProvider>,
     req: HttpRequest
@@ -85,16 +88,17 @@ async fn get_roles(
             GetUserRolesDTO { user_id: user_id.clone() }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
-    } else if let (Some(page), Some(per_page)) = (&data.page, &data.per_page) {
+    } else if let Some(per_page) = page.per_page {
         let data = ioc.get_role_range(id_provider).execute(
             RoleRangeDTO {
-                page: page.clone(),
-                per_page: per_page.clone()
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total()
             }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
     }
-    Err(ApplicationError::InvalidData(ErrorContent::Message("Invalid query".to_string())))
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
 }
 
 #[put("")]
@@ -6,9 +6,11 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
 use crate::application::service::get_range::GetServiceRangeDTO;
 use crate::application::service::update::UpdateServiceDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::service::ServiceId;
 use crate::presentation::id_provider::make_id_provider_from_request;
 use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::PageQuery;
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -21,13 +23,12 @@ pub fn router(cfg: &mut web::ServiceConfig) {
 #[derive(Debug, Deserialize)]
 struct ServicesQuery {
     id: Option<ServiceId>,
-    page: Option<u64>,
-    per_page: Option<u64>
 }
 
 #[get("")]
 async fn get_services(
     data: web::Query<ServicesQuery>,
+    page: web::Query<PageQuery>,
     ioc: web::Data<dyn InteractorFactory>,
     app_config_provider: web::Data<AppConfigProvider>,
     req: HttpRequest
@@ -43,16 +44,17 @@ async fn get_services(
         return Ok(HttpResponse::Ok().json(
             ioc.get_service(id_provider).execute(id.clone()).await?
         ))
-    } else if let (Some(page), Some(per_page)) = (&data.page, &data.per_page) {
+    } else if let Some(per_page) = page.per_page {
         let data = ioc.get_service_range(id_provider).execute(
             GetServiceRangeDTO {
-                page: page.clone(),
-                per_page: per_page.clone()
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total()
             }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
     }
-    Err(ApplicationError::InvalidData(ErrorContent::Message("Invalid query".to_string())))
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
 }
 
 #[put("")]
@@ -7,10 +7,13 @@ use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
 use crate::application::session::create::CreateSessionDTO;
 use crate::application::session::delete::DeleteSessionDTO;
+use crate::application::session::get_by_user_id::SessionsByUserIdDTO;
+use crate::domain::exceptions::Message;
 use crate::domain::models::session::SessionId;
 use crate::domain::models::user::UserId;
 use crate::presentation::id_provider::make_id_provider_from_request;
 use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::pagination::{DEFAULT_PER_PAGE, PageQuery};
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
@@ -93,6 +96,7 @@ struct SessionsQueryParams {
 #[get("")]
 async fn sessions_by(
     data: web::Query<SessionsQueryParams>,
+    page: web::Query<PageQuery>,
     app_config_provider: web::Data<AppConfigProvider>,
     ioc: web::Data<dyn InteractorFactory>,
     req: HttpRequest
@@ -112,7 +116,12 @@ async fn sessions_by(
     
     if let Some(user_id) = data.user_id {
         let data = ioc.get_sessions_by_user_id(id_provider).execute(
-            user_id
+            SessionsByUserIdDTO {
+                user_id,
+                cursor: page.cursor.clone(),
+                per_page: page.per_page.unwrap_or(DEFAULT_PER_PAGE),
+                with_total: page.with_total()
+            }
         ).await?;
         return Ok(HttpResponse::Ok().json(data));
     }
@@ -120,7 +129,7 @@ async fn sessions_by(
     Err(
         ApplicationError::InvalidData(
             ErrorContent::Message(
-                "Необходимо указать user_id или id сессии".to_string()
+                Message::new("session_filter_required")
             )
         )
     )
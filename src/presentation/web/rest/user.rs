@@ -1,33 +1,63 @@
-use actix_web::{get, HttpRequest, HttpResponse, patch, post, put, Result, web};
+use actix_multipart::form::{MultipartForm, MultipartFormConfig};
+use actix_multipart::form::bytes::Bytes;
+use actix_web::{delete, get, HttpRequest, HttpResponse, patch, post, put, Result, web};
+use actix_web::http::header::ContentDisposition;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::AppConfigProvider;
+use crate::application::ban::create::BanUserDTO;
+use crate::application::ban::revoke::RevokeBanDTO;
 use crate::application::common::exceptions::{ApplicationError, ErrorContent};
 use crate::application::common::interactor::Interactor;
+use crate::application::common::user_gateway::{SortOrder, UserSortField};
+use crate::application::user::accept_invitation::AcceptInvitationDTO;
+use crate::application::user::change_email_self::ChangeEmailSelfDTO;
 use crate::application::user::change_password::ChangePasswordDTO;
+use crate::application::user::change_phone_self::ChangePhoneSelfDTO;
 use crate::application::user::confirm::ConfirmUserDTO;
+use crate::application::user::confirm_phone::ConfirmPhoneDTO;
+use crate::application::user::update_preferences_self::UpdatePreferencesSelfDTO;
 use crate::application::user::create::CreateUserDTO;
+use crate::application::user::get_attributes::GetUserAttributesDTO;
 use crate::application::user::get_by_id::GetUserByIdDTO;
 use crate::application::user::get_by_ids::GetUsersByIdsDTO;
 use crate::application::user::get_range::GetUserRangeDTO;
+use crate::application::user::import::ImportUsersDTO;
+use crate::application::user::invite::InviteUserDTO;
 use crate::application::user::reset_password::ResetPasswordDTO;
+use crate::application::user::revert_email_change::RevertEmailChangeDTO;
 use crate::application::user::send_confirm_code::SendConfirmCodeDTO;
+use crate::application::user::send_email_change_code::SendEmailChangeCodeDTO;
 use crate::application::user::update::UpdateUserDTO;
+use crate::application::user::update_attributes::UpdateUserAttributesDTO;
+use crate::application::user::upload_avatar::UploadAvatarDTO;
 use crate::application::user::update_self::UpdateSelfDTO;
+use crate::domain::exceptions::Message;
+use crate::domain::models::user::UserState;
 use crate::presentation::id_provider::make_id_provider_from_request;
+use crate::presentation::import::{ImportFormat, parse_users, report_json};
 use crate::presentation::interactor_factory::InteractorFactory;
 use crate::presentation::web::deserializers::deserialize_uuid_list;
+use crate::presentation::web::exception::request_locale;
+use crate::presentation::web::pagination::PageQuery;
 
 pub fn router(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/users")
             .service(users_by_query)
             .service(user_self)
+            .service(export_user_self)
             .service(create_user)
             .service(update_user)
             .service(update_user_self)
             .service(change_password_self)
+            .service(change_email_self)
+            .service(change_phone_self)
+            .service(confirm_phone)
+            .service(update_preferences_self)
+            .service(revert_email_change)
             .service(
                 web::scope("/confirm")
                     .service(confirm_email)
@@ -36,6 +66,36 @@ pub fn router(cfg: &mut web::ServiceConfig) {
                 web::scope("/reset")
                     .service(reset_password)
             )
+            .service(
+                web::scope("/ban")
+                    .service(user_bans)
+                    .service(ban_user)
+                    .service(revoke_ban)
+            )
+            .service(
+                web::scope("/invite")
+                    .service(invite_user)
+                    .service(accept_invitation)
+            )
+            .service(
+                web::scope("/import")
+                    .app_data(web::PayloadConfig::new(IMPORT_PAYLOAD_LIMIT))
+                    .service(import_users)
+            )
+            .service(
+                web::scope("/avatar")
+                    .app_data(
+                        MultipartFormConfig::default()
+                            .total_limit(AVATAR_FORM_LIMIT)
+                            .memory_limit(AVATAR_FORM_LIMIT)
+                    )
+                    .service(upload_avatar)
+            )
+            .service(
+                web::scope("/attributes")
+                    .service(user_attributes)
+                    .service(update_user_attributes)
+            )
     );
 }
 
@@ -44,14 +104,22 @@ struct UsersQuery {
     id: Option<Uuid>,
     #[serde(deserialize_with = "deserialize_uuid_list", default)]
     ids: Option<Vec<Uuid>>,
-    page: Option<u64>,
-    per_page: Option<u64>
-
+    username: Option<String>,
+    email: Option<String>,
+    state: Option<UserState>,
+    role_id: Option<Uuid>,
+    created_from: Option<DateTime<Utc>>,
+    created_to: Option<DateTime<Utc>>,
+    last_login_from: Option<DateTime<Utc>>,
+    last_login_to: Option<DateTime<Utc>>,
+    sort: Option<UserSortField>,
+    order: Option<SortOrder>,
 }
 
 #[get("")]
 async fn users_by_query(
     data: web::Query<UsersQuery>,
+    page: web::Query<PageQuery>,
     ioc: web::Data<dyn InteractorFactory>,
     app_config_provider: web::Data<AppConfigProvider>,
     req: HttpRequest
@@ -73,16 +141,27 @@ async fn users_by_query(
             GetUsersByIdsDTO { ids: ids.clone(), }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
-    } else if let (Some(page), Some(per_page)) = (&data.page, &data.per_page) {
+    } else if let Some(per_page) = page.per_page {
         let data = ioc.get_user_range(id_provider).execute(
             GetUserRangeDTO {
-                page: page.clone(),
-                per_page: per_page.clone()
+                cursor: page.cursor.clone(),
+                per_page,
+                with_total: page.with_total(),
+                username: data.username.clone(),
+                email: data.email.clone(),
+                state: data.state.clone(),
+                role_id: data.role_id,
+                created_from: data.created_from,
+                created_to: data.created_to,
+                last_login_from: data.last_login_from,
+                last_login_to: data.last_login_to,
+                sort: data.sort.unwrap_or_default(),
+                order: data.order.unwrap_or_default(),
             }
         ).await?;
         return Ok(HttpResponse::Ok().json(data))
     }
-    Err(ApplicationError::InvalidData(ErrorContent::Message("Invalid query".to_string())))
+    Err(ApplicationError::InvalidData(ErrorContent::Message(Message::new("query_invalid"))))
 }
 
 #[get("/self")]
@@ -100,6 +179,25 @@ async fn user_self(
     Ok(HttpResponse::Ok().json(data))
 }
 
+#[get("/self/export")]
+async fn export_user_self(
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.export_user_self(id_provider).execute(()).await?;
+    Ok(HttpResponse::Ok()
+        .insert_header(ContentDisposition::attachment(
+            format!("user-{}.json", data.user.id)
+        ))
+        .json(data))
+}
+
 #[post("")]
 async fn create_user(
     data: web::Json<CreateUserDTO>,
@@ -112,7 +210,10 @@ async fn create_user(
         app_config_provider.is_intermediate,
         &req
     );
-    let data = ioc.create_user(id_provider).execute(data.into_inner()).await?;
+    let mut data = data.into_inner();
+    // Язык писем по умолчанию берется из Accept-Language
+    data.locale.get_or_insert_with(|| request_locale(req.headers()).to_string());
+    let data = ioc.create_user(id_provider).execute(data).await?;
     Ok(HttpResponse::Ok().json(data))
 }
 
@@ -164,6 +265,108 @@ async fn change_password_self(
     Ok(HttpResponse::NoContent().finish())
 }
 
+#[derive(Debug, Deserialize)]
+struct ChangeEmailBody {
+    email: String
+}
+
+#[put("self/phone")]
+async fn change_phone_self(
+    data: web::Json<ChangePhoneSelfDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.change_phone_self(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[post("self/phone/confirm")]
+async fn confirm_phone(
+    data: web::Json<ConfirmPhoneDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.confirm_phone(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[put("self/preferences")]
+async fn update_preferences_self(
+    data: web::Json<UpdatePreferencesSelfDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.update_preferences_self(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[post("self/email")]
+async fn change_email_self(
+    data: web::Json<ChangeEmailBody>,
+    query: web::Query<ConfirmQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+
+    match query.code {
+        None => {
+            let data = SendEmailChangeCodeDTO {
+                email: data.into_inner().email,
+            };
+            ioc.send_email_change_code(id_provider).execute(data).await?;
+        },
+        Some(code) => {
+            let data = ChangeEmailSelfDTO {
+                email: data.into_inner().email,
+                code
+            };
+            ioc.change_email_self(id_provider).execute(data).await?;
+        }
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[post("email/revert")]
+async fn revert_email_change(
+    query: web::Query<RevertEmailChangeDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.revert_email_change(id_provider).execute(query.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
 #[derive(Debug, Deserialize)]
 struct ConfirmQuery {
     code: Option<u32>
@@ -232,7 +435,7 @@ async fn reset_password(
         Some(code) => match &query.new_password {
             None => {
                 return Err(ApplicationError::InvalidData(
-                    ErrorContent::Message("Требуется указать новый пароль".to_string())
+                    ErrorContent::Message(Message::new("new_password_required"))
                 ))
             },
             Some(new_password) => {
@@ -248,3 +451,206 @@ async fn reset_password(
     
     Ok(HttpResponse::NoContent().finish())
 }
+
+#[derive(Debug, Deserialize)]
+struct BansQuery {
+    user_id: Uuid
+}
+
+#[get("")]
+async fn user_bans(
+    data: web::Query<BansQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.get_user_bans(id_provider).execute(data.user_id).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[post("")]
+async fn ban_user(
+    data: web::Json<BanUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.ban_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[delete("")]
+async fn revoke_ban(
+    data: web::Json<RevokeBanDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.revoke_ban(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[derive(Debug, Deserialize)]
+struct TargetUserQuery {
+    user_id: Option<Uuid>
+}
+
+#[get("/{namespace}")]
+async fn user_attributes(
+    namespace: web::Path<String>,
+    query: web::Query<TargetUserQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.get_user_attributes(id_provider).execute(
+        GetUserAttributesDTO {
+            user_id: query.user_id,
+            namespace: namespace.into_inner()
+        }
+    ).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[put("/{namespace}")]
+async fn update_user_attributes(
+    namespace: web::Path<String>,
+    query: web::Query<TargetUserQuery>,
+    data: web::Json<serde_json::Value>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.update_user_attributes(id_provider).execute(
+        UpdateUserAttributesDTO {
+            user_id: query.user_id,
+            namespace: namespace.into_inner(),
+            attributes: data.into_inner()
+        }
+    ).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+/// Ограничение размера формы загрузки аватара, 
+/// точный размер изображения проверяется при валидации
+const AVATAR_FORM_LIMIT: usize = 10 * 1024 * 1024;
+
+#[derive(Debug, MultipartForm)]
+struct AvatarForm {
+    file: Bytes,
+}
+
+#[put("")]
+async fn upload_avatar(
+    form: MultipartForm<AvatarForm>,
+    query: web::Query<TargetUserQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let file = form.into_inner().file;
+    let data = ioc.upload_avatar(id_provider).execute(
+        UploadAvatarDTO {
+            user_id: query.user_id,
+            content_type: file.content_type.map(|mime| {
+                mime.essence_str().to_string()
+            }).unwrap_or_default(),
+            data: file.data.to_vec()
+        }
+    ).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+/// Ограничение размера файла импорта пользователей
+const IMPORT_PAYLOAD_LIMIT: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+struct ImportQuery {
+    format: ImportFormat,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[post("")]
+async fn import_users(
+    body: web::Bytes,
+    query: web::Query<ImportQuery>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.import_users(id_provider).execute(
+        ImportUsersDTO {
+            users: parse_users(query.format, &body)?,
+            dry_run: query.dry_run
+        }
+    ).await?;
+    Ok(HttpResponse::Ok().json(report_json(&data, request_locale(req.headers()))))
+}
+
+#[post("")]
+async fn invite_user(
+    data: web::Json<InviteUserDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    let data = ioc.invite_user(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(data))
+}
+
+#[post("accept")]
+async fn accept_invitation(
+    data: web::Json<AcceptInvitationDTO>,
+    ioc: web::Data<dyn InteractorFactory>,
+    app_config_provider: web::Data<AppConfigProvider>,
+    req: HttpRequest
+) -> Result<HttpResponse, ApplicationError> {
+    let id_provider = make_id_provider_from_request(
+        &app_config_provider.service_name,
+        app_config_provider.is_intermediate,
+        &req
+    );
+    ioc.accept_invitation(id_provider).execute(data.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
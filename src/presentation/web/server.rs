@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use actix_web::{App, HttpServer as ActixHttpServer, web};
+use actix_web::dev::Service;
 use actix_web::http::KeepAlive;
 use actix_web::middleware::Logger;
 use openssl::ssl::{SslAcceptor, SslFiletype, SslMethod};
@@ -13,6 +14,7 @@ use crate::domain::models::service::ServiceTextId;
 use crate::ioc::IoC;
 use crate::presentation;
 use crate::presentation::interactor_factory::InteractorFactory;
+use crate::presentation::web::exception::{localize_error, request_locale};
 
 #[derive(Clone)]
 pub struct AppConfigProvider {
@@ -83,12 +85,21 @@ impl Server for HttpServer {
                         .configure(presentation::web::rest::stats::router)
                         .configure(presentation::web::rest::permission::router)
                         .configure(presentation::web::rest::service::router)
+                        .configure(presentation::web::rest::organization::router)
+                        .configure(presentation::web::rest::group::router)
                     )
                     .app_data(web::Data::new(
                         app_config_provider.clone()
                     ))
                     .app_data(ioc_data)
                     .default_service(web::route().to(presentation::web::exception::not_found))
+                    .wrap_fn(|request, service| {
+                        let locale = request_locale(request.headers());
+                        let response = service.call(request);
+                        async move {
+                            response.await.map(|response| localize_error(response, locale))
+                        }
+                    })
                     .wrap(Logger::default())
             };
 